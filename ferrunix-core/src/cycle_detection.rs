@@ -1,11 +1,14 @@
 //! Implementation of a cycle detection algorithm for our dependency resolution algorithm.
 
 use std::any::TypeId;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-use crate::dependency_builder::{self, DepBuilder};
+use crate::dependency_builder::{self, DepBuilder, DepInfo};
 use crate::types::{
-    HashMap, NonAsyncRwLock, Registerable, RegisterableSingleton, Visitor,
+    HashMap, HashSet, NonAsyncRwLock, Registerable, RegisterableSingleton,
+    RegistrationKey, Visitor, WeakRef,
 };
+use crate::Registry;
 
 /// All possible errors during validation.
 #[derive(Debug, Clone, PartialEq, Hash)]
@@ -14,7 +17,10 @@ pub enum ValidationError {
     /// A cycle between dependencies has been detected.
     Cycle,
     /// Dependencies are missing.
-    Missing,
+    ///
+    /// Contains the name of one of the missing dependencies, if known. Use
+    /// [`Registry::validate_all_full`] for all missing dependencies.
+    Missing(Option<&'static str>),
 }
 
 impl std::fmt::Display for ValidationError {
@@ -22,7 +28,10 @@ impl std::fmt::Display for ValidationError {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Cycle => write!(fmt, "cycle detected!"),
-            Self::Missing => write!(fmt, "dependencies missing!"),
+            Self::Missing(None) => write!(fmt, "dependencies missing!"),
+            Self::Missing(Some(name)) => {
+                write!(fmt, "dependencies missing: {name}")
+            }
         }
     }
 }
@@ -38,7 +47,11 @@ impl std::error::Error for ValidationError {
 #[non_exhaustive]
 pub enum FullValidationError {
     /// A cycle between dependencies has been detected.
-    Cycle(Option<String>),
+    ///
+    /// Contains the types participating in the cycle, in dependency order.
+    /// The first type is repeated at the end to close the cycle, e.g.,
+    /// `["A", "B", "C", "A"]` for `A -> B -> C -> A`.
+    Cycle(Vec<String>),
     /// Dependencies are missing.
     Missing(Vec<MissingDependencies>),
 }
@@ -47,10 +60,13 @@ impl std::fmt::Display for FullValidationError {
     #[allow(clippy::use_debug)]
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Cycle(ref node) => match node {
-                Some(node) => write!(fmt, "cycle detected at {node}"),
-                None => write!(fmt, "cycle detected!"),
-            },
+            Self::Cycle(ref path) => {
+                if path.is_empty() {
+                    write!(fmt, "cycle detected!")
+                } else {
+                    write!(fmt, "cycle detected: {}", path.join(" -> "))
+                }
+            }
             Self::Missing(ref all_missing) => {
                 writeln!(fmt, "dependencies missing:")?;
 
@@ -88,6 +104,19 @@ pub struct MissingDependencies {
 }
 
 impl MissingDependencies {
+    /// Creates a new description of the missing `deps` of type `ty`, e.g., to
+    /// aggregate the validation results of multiple registries.
+    ///
+    /// Both `ty` and `deps` are tuples of the [`std::any::TypeId`] and the
+    /// type name, as returned from [`std::any::type_name`].
+    #[must_use]
+    pub fn new(
+        ty: (TypeId, &'static str),
+        deps: Vec<(TypeId, &'static str)>,
+    ) -> Self {
+        Self { ty, deps }
+    }
+
     /// Returns a reference to a tuple of the [`std::any::TypeId`] and the type name (as returned
     /// from [`std::any::type_name`], therefore, it's "best effort", and might not be correct or
     /// reproducible).
@@ -101,191 +130,373 @@ impl MissingDependencies {
     pub fn missing_dependencies(&self) -> &[(TypeId, &'static str)] {
         &self.deps
     }
+
+    /// The name of the first missing dependency, for [`ValidationError`].
+    pub(crate) fn first_dep(&self) -> Option<&'static str> {
+        self.deps.first().map(|(_, name)| *name)
+    }
+}
+
+/// A singleton that depends on a transient, as returned by
+/// [`Registry::validate_lifetimes`](crate::Registry::validate_lifetimes).
+///
+/// The singleton is only constructed once, therefore it holds a single
+/// instance of the transient forever, instead of a new one each time.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LifetimeWarning {
+    /// The singleton that depends on `transient`.
+    singleton: (TypeId, &'static str),
+    /// The transient dependency of `singleton`.
+    transient: (TypeId, &'static str),
+}
+
+impl LifetimeWarning {
+    /// Returns a reference to a tuple of the [`std::any::TypeId`] and the
+    /// type name (as returned from [`std::any::type_name`]) of the singleton.
+    pub fn singleton(&self) -> &(TypeId, &'static str) {
+        &self.singleton
+    }
+
+    /// Returns a reference to a tuple of the [`std::any::TypeId`] and the
+    /// type name (as returned from [`std::any::type_name`]) of the transient
+    /// dependency.
+    pub fn transient(&self) -> &(TypeId, &'static str) {
+        &self.transient
+    }
+}
+
+impl std::fmt::Display for LifetimeWarning {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            fmt,
+            "singleton {} depends on transient {}, it's only constructed once",
+            self.singleton.1, self.transient.1
+        )
+    }
 }
 
 /// Validation whether all dependencies are registered, and the dependency chain has no cycles.
 pub(crate) struct DependencyValidator {
     /// The visitor callbacks. Those are necessary because we only want to register each type once
     /// we have collected them all.
-    visitor: NonAsyncRwLock<HashMap<TypeId, Visitor>>,
+    visitor: NonAsyncRwLock<HashMap<RegistrationKey, Visitor>>,
+    /// The visitor callbacks of all types registered with
+    /// [`Registry::register_all`](crate::Registry::register_all). There can be
+    /// many visitors per type.
+    multi_visitor: NonAsyncRwLock<HashMap<TypeId, Vec<Visitor>>>,
     /// Context for visitors.
     context: NonAsyncRwLock<VisitorContext>,
+    /// The parent registry, for child registries. Dependencies registered on
+    /// the parent aren't missing.
+    parent: Option<WeakRef<Registry>>,
+    /// Incremented whenever the registered types change. Child registries
+    /// compare it to detect registrations on their parents.
+    generation: AtomicUsize,
 }
 
 impl DependencyValidator {
-    /// Create a new dependency validator.
-    pub(crate) fn new() -> Self {
+    /// Create a new dependency validator, with space for at least `capacity`
+    /// types.
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
         Self {
-            visitor: NonAsyncRwLock::new(HashMap::new()),
+            visitor: NonAsyncRwLock::new(HashMap::with_capacity(capacity)),
+            multi_visitor: NonAsyncRwLock::new(HashMap::new()),
             context: NonAsyncRwLock::new(VisitorContext::new()),
+            parent: None,
+            generation: AtomicUsize::new(0),
         }
     }
 
-    /// Register a new transient, without any dependencies.
-    pub(crate) fn add_transient_no_deps<T>(&self)
-    where
-        T: Registerable,
-    {
-        let visitor = Visitor(|_this, _visitors, context| {
-            if let Some(index) = context.visited.get(&TypeId::of::<T>()) {
-                return *index;
-            }
+    /// Set the parent registry, whose registered types are treated as
+    /// registered while validating.
+    pub(crate) fn set_parent(&mut self, parent: WeakRef<Registry>) {
+        self.parent = Some(parent);
+    }
 
-            let index = context.graph.add_node(std::any::type_name::<T>());
+    /// Invalidate the cached validation result, after the registered types
+    /// changed.
+    fn invalidate(&self) {
+        self.context.write().reset();
+        self.generation.fetch_add(1, Ordering::AcqRel);
+    }
 
-            context.visited.insert(TypeId::of::<T>(), index);
+    /// Returns the number of parent registries, and the sum of their
+    /// generations. It changes whenever a type is registered on any of them.
+    fn ancestors_generation(&self) -> (usize, usize) {
+        let mut generation = (0, 0);
+        let mut parent = self.parent.as_ref().and_then(WeakRef::upgrade);
+        while let Some(registry) = parent {
+            let validator = registry.validator();
+            generation.0 += 1;
+            generation.1 = generation
+                .1
+                .wrapping_add(validator.generation.load(Ordering::Acquire));
+            parent = validator.parent.as_ref().and_then(WeakRef::upgrade);
+        }
 
-            index
-        });
+        generation
+    }
 
-        {
-            let mut visitors = self.visitor.write();
-            visitors.insert(TypeId::of::<T>(), visitor);
-            {
-                let mut context = self.context.write();
-                context.reset();
+    /// Returns `true` if the validation result cached in `context` is
+    /// up-to-date, including the types registered on the parent registries.
+    fn is_current(&self, context: &VisitorContext) -> bool {
+        context.validation_cache.is_some()
+            && (self.parent.is_none()
+                || context.ancestors == self.ancestors_generation())
+    }
+
+    /// Returns `true` if `key` is registered on any of the parent registries.
+    fn parent_contains(&self, key: &RegistrationKey) -> bool {
+        let mut parent = self.parent.as_ref().and_then(WeakRef::upgrade);
+        while let Some(registry) = parent {
+            let validator = registry.validator();
+            if validator.visitor.read().contains_key(key) {
+                return true;
             }
+            parent = validator.parent.as_ref().and_then(WeakRef::upgrade);
         }
+
+        false
+    }
+
+    /// Register a new transient, without any dependencies.
+    ///
+    /// `name` is the name of a named registration.
+    pub(crate) fn add_transient_no_deps<T>(&self, name: Option<&'static str>)
+    where
+        T: Registerable,
+    {
+        self.add_no_deps::<T>(name, Lifetime::Transient);
     }
 
     /// Register a new singleton, without any dependencies.
-    pub(crate) fn add_singleton_no_deps<T>(&self)
+    ///
+    /// `name` is the name of a named registration.
+    pub(crate) fn add_singleton_no_deps<T>(&self, name: Option<&'static str>)
+    where
+        T: RegisterableSingleton,
+    {
+        self.add_no_deps::<T>(name, Lifetime::Singleton);
+    }
+
+    /// Register a new scoped type, without any dependencies.
+    pub(crate) fn add_scoped_no_deps<T>(&self)
     where
         T: RegisterableSingleton,
     {
-        self.add_transient_no_deps::<T>();
+        self.add_no_deps::<T>(None, Lifetime::Scoped);
+    }
+
+    /// Register a new type with `lifetime`, without any dependencies.
+    ///
+    /// `name` is the name of a named registration.
+    fn add_no_deps<T>(&self, name: Option<&'static str>, lifetime: Lifetime)
+    where
+        T: Registerable,
+    {
+        let visitor = Visitor(
+            |_this, _visitors, context, key, lifetime, _deps| {
+                if let Some(index) = context.visited.get(&key) {
+                    return *index;
+                }
+
+                let index = context
+                    .graph
+                    .add_node(DependencyNode::new::<T>(key.1, lifetime));
+
+                context.visited.insert(key, index);
+
+                index
+            },
+            Box::default(),
+            lifetime,
+        );
+
+        {
+            let mut visitors = self.visitor.write();
+            visitors.insert((TypeId::of::<T>(), name), visitor);
+            self.invalidate();
+        }
     }
 
     /// Register a new transient, with dependencies specified via `Deps`.
+    ///
+    /// `name` is the name of a named registration.
     pub(crate) fn add_transient_deps<
         T: Registerable,
-        #[cfg(not(feature = "tokio"))] Deps: DepBuilder<T> + 'static,
-        #[cfg(feature = "tokio")] Deps: DepBuilder<T> + Sync + 'static,
+        #[cfg(not(feature = "async"))] Deps: DepBuilder<T> + 'static,
+        #[cfg(feature = "async")] Deps: DepBuilder<T> + Sync + 'static,
     >(
         &self,
+        name: Option<&'static str>,
     ) {
-        let visitor = Visitor(|this, visitors, context| {
-            // We already visited this type.
-            if let Some(index) = context.visited.get(&TypeId::of::<T>()) {
-                return *index;
-            }
-
-            let current = context.graph.add_node(std::any::type_name::<T>());
+        self.add_deps::<T, Deps>(name, Lifetime::Transient);
+    }
 
-            // We visited this type. This must be added before we visit dependencies.
-            {
-                context.visited.insert(TypeId::of::<T>(), current);
-            }
+    /// Register a new singleton, with dependencies specified via `Deps`.
+    ///
+    /// `name` is the name of a named registration.
+    pub(crate) fn add_singleton_deps<
+        T: RegisterableSingleton,
+        #[cfg(not(feature = "async"))] Deps: DepBuilder<T> + 'static,
+        #[cfg(feature = "async")] Deps: DepBuilder<T> + Sync + 'static,
+    >(
+        &self,
+        name: Option<&'static str>,
+    ) {
+        self.add_deps::<T, Deps>(name, Lifetime::Singleton);
+    }
 
-            let type_ids =
-                Deps::as_typeids(dependency_builder::private::SealToken);
+    /// Register a new scoped type, with dependencies specified via `Deps`.
+    pub(crate) fn add_scoped_deps<
+        T: RegisterableSingleton,
+        #[cfg(not(feature = "async"))] Deps: DepBuilder<T> + 'static,
+        #[cfg(feature = "async")] Deps: DepBuilder<T> + Sync + 'static,
+    >(
+        &self,
+    ) {
+        self.add_deps::<T, Deps>(None, Lifetime::Scoped);
+    }
 
-            for (type_id, type_name) in &type_ids {
-                // We have been to the dependency type before, we don't need to do it again.
-                if let Some(index) = context.visited.get(type_id) {
-                    context.graph.add_edge(current, *index, ());
-                    continue;
+    /// Register a new type with `lifetime`, with dependencies specified via
+    /// `Deps`.
+    ///
+    /// `name` is the name of a named registration.
+    fn add_deps<
+        T: Registerable,
+        #[cfg(not(feature = "async"))] Deps: DepBuilder<T> + 'static,
+        #[cfg(feature = "async")] Deps: DepBuilder<T> + Sync + 'static,
+    >(
+        &self,
+        name: Option<&'static str>,
+        lifetime: Lifetime,
+    ) {
+        let visitor = Visitor(
+            |this, visitors, context, key, lifetime, deps| {
+                // We already visited this type.
+                if let Some(index) = context.visited.get(&key) {
+                    return *index;
                 }
 
-                // Never seen the type before, visit it.
-                if let Some(visitor) = visitors.get(type_id) {
-                    let index = (visitor.0)(this, visitors, context);
-                    context.graph.add_edge(current, index, ());
-                    continue;
-                }
+                let current = context
+                    .graph
+                    .add_node(DependencyNode::new::<T>(key.1, lifetime));
 
+                // We visited this type. This must be added before we visit dependencies.
                 {
-                    if let Some(ty) =
-                        context.missing.get_mut(&TypeId::of::<T>())
-                    {
-                        ty.deps.push((*type_id, type_name));
-                    } else {
-                        context.missing.insert(
-                            TypeId::of::<T>(),
-                            MissingDependencies {
-                                ty: (
-                                    TypeId::of::<T>(),
-                                    std::any::type_name::<T>(),
-                                ),
-                                deps: vec![(*type_id, type_name)],
-                            },
-                        );
-                    }
+                    context.visited.insert(key, current);
                 }
 
-                #[cfg(feature = "tracing")]
-                tracing::warn!(
-                    "couldn't add dependency of {}: {type_name}",
-                    std::any::type_name::<T>()
+                visit_dependencies::<T>(
+                    this, visitors, context, key, current, deps,
                 );
-            }
 
-            current
-        });
+                current
+            },
+            Deps::as_typeids(dependency_builder::private::SealToken)
+                .into_boxed_slice(),
+            lifetime,
+        );
 
         {
             let mut visitors = self.visitor.write();
-            visitors.insert(TypeId::of::<T>(), visitor);
-            {
-                let mut context = self.context.write();
-                context.reset();
-            }
+            visitors.insert((TypeId::of::<T>(), name), visitor);
+            self.invalidate();
         }
     }
 
-    /// Register a new singleton, with dependencies specified via `Deps`.
-    pub(crate) fn add_singleton_deps<
-        T: RegisterableSingleton,
-        #[cfg(not(feature = "tokio"))] Deps: DepBuilder<T> + 'static,
-        #[cfg(feature = "tokio")] Deps: DepBuilder<T> + Sync + 'static,
+    /// Register one more constructor of `T`, with dependencies specified via
+    /// `Deps`, for [`Registry::register_all`](crate::Registry::register_all).
+    pub(crate) fn add_multi_transient_deps<
+        T: Registerable,
+        #[cfg(not(feature = "async"))] Deps: DepBuilder<T> + 'static,
+        #[cfg(feature = "async")] Deps: DepBuilder<T> + Sync + 'static,
     >(
         &self,
     ) {
-        self.add_transient_deps::<T, Deps>();
+        let visitor = Visitor(
+            |this, visitors, context, key, _lifetime, deps| {
+                // Every constructor gets its own node, nothing can depend on it.
+                let current =
+                    context.graph.add_node(DependencyNode::collection::<T>());
+
+                visit_dependencies::<T>(
+                    this, visitors, context, key, current, deps,
+                );
+
+                current
+            },
+            Deps::as_typeids(dependency_builder::private::SealToken)
+                .into_boxed_slice(),
+            Lifetime::Transient,
+        );
+
+        {
+            let mut visitors = self.multi_visitor.write();
+            visitors.entry(TypeId::of::<T>()).or_default().push(visitor);
+            self.invalidate();
+        }
     }
 
-    /// Walk the dependency graph and validate that all types can be constructed, all dependencies
-    /// are fulfillable and there are no cycles in the graph.
-    pub(crate) fn validate_all(&self) -> Result<(), ValidationError> {
-        let read_context = self.context.read();
-        if Self::validate_context(&read_context)? {
-            // Validation result is still cached.
-            return Ok(());
+    /// Move all visitors of `other` into `self`, replacing the visitors of
+    /// types that are registered in both.
+    pub(crate) fn merge(&self, other: Self) {
+        let other_visitors = other.visitor.into_inner();
+        let other_multi_visitors = other.multi_visitor.into_inner();
+
+        let mut visitors = self.visitor.write();
+        let mut multi_visitors = self.multi_visitor.write();
+        visitors.extend(other_visitors);
+        for (type_id, mut entries) in other_multi_visitors {
+            multi_visitors
+                .entry(type_id)
+                .or_default()
+                .append(&mut entries);
         }
+        self.invalidate();
+    }
 
-        // No validation result is cached, drop the read lock and acquire an exclusive lock to
-        // update the cached validation result.
-        drop(read_context);
-        let visitors = self.visitor.read();
-        let mut write_context = self.context.write();
-        if Self::validate_context(&write_context)? {
-            // Context was updated by another thread while we waited for the exclusive write lock
-            // to be acquired.
-            return Ok(());
+    /// Returns a new validator with the visitors of all types registered on
+    /// `self`. The cached validation result isn't copied.
+    pub(crate) fn duplicate(&self) -> Self {
+        Self {
+            visitor: NonAsyncRwLock::new(self.visitor.read().clone()),
+            multi_visitor: NonAsyncRwLock::new(
+                self.multi_visitor.read().clone(),
+            ),
+            context: NonAsyncRwLock::new(VisitorContext::new()),
+            parent: self.parent.clone(),
+            generation: AtomicUsize::new(0),
         }
+    }
 
-        // Validation did not run, we need to run it.
-        self.calculate_validation(&visitors, &mut write_context);
+    /// Remove the visitor of type `T`, and invalidate the cached validation result.
+    ///
+    /// `name` is the name of a named registration.
+    pub(crate) fn remove<T: 'static>(&self, name: Option<&'static str>) {
+        let mut visitors = self.visitor.write();
+        visitors.remove(&(TypeId::of::<T>(), name));
+        self.invalidate();
+    }
+
+    /// Walk the dependency graph and validate that all types can be constructed, all dependencies
+    /// are fulfillable and there are no cycles in the graph.
+    pub(crate) fn validate_all(&self) -> Result<(), ValidationError> {
+        let context = self.build_graph();
 
         // Throws an error if our dependency graph is invalid.
-        Self::validate_context(&write_context)?;
+        Self::validate_context(&context)?;
 
         Ok(())
     }
 
     /// Walk the dependency graph and validate that all types can be constructed, all dependencies
     /// are fulfillable and there are no cycles in the graph.
+    ///
+    /// The dependency graph is cached, and only rebuilt if types were
+    /// registered since the last validation.
     pub(crate) fn validate_all_full(&self) -> Result<(), FullValidationError> {
-        let mut context = VisitorContext::new();
-        {
-            let visitors = self.visitor.read();
-            self.calculate_validation(&visitors, &mut context);
-        }
-
-        // Evaluate whether we want to make this available via an option? It takes ages to
-        // calculate!
-        // let tarjan = petgraph::algo::tarjan_scc(&context.graph);
-        // dbg!(&tarjan);
+        let context = self.build_graph();
 
         if !context.missing.is_empty() {
             let mut vec = Vec::with_capacity(context.missing.len());
@@ -295,20 +506,22 @@ impl DependencyValidator {
             return Err(FullValidationError::Missing(vec));
         }
 
-        if let Some(cached) = &context.validation_cache {
-            return match cached {
-                Ok(_) => Ok(()),
-                Err(err) => {
-                    let index = err.node_id();
-                    let node_name = context.graph.node_weight(index);
-                    return Err(FullValidationError::Cycle(
-                        node_name.map(|el| (*el).to_owned()),
-                    ));
-                }
-            };
+        match &context.validation_cache {
+            Some(Err(err)) => {
+                let path = cycle_path(&context.graph, err.node_id());
+                Err(FullValidationError::Cycle(path))
+            }
+            _ => Ok(()),
         }
+    }
 
-        unreachable!("this is a bug")
+    /// Returns `true` if the cached validation result is up-to-date, and the
+    /// dependency graph has neither missing dependencies, nor cycles.
+    pub(crate) fn is_validated(&self) -> bool {
+        let context = self.context.read();
+        self.is_current(&context)
+            && context.missing.is_empty()
+            && matches!(context.validation_cache, Some(Ok(_)))
     }
 
     /// Inspect `context`, and return a [`ValidationError`] if there are errors in the dependency
@@ -319,31 +532,64 @@ impl DependencyValidator {
     fn validate_context(
         context: &VisitorContext,
     ) -> Result<bool, ValidationError> {
-        if !context.missing.is_empty() {
-            return Err(ValidationError::Missing);
-        }
+        // The missing dependencies are only up-to-date, if the validation
+        // result is.
+        let Some(cached) = &context.validation_cache else {
+            return Ok(false);
+        };
 
-        if let Some(cached) = &context.validation_cache {
-            return match cached {
-                Ok(_) => Ok(true),
-                Err(_) => Err(ValidationError::Cycle),
-            };
+        if let Some(missing) = context.missing.values().next() {
+            return Err(ValidationError::Missing(missing.first_dep()));
         }
 
-        Ok(false)
+        match cached {
+            Ok(_) => Ok(true),
+            Err(_) => Err(ValidationError::Cycle),
+        }
     }
 
     /// Visit all visitors in `self.visitor`, and create the new dependency graph.
     fn calculate_validation(
         &self,
-        visitors: &HashMap<TypeId, Visitor>,
+        visitors: &HashMap<RegistrationKey, Visitor>,
+        multi_visitors: &HashMap<TypeId, Vec<Visitor>>,
         context: &mut VisitorContext,
     ) {
+        use petgraph::visit::IntoNodeReferences;
+
         {
-            for cb in visitors.values() {
+            for (key, cb) in visitors {
                 // To avoid a dead lock due to other visitors needing to be called, we pass in the
                 // visitors hashmap.
-                (cb.0)(self, visitors, context);
+                (cb.0)(self, visitors, context, *key, cb.2, &cb.1);
+            }
+
+            for (type_id, cbs) in multi_visitors {
+                for cb in cbs {
+                    (cb.0)(
+                        self,
+                        visitors,
+                        context,
+                        (*type_id, None),
+                        cb.2,
+                        &cb.1,
+                    );
+                }
+            }
+
+            let collection_deps = std::mem::take(&mut context.collection_deps);
+            for (dependent, type_id) in collection_deps {
+                let constructors = context
+                    .graph
+                    .node_references()
+                    .filter(|(_, node)| {
+                        node.collection && node.type_id == type_id
+                    })
+                    .map(|(index, _)| index)
+                    .collect::<Vec<_>>();
+                for index in constructors {
+                    context.graph.add_edge(dependent, index, ());
+                }
             }
         }
 
@@ -354,12 +600,204 @@ impl DependencyValidator {
     }
 
     /// Validate whether the type `T` is constructible.
+    ///
+    /// Only the part of the dependency graph that's reachable from `T` is
+    /// validated. Missing dependencies or cycles elsewhere in the graph are
+    /// ignored.
+    ///
+    /// If `T` isn't registered on this registry, it's validated on the
+    /// closest parent registry, the same one it's resolved from.
     pub(crate) fn validate<T>(&self) -> Result<(), ValidationError>
     where
         T: Registerable,
     {
-        let _ = std::marker::PhantomData::<T>;
-        self.validate_all()
+        let type_id = TypeId::of::<T>();
+        let type_name = std::any::type_name::<T>();
+        {
+            let context = self.build_graph();
+            let cached = context.validated.read().get(&type_id).cloned();
+            let result = cached.unwrap_or_else(|| {
+                let registered = context
+                    .graph
+                    .node_weights()
+                    .any(|node| node.type_id == type_id);
+                let result = (registered || self.parent.is_none()).then(|| {
+                    Self::validate_subgraph(&context, type_id, type_name)
+                });
+                context.validated.write().insert(type_id, result.clone());
+                result
+            });
+            if let Some(result) = result {
+                return result;
+            }
+        }
+
+        // The context is unlocked before the parent is validated.
+        match self.parent.as_ref().and_then(WeakRef::upgrade) {
+            Some(parent) => parent.validator().validate::<T>(),
+            None => Err(ValidationError::Missing(Some(type_name))),
+        }
+    }
+
+    /// Return the names of all types along the shortest path from `T` to the
+    /// first type with missing dependencies, followed by the name of the
+    /// missing dependency.
+    ///
+    /// Returns an empty `Vec`, if no dependencies reachable from `T` are
+    /// missing.
+    pub(crate) fn missing_chain<T>(&self) -> Vec<&'static str>
+    where
+        T: Registerable,
+    {
+        let context = self.build_graph();
+        let missing = context
+            .missing
+            .iter()
+            .filter_map(|(key, missing)| {
+                context.visited.get(key).map(|index| (*index, missing))
+            })
+            .collect::<HashMap<_, _>>();
+
+        let type_id = TypeId::of::<T>();
+        let mut parents = HashMap::new();
+        let mut queue = context
+            .graph
+            .node_indices()
+            .filter(|index| {
+                context
+                    .graph
+                    .node_weight(*index)
+                    .map_or(false, |node| node.type_id == type_id)
+            })
+            .collect::<std::collections::VecDeque<_>>();
+        let mut seen = queue.iter().copied().collect::<HashSet<_>>();
+
+        while let Some(current) = queue.pop_front() {
+            if let Some(missing) = missing.get(&current) {
+                // Walk back from `current` to the starting node to reconstruct the path.
+                let mut chain = vec![];
+                if let Some((_, type_name)) = missing.deps.first() {
+                    chain.push(*type_name);
+                }
+                let mut node = Some(current);
+                while let Some(index) = node {
+                    if let Some(weight) = context.graph.node_weight(index) {
+                        chain.push(weight.type_name);
+                    }
+                    node = parents.get(&index).copied();
+                }
+                chain.reverse();
+                return chain;
+            }
+
+            for next in context.graph.neighbors(current) {
+                if seen.insert(next) {
+                    parents.insert(next, current);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        Vec::new()
+    }
+
+    /// Rebuild the dependency graph, if it's outdated. In contrast to
+    /// [`DependencyValidator::validate_all`], this doesn't return any errors.
+    ///
+    /// Returns the context with an up-to-date validation result. The context
+    /// stays locked until the returned guard is dropped, a registration in
+    /// between would reset it.
+    ///
+    /// The context is only locked exclusively, if the graph is rebuilt.
+    fn build_graph(
+        &self,
+    ) -> impl std::ops::Deref<Target = VisitorContext> + '_ {
+        loop {
+            {
+                let context = self.context.read();
+                if self.is_current(&context) {
+                    return context;
+                }
+            }
+
+            let visitors = self.visitor.read();
+            let multi_visitors = self.multi_visitor.read();
+            let mut context = self.context.write();
+            if !self.is_current(&context) {
+                // Captured before the graph is built, a type registered on a
+                // parent in between invalidates it again.
+                let ancestors = self.ancestors_generation();
+                context.reset();
+                context.ancestors = ancestors;
+                self.calculate_validation(
+                    &visitors,
+                    &multi_visitors,
+                    &mut context,
+                );
+            }
+        }
+    }
+
+    /// Validate the subgraph of `context` that's reachable from any
+    /// registration of the type `type_id`, including named registrations.
+    fn validate_subgraph(
+        context: &VisitorContext,
+        type_id: TypeId,
+        type_name: &'static str,
+    ) -> Result<(), ValidationError> {
+        let starts = context
+            .graph
+            .node_indices()
+            .filter(|index| {
+                context
+                    .graph
+                    .node_weight(*index)
+                    .map_or(false, |node| node.type_id == type_id)
+            })
+            .collect::<Vec<_>>();
+        if starts.is_empty() {
+            // The type itself isn't registered.
+            return Err(ValidationError::Missing(Some(type_name)));
+        }
+
+        let mut reachable = HashSet::new();
+        for start in starts {
+            let mut dfs = petgraph::visit::Dfs::new(&context.graph, start);
+            while let Some(index) = dfs.next(&context.graph) {
+                reachable.insert(index);
+            }
+        }
+
+        let missing = context.missing.iter().find(|(key, _)| {
+            key.0 == type_id
+                || context
+                    .visited
+                    .get(*key)
+                    .map_or(false, |index| reachable.contains(index))
+        });
+        if let Some((_, missing)) = missing {
+            return Err(ValidationError::Missing(missing.first_dep()));
+        }
+
+        // Fast path, the whole graph is free of cycles.
+        if matches!(context.validation_cache, Some(Ok(_))) {
+            return Ok(());
+        }
+
+        let has_self_loop = reachable
+            .iter()
+            .any(|index| context.graph.contains_edge(*index, *index));
+        let has_cycle = petgraph::algo::tarjan_scc(&context.graph)
+            .iter()
+            .filter(|component| component.len() > 1)
+            .any(|component| {
+                component.iter().any(|index| reachable.contains(index))
+            });
+        if has_self_loop || has_cycle {
+            return Err(ValidationError::Cycle);
+        }
+
+        Ok(())
     }
 
     /// Return a string of the dependency graph visualized using graphviz's `dot` language.
@@ -367,23 +805,599 @@ impl DependencyValidator {
         self.validate_all()?;
 
         let context = self.context.read();
+        let graph = context
+            .graph
+            .map(|_, node| node.to_string(), |_, edge| *edge);
         let dot = petgraph::dot::Dot::with_config(
-            &context.graph,
+            &graph,
             &[petgraph::dot::Config::EdgeNoLabel],
         );
 
         Ok(format!("{dot:?}"))
     }
+
+    /// Return a string of the dependency graph visualized using graphviz's
+    /// `dot` language, with the nodes styled by their lifetime, and a legend.
+    pub(crate) fn dotgraph_styled(&self) -> Result<String, ValidationError> {
+        self.validate_all()?;
+
+        let context = self.context.read();
+        Ok(DependencyGraph::from_graph(&context.graph).to_dot_styled())
+    }
+
+    /// Return a string of the dependency graph visualized as `graph TD`
+    /// mermaid diagram.
+    pub(crate) fn mermaid_graph(&self) -> Result<String, ValidationError> {
+        self.validate_all()?;
+
+        let context = self.context.read();
+        Ok(DependencyGraph::from_graph(&context.graph).to_mermaid())
+    }
+
+    /// Return a string of the dependency graph serialized as JSON object,
+    /// with a list of `nodes` and `edges`.
+    pub(crate) fn graph_json(&self) -> Result<String, ValidationError> {
+        self.validate_all()?;
+
+        let context = self.context.read();
+        Ok(DependencyGraph::from_graph(&context.graph).to_json())
+    }
+
+    /// Return the keys of all registered types in topological order, every
+    /// type comes before its dependencies. Missing dependencies are ignored.
+    pub(crate) fn topological_order(
+        &self,
+    ) -> Result<Vec<RegistrationKey>, ValidationError> {
+        let context = self.build_graph();
+        let Some(Ok(order)) = &context.validation_cache else {
+            return Err(ValidationError::Cycle);
+        };
+
+        Ok(order
+            .iter()
+            .filter_map(|index| context.graph.node_weight(*index))
+            .filter(|node| !node.collection)
+            .map(|node| (node.type_id, node.name))
+            .collect())
+    }
+
+    /// Return the dependency graph of all registered types.
+    ///
+    /// The graph is returned even if it's missing dependencies or has cycles.
+    pub(crate) fn dependency_graph(&self) -> DependencyGraph {
+        // Validation errors are part of the returned graph.
+        #[allow(clippy::let_underscore_must_use)]
+        let _ = self.validate_all();
+
+        let context = self.context.read();
+        DependencyGraph::from_graph(&context.graph)
+    }
+
+    /// Return a warning for every singleton that depends on a transient.
+    ///
+    /// Dependencies registered on a parent registry aren't checked.
+    pub(crate) fn validate_lifetimes(&self) -> Vec<LifetimeWarning> {
+        use petgraph::visit::EdgeRef;
+
+        let context = self.build_graph();
+        context
+            .graph
+            .edge_references()
+            .filter_map(|edge| {
+                let singleton = context.graph.node_weight(edge.source())?;
+                let transient = context.graph.node_weight(edge.target())?;
+                let captures_transient = singleton.lifetime
+                    == Lifetime::Singleton
+                    && transient.lifetime == Lifetime::Transient
+                    && !transient.collection;
+
+                captures_transient.then_some(LifetimeWarning {
+                    singleton: (singleton.type_id, singleton.type_name),
+                    transient: (transient.type_id, transient.type_name),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Visit all dependencies `deps` of the type `T` (registered under `key`), and
+/// connect them to the node `current` in the dependency graph. Missing
+/// dependencies are recorded in `context`.
+fn visit_dependencies<T: Registerable>(
+    this: &DependencyValidator,
+    visitors: &HashMap<RegistrationKey, Visitor>,
+    context: &mut VisitorContext,
+    key: RegistrationKey,
+    current: petgraph::graph::NodeIndex,
+    deps: &[DepInfo],
+) {
+    for dep in deps {
+        let dep_key = (dep.type_id, dep.name);
+
+        // Collections depend on every constructor registered for the type,
+        // which are only all known after all visitors ran.
+        if dep.collection {
+            context.collection_deps.push((current, dep.type_id));
+            continue;
+        }
+
+        // Weak dependencies aren't constructed along with the dependent type,
+        // they only need to be registered.
+        if dep.weak
+            && (context.visited.contains_key(&dep_key)
+                || visitors.contains_key(&dep_key)
+                || this.parent_contains(&dep_key))
+        {
+            continue;
+        }
+
+        // We have been to the dependency type before, we don't need to do it again.
+        if let Some(index) = context.visited.get(&dep_key) {
+            context.graph.add_edge(current, *index, ());
+            continue;
+        }
+
+        // Never seen the type before, visit it.
+        if let Some(visitor) = visitors.get(&dep_key) {
+            let index = (visitor.0)(
+                this, visitors, context, dep_key, visitor.2, &visitor.1,
+            );
+            context.graph.add_edge(current, index, ());
+            continue;
+        }
+
+        // Optional dependencies don't need to be registered.
+        if dep.optional {
+            continue;
+        }
+
+        // Dependencies registered on a parent registry are resolved there, and
+        // can't depend on any types of this registry.
+        if this.parent_contains(&dep_key) {
+            continue;
+        }
+
+        {
+            if let Some(ty) = context.missing.get_mut(&key) {
+                ty.deps.push((dep.type_id, dep.type_name));
+            } else {
+                context.missing.insert(
+                    key,
+                    MissingDependencies {
+                        ty: (TypeId::of::<T>(), std::any::type_name::<T>()),
+                        deps: vec![(dep.type_id, dep.type_name)],
+                    },
+                );
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            "couldn't add dependency of {}: {}",
+            std::any::type_name::<T>(),
+            dep.type_name,
+        );
+    }
+}
+
+/// Find the cycle in `graph` that `start` participates in, and return the
+/// labels of all nodes along the cycle, starting and ending with `start`.
+///
+/// The strongly connected component containing `start` is calculated with
+/// tarjan's algorithm, and the shortest path back to `start` within the
+/// component is searched with a breadth-first search.
+fn cycle_path(graph: &Graph, start: petgraph::graph::NodeIndex) -> Vec<String> {
+    let label = |index| {
+        graph
+            .node_weight(index)
+            .map(ToString::to_string)
+            .unwrap_or_default()
+    };
+
+    let Some(component) = petgraph::algo::tarjan_scc(graph)
+        .into_iter()
+        .find(|component| component.contains(&start))
+    else {
+        return vec![label(start)];
+    };
+
+    let mut parents = HashMap::new();
+    let mut queue = std::collections::VecDeque::from([start]);
+    while let Some(current) = queue.pop_front() {
+        for next in graph.neighbors(current) {
+            if next == start {
+                // Walk back from `current` to `start` to reconstruct the path.
+                let mut chain = Vec::new();
+                let mut node = Some(current);
+                while let Some(index) = node.filter(|index| *index != start) {
+                    chain.push(index);
+                    node = parents.get(&index).copied();
+                }
+
+                return std::iter::once(start)
+                    .chain(chain.into_iter().rev())
+                    .chain(std::iter::once(start))
+                    .map(label)
+                    .collect();
+            }
+
+            if component.contains(&next) && !parents.contains_key(&next) {
+                parents.insert(next, current);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    vec![label(start)]
+}
+
+/// Format `value` as quoted and escaped string of graphviz's `dot` language.
+fn dot_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Format `value` as quoted and escaped JSON string.
+fn json_string(value: &str) -> String {
+    let escaped = value
+        .chars()
+        .map(|ch| match ch {
+            '"' => "\\\"".to_owned(),
+            '\\' => "\\\\".to_owned(),
+            '\n' => "\\n".to_owned(),
+            '\r' => "\\r".to_owned(),
+            '\t' => "\\t".to_owned(),
+            _ if ch.is_control() => format!("\\u{:04x}", u32::from(ch)),
+            _ => ch.to_string(),
+        })
+        .collect::<String>();
+
+    format!("\"{escaped}\"")
+}
+
+/// The dependency graph, as it's built by the visitors.
+type Graph = petgraph::Graph<DependencyNode, (), petgraph::Directed>;
+
+/// The lifetime a type is registered with, see
+/// [`DependencyNode::lifetime`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Lifetime {
+    /// A new instance is constructed every time the type is requested, e.g.,
+    /// registered with [`Registry::transient`](crate::Registry::transient).
+    Transient,
+    /// A single instance is constructed once, and shared, e.g., registered
+    /// with [`Registry::singleton`](crate::Registry::singleton).
+    Singleton,
+    /// A single instance is constructed once per scope, registered with
+    /// [`Registry::register_scoped`](crate::Registry::register_scoped).
+    Scoped,
+}
+
+impl Lifetime {
+    /// Returns the lowercase name of the lifetime, used as label in
+    /// [`Registry::dotgraph_styled`](crate::Registry::dotgraph_styled).
+    fn label(self) -> &'static str {
+        match self {
+            Self::Transient => "transient",
+            Self::Singleton => "singleton",
+            Self::Scoped => "scoped",
+        }
+    }
+
+    /// Returns the `dot` attributes of nodes with this lifetime.
+    fn dot_attributes(self) -> &'static str {
+        match self {
+            Self::Transient => "shape = ellipse",
+            Self::Singleton => "shape = box",
+            Self::Scoped => "shape = box, style = dashed",
+        }
+    }
+}
+
+/// A registered type in the [`DependencyGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DependencyNode {
+    /// The [`TypeId`] of the registered type.
+    type_id: TypeId,
+    /// Name of the registered type, as returned by [`std::any::type_name`].
+    type_name: &'static str,
+    /// Name of the registration, for named registrations.
+    name: Option<&'static str>,
+    /// Whether this is one of the constructors registered with
+    /// [`Registry::register_all`](crate::Registry::register_all).
+    collection: bool,
+    /// The lifetime the type is registered with.
+    lifetime: Lifetime,
+}
+
+impl DependencyNode {
+    /// Create a new node for the type `T`, registered with `name` and
+    /// `lifetime`.
+    fn new<T: 'static>(name: Option<&'static str>, lifetime: Lifetime) -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            type_name: std::any::type_name::<T>(),
+            name,
+            collection: false,
+            lifetime,
+        }
+    }
+
+    /// Create a new node for one of the constructors of `T` registered with
+    /// [`Registry::register_all`](crate::Registry::register_all).
+    fn collection<T: 'static>() -> Self {
+        Self {
+            collection: true,
+            ..Self::new::<T>(None, Lifetime::Transient)
+        }
+    }
+
+    /// Returns the [`TypeId`] of the registered type.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Returns the name of the registered type, as returned by
+    /// [`std::any::type_name`] (therefore, it's "best effort", and might not
+    /// be correct or reproducible).
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns the name of the registration, for named registrations.
+    pub fn name(&self) -> Option<&'static str> {
+        self.name
+    }
+
+    /// Returns the lifetime the type is registered with.
+    pub fn lifetime(&self) -> Lifetime {
+        self.lifetime
+    }
+}
+
+impl std::fmt::Display for DependencyNode {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(fmt, "{}", self.type_name)?;
+        if let Some(name) = self.name {
+            write!(fmt, " ({name})")?;
+        }
+        if self.collection {
+            write!(fmt, " (all)")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The dependency graph of all types registered in a
+/// [`Registry`](crate::Registry), as returned by
+/// [`Registry::dependency_graph`](crate::Registry::dependency_graph).
+///
+/// Edges point from the dependent type to its dependency. Dependencies that
+/// are missing aren't part of the graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyGraph {
+    /// All registered types.
+    nodes: Vec<DependencyNode>,
+    /// All edges, as indices into `nodes`.
+    edges: Vec<(usize, usize)>,
+}
+
+impl DependencyGraph {
+    /// Create a new dependency graph from the `graph` built by the visitors.
+    fn from_graph(graph: &Graph) -> Self {
+        use petgraph::visit::EdgeRef;
+
+        Self {
+            nodes: graph.raw_nodes().iter().map(|node| node.weight).collect(),
+            edges: graph
+                .edge_references()
+                .map(|edge| (edge.source().index(), edge.target().index()))
+                .collect(),
+        }
+    }
+
+    /// Returns all registered types.
+    pub fn nodes(&self) -> &[DependencyNode] {
+        &self.nodes
+    }
+
+    /// Returns all edges, from the dependent type to its dependency, as pairs
+    /// of indices into [`DependencyGraph::nodes`].
+    pub fn edges(&self) -> &[(usize, usize)] {
+        &self.edges
+    }
+
+    /// Returns all types the type with `id` directly depends on.
+    pub fn dependencies_of(&self, id: TypeId) -> Vec<&DependencyNode> {
+        self.edges
+            .iter()
+            .filter(|(from, _)| self.is_type(*from, id))
+            .filter_map(|(_, to)| self.nodes.get(*to))
+            .collect()
+    }
+
+    /// Returns all types that directly depend on the type with `id`.
+    pub fn dependents_of(&self, id: TypeId) -> Vec<&DependencyNode> {
+        self.edges
+            .iter()
+            .filter(|(_, to)| self.is_type(*to, id))
+            .filter_map(|(from, _)| self.nodes.get(*from))
+            .collect()
+    }
+
+    /// Returns all types that no other type depends on.
+    ///
+    /// Constructors registered with
+    /// [`Registry::register_all`](crate::Registry::register_all) are never
+    /// depended on, and therefore not included.
+    pub fn unused(&self) -> Vec<&DependencyNode> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(index, node)| {
+                !node.collection
+                    && !self.edges.iter().any(|(_, to)| to == index)
+            })
+            .map(|(_, node)| node)
+            .collect()
+    }
+
+    /// Returns all types that aren't reachable from any of the types in
+    /// `roots`, following the edges from dependent to dependency.
+    ///
+    /// Constructors registered with
+    /// [`Registry::register_all`](crate::Registry::register_all) are only
+    /// included if they're reachable.
+    pub fn unreachable_from(&self, roots: &[TypeId]) -> Vec<&DependencyNode> {
+        let mut reachable = vec![false; self.nodes.len()];
+        let mut stack = self
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| roots.contains(&node.type_id))
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+
+        while let Some(index) = stack.pop() {
+            match reachable.get_mut(index) {
+                Some(seen) if !*seen => *seen = true,
+                _ => continue,
+            }
+
+            stack.extend(
+                self.edges
+                    .iter()
+                    .filter(|(from, _)| *from == index)
+                    .map(|(_, to)| *to),
+            );
+        }
+
+        self.nodes
+            .iter()
+            .zip(reachable)
+            .filter(|(node, reachable)| !reachable && !node.collection)
+            .map(|(node, _)| node)
+            .collect()
+    }
+
+    /// Format the graph in graphviz's `dot` language. Nodes are drawn with
+    /// the shape of their lifetime, edges are labeled with the lifetime of
+    /// the dependency, and a legend explains the shapes.
+    fn to_dot_styled(&self) -> String {
+        let lifetimes =
+            [Lifetime::Transient, Lifetime::Singleton, Lifetime::Scoped];
+        let nodes = self.nodes.iter().enumerate().map(|(index, node)| {
+            format!(
+                "    n{index} [ label = {}, {} ]\n",
+                dot_string(&node.to_string()),
+                node.lifetime.dot_attributes(),
+            )
+        });
+        let edges = self.edges.iter().map(|(from, to)| {
+            let label =
+                self.nodes.get(*to).map_or("", |node| node.lifetime.label());
+            format!("    n{from} -> n{to} [ label = \"{label}\" ]\n")
+        });
+        let legend = lifetimes.iter().map(|lifetime| {
+            format!(
+                "        legend_{0} [ label = \"{0}\", {1} ]\n",
+                lifetime.label(),
+                lifetime.dot_attributes(),
+            )
+        });
+
+        std::iter::once("digraph {\n".to_owned())
+            .chain(nodes)
+            .chain(edges)
+            .chain(std::iter::once(
+                "    subgraph cluster_legend {\n        label = \"Legend\"\n"
+                    .to_owned(),
+            ))
+            .chain(legend)
+            .chain(std::iter::once("    }\n}\n".to_owned()))
+            .collect()
+    }
+
+    /// Format the graph as `graph TD` mermaid diagram.
+    fn to_mermaid(&self) -> String {
+        let nodes = self.nodes.iter().enumerate().map(|(index, node)| {
+            let label = node
+                .to_string()
+                .replace('"', "#quot;")
+                .replace('<', "#lt;")
+                .replace('>', "#gt;");
+            format!("    n{index}[\"{label}\"]\n")
+        });
+        let edges = self
+            .edges
+            .iter()
+            .map(|(from, to)| format!("    n{from} --> n{to}\n"));
+
+        std::iter::once("graph TD\n".to_owned())
+            .chain(nodes)
+            .chain(edges)
+            .collect()
+    }
+
+    /// Format the graph as JSON object, e.g.:
+    ///
+    /// ```json
+    /// {"nodes":[{"id":0,"type_name":"u16","name":null},{"id":1,"type_name":"u8","name":null}],"edges":[{"from":0,"to":1}]}
+    /// ```
+    fn to_json(&self) -> String {
+        let nodes = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| {
+                format!(
+                    r#"{{"id":{index},"type_name":{},"name":{}}}"#,
+                    json_string(node.type_name),
+                    node.name.map_or_else(|| "null".to_owned(), json_string),
+                )
+            })
+            .collect::<Vec<_>>();
+        let edges = self
+            .edges
+            .iter()
+            .map(|(from, to)| format!(r#"{{"from":{from},"to":{to}}}"#))
+            .collect::<Vec<_>>();
+
+        format!(
+            r#"{{"nodes":[{}],"edges":[{}]}}"#,
+            nodes.join(","),
+            edges.join(",")
+        )
+    }
+
+    /// Returns `true` if the node at `index` is of the type with `id`.
+    fn is_type(&self, index: usize, id: TypeId) -> bool {
+        self.nodes
+            .get(index)
+            .map_or(false, |node| node.type_id == id)
+    }
 }
 
 /// Context that's passed into every `visitor`.
 pub(crate) struct VisitorContext {
     /// Dependency graph.
-    graph: petgraph::Graph<&'static str, (), petgraph::Directed>,
+    graph: Graph,
     /// All missing dependencies.
-    missing: HashMap<TypeId, MissingDependencies>,
+    missing: HashMap<RegistrationKey, MissingDependencies>,
     /// Cache of all previously visited types. To avoid infinite recursion and as an optimization.
-    visited: HashMap<TypeId, petgraph::graph::NodeIndex>,
+    visited: HashMap<RegistrationKey, petgraph::graph::NodeIndex>,
+    /// Dependents on all constructors of a type registered with
+    /// [`Registry::register_all`](crate::Registry::register_all).
+    collection_deps: Vec<(petgraph::graph::NodeIndex, TypeId)>,
+    /// Cached validation result of single types, see
+    /// [`DependencyValidator::validate`]. `None` if the type isn't registered
+    /// on this registry, and is validated on the parent registry.
+    validated:
+        NonAsyncRwLock<HashMap<TypeId, Option<Result<(), ValidationError>>>>,
+    /// The generation of the parent registries, when the graph was built. See
+    /// [`DependencyValidator::ancestors_generation`].
+    ancestors: (usize, usize),
     /// Cached validation result.
     validation_cache: Option<
         Result<
@@ -400,6 +1414,9 @@ impl VisitorContext {
             graph: petgraph::Graph::new(),
             missing: HashMap::new(),
             visited: HashMap::new(),
+            collection_deps: Vec::new(),
+            validated: NonAsyncRwLock::new(HashMap::new()),
+            ancestors: (0, 0),
             validation_cache: None,
         }
     }
@@ -409,6 +1426,8 @@ impl VisitorContext {
         self.graph.clear();
         self.missing.clear();
         self.visited.clear();
+        self.collection_deps.clear();
+        self.validated.write().clear();
         self.validation_cache = None;
     }
 }
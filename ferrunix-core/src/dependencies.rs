@@ -4,6 +4,18 @@
 //!   * [`Transient`]: Dependencies that are created from scratch when
 //!     requested.
 //!   * [`Singleton`]: Dependencies that are created once for every registry.
+//!   * [`NamedTransient`] and [`NamedSingleton`]: Same as above, but resolved
+//!     from a named registration.
+//!   * [`OptionalTransient`] and [`OptionalSingleton`]: Same as above, but
+//!     resolved to `None` if the type isn't registered.
+//!   * [`WeakSingleton`]: A weak reference to a singleton, e.g., to break
+//!     ownership cycles.
+//!   * [`Lazy`]: A transient that's constructed on first access.
+//!   * [`TransientAll`]: All transients registered with
+//!     [`Registry::register_all`].
+//!   * [`RegistryRef`]: The registry the type is resolved from.
+//!   * [`DepGroup`]: A tuple of dependencies, resolved as a single
+//!     dependency.
 //!
 //! All dependency types implement the [`Dep`] trait, and can get access to the
 //! inner type via `.get`.
@@ -32,8 +44,13 @@
 //! ```
 
 use std::any::TypeId;
+use std::marker::PhantomData;
 
-use crate::types::{Registerable, RegisterableSingleton};
+use crate::dependency_builder::{private::SealToken, DepInfo};
+use crate::error::ResolveError;
+use crate::types::{
+    OnceCell, Registerable, RegisterableSingleton, WeakAny, WeakRef,
+};
 use crate::{types::Ref, Registry};
 
 /// Required for sealing the `Dep` trait. *Must not be public*.
@@ -48,27 +65,106 @@ mod private {
 /// Current implementors:
 ///   * [`Transient`]
 ///   * [`Singleton`]
+///   * [`NamedTransient`]
+///   * [`NamedSingleton`]
+///   * [`OptionalTransient`]
+///   * [`OptionalSingleton`]
+///   * [`WeakSingleton`]
+///   * [`Lazy`]
+///   * [`TransientAll`]
+///   * [`RegistryRef`]
+///   * [`DepGroup`]
 ///
-/// This trait is sealed, it cannot be implemented outside of this crate.
+/// This trait is sealed, it cannot be implemented outside of this crate. Use
+/// [`Registry::resolve_dep`] to resolve any of the dependency types outside
+/// of a constructor.
 pub trait Dep: Registerable + private::Sealed {
     /// Looks up the dependency in `registry`, and constructs a new [`Dep`].
     ///
-    /// This function is allowed to panic, if the type isn't registered.
-    #[cfg(not(feature = "tokio"))]
-    fn new(registry: &Registry) -> Self;
+    /// # Errors
+    /// Returns an error if the type isn't registered, or failed to construct.
+    #[cfg(not(feature = "async"))]
+    fn new(registry: &Registry) -> Result<Self, ResolveError>
+    where
+        Self: Sized;
 
     /// Looks up the dependency in `registry`, and constructs a new [`Dep`].
     ///
-    /// This function is allowed to panic, if the type isn't registered.
-    #[cfg(feature = "tokio")]
+    /// # Errors
+    /// Returns an error if the type isn't registered, or failed to construct.
+    #[cfg(feature = "async")]
     fn new(
         registry: &Registry,
-    ) -> impl std::future::Future<Output = Self> + Send
+    ) -> impl std::future::Future<Output = Result<Self, ResolveError>> + Send
     where
         Self: Sized;
 
     /// Returns [`std::any::TypeId`] of the dependency type.
     fn type_id() -> TypeId;
+
+    /// Returns [`std::any::type_name`] of the dependency type.
+    fn type_name() -> &'static str;
+
+    /// Returns the name of the registration for named dependencies, or `None`.
+    fn name() -> Option<&'static str> {
+        None
+    }
+
+    /// Returns whether the dependency is optional. Optional dependencies
+    /// aren't required to be registered.
+    fn optional() -> bool {
+        false
+    }
+
+    /// Returns whether the dependency is a weak reference, or otherwise not
+    /// constructed along with the dependent type. Weak dependencies don't
+    /// form cycles in the dependency graph.
+    fn weak() -> bool {
+        false
+    }
+
+    /// Returns whether the dependency resolves to all constructors registered
+    /// with [`Registry::register_all`], instead of a single registration.
+    fn collection() -> bool {
+        false
+    }
+
+    /// Returns the [`DepInfo`]s of all types the dependency resolves to.
+    ///
+    /// Most dependencies resolve to a single type, [`DepGroup`] resolves to
+    /// all types of its dependencies.
+    #[doc(hidden)]
+    fn dep_infos(_: SealToken) -> Vec<DepInfo> {
+        vec![DepInfo {
+            type_id: Self::type_id(),
+            name: Self::name(),
+            type_name: Self::type_name(),
+            optional: Self::optional(),
+            weak: Self::weak(),
+            collection: Self::collection(),
+        }]
+    }
+}
+
+/// Name of a named registration, used as a type parameter for the named
+/// dependency types [`NamedTransient`] and [`NamedSingleton`].
+///
+/// # Example
+/// ```rust,ignore
+/// use ferrunix_core::dependencies::{DepName, NamedTransient};
+///
+/// struct Primary;
+///
+/// impl DepName for Primary {
+///     const NAME: &'static str = "primary";
+/// }
+///
+/// // Resolves the `u16` registered with `transient_named("primary", ...)`.
+/// type PrimaryPort = NamedTransient<u16, Primary>;
+/// ```
+pub trait DepName: 'static {
+    /// The name of the registration.
+    const NAME: &'static str;
 }
 
 /// Transient dependencies.
@@ -117,36 +213,35 @@ impl<T> private::Sealed for Transient<T> {}
 impl<T: Registerable> Dep for Transient<T> {
     /// Create a new [`Transient`].
     ///
-    /// # Panic
-    /// This function panics if the `T` isn't registered.
-    #[cfg(not(feature = "tokio"))]
-    fn new(registry: &Registry) -> Self {
-        Self {
-            inner: registry.get_transient::<T>().expect(
-                "transient dependency must only be constructed if it's \
-                 fulfillable",
-            ),
-        }
+    /// # Errors
+    /// Returns an error if `T` isn't registered, or failed to construct.
+    #[cfg(not(feature = "async"))]
+    fn new(registry: &Registry) -> Result<Self, ResolveError> {
+        Ok(Self {
+            inner: registry.resolve_transient::<T>(None)?,
+        })
     }
 
     /// Create a new [`Transient`], asynchronously.
     ///
-    /// # Panic
-    /// This function panics if the `T` isn't registered.
-    #[cfg(feature = "tokio")]
-    async fn new(registry: &Registry) -> Self {
-        Self {
-            inner: registry.get_transient::<T>().await.expect(
-                "transient dependency must only be constructed if it's \
-                 fulfillable",
-            ),
-        }
+    /// # Errors
+    /// Returns an error if `T` isn't registered, or failed to construct.
+    #[cfg(feature = "async")]
+    async fn new(registry: &Registry) -> Result<Self, ResolveError> {
+        Ok(Self {
+            inner: registry.resolve_transient::<T>(None).await?,
+        })
     }
 
     /// Returns [`std::any::TypeId`] of the inner type `T`.
     fn type_id() -> TypeId {
         TypeId::of::<T>()
     }
+
+    /// Returns [`std::any::type_name`] of the inner type `T`.
+    fn type_name() -> &'static str {
+        std::any::type_name::<T>()
+    }
 }
 
 /// Singleton dependencies.
@@ -202,34 +297,965 @@ impl<T> private::Sealed for Singleton<T> {}
 impl<T: RegisterableSingleton> Dep for Singleton<T> {
     /// Create a new [`Singleton`].
     ///
-    /// # Panic
-    /// This function panics if the `T` isn't registered.
-    #[cfg(not(feature = "tokio"))]
-    fn new(registry: &Registry) -> Self {
-        Self {
-            inner: registry.get_singleton::<T>().expect(
-                "singleton dependency must only be constructed if it's \
-                 fulfillable",
-            ),
-        }
+    /// # Errors
+    /// Returns an error if `T` isn't registered, or failed to construct.
+    #[cfg(not(feature = "async"))]
+    fn new(registry: &Registry) -> Result<Self, ResolveError> {
+        Ok(Self {
+            inner: registry.resolve_singleton::<T>(None)?,
+        })
     }
 
     /// Create a new [`Singleton`], asynchronously.
     ///
-    /// # Panic
-    /// This function panics if the `T` isn't registered.
-    #[cfg(feature = "tokio")]
-    async fn new(registry: &Registry) -> Self {
-        Self {
-            inner: registry.get_singleton::<T>().await.expect(
-                "singleton dependency must only be constructed if it's \
-                 fulfillable",
-            ),
-        }
+    /// # Errors
+    /// Returns an error if `T` isn't registered, or failed to construct.
+    #[cfg(feature = "async")]
+    async fn new(registry: &Registry) -> Result<Self, ResolveError> {
+        Ok(Self {
+            inner: registry.resolve_singleton::<T>(None).await?,
+        })
     }
 
     /// Returns [`std::any::TypeId`] of the inner type `T`.
     fn type_id() -> TypeId {
         TypeId::of::<T>()
     }
+
+    /// Returns [`std::any::type_name`] of the inner type `T`.
+    fn type_name() -> &'static str {
+        std::any::type_name::<T>()
+    }
+}
+
+/// Named transient dependencies.
+///
+/// Same as [`Transient`], except that it's resolved from the registration
+/// with the name `N::NAME` (see [`DepName`]).
+#[repr(transparent)]
+pub struct NamedTransient<T, N> {
+    /// The resolved type.
+    inner: T,
+    /// Marker for the name.
+    _marker: PhantomData<fn() -> N>,
+}
+
+impl<T: std::fmt::Debug, N> std::fmt::Debug for NamedTransient<T, N> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("NamedTransient")
+            .field("inner", &self.inner)
+            .finish()
+    }
 }
+
+impl<T: Registerable, N: DepName> std::ops::Deref for NamedTransient<T, N> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: Registerable, N: DepName> std::ops::DerefMut for NamedTransient<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T: Registerable, N: DepName> NamedTransient<T, N> {
+    /// Access the inner `T`.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn get(self) -> T {
+        self.inner
+    }
+}
+
+// Required for implementing `Dep`.
+impl<T, N> private::Sealed for NamedTransient<T, N> {}
+
+impl<T: Registerable, N: DepName> Dep for NamedTransient<T, N> {
+    /// Create a new [`NamedTransient`].
+    ///
+    /// # Errors
+    /// Returns an error if `T` isn't registered with the name `N::NAME`, or
+    /// failed to construct.
+    #[cfg(not(feature = "async"))]
+    fn new(registry: &Registry) -> Result<Self, ResolveError> {
+        Ok(Self {
+            inner: registry.resolve_transient::<T>(Some(N::NAME))?,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Create a new [`NamedTransient`], asynchronously.
+    ///
+    /// # Errors
+    /// Returns an error if `T` isn't registered with the name `N::NAME`, or
+    /// failed to construct.
+    #[cfg(feature = "async")]
+    async fn new(registry: &Registry) -> Result<Self, ResolveError> {
+        Ok(Self {
+            inner: registry.resolve_transient::<T>(Some(N::NAME)).await?,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns [`std::any::TypeId`] of the inner type `T`.
+    fn type_id() -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    /// Returns [`std::any::type_name`] of the inner type `T`.
+    fn type_name() -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    /// Returns `N::NAME`.
+    fn name() -> Option<&'static str> {
+        Some(N::NAME)
+    }
+}
+
+/// Named singleton dependencies.
+///
+/// Same as [`Singleton`], except that it's resolved from the registration
+/// with the name `N::NAME` (see [`DepName`]).
+#[repr(transparent)]
+pub struct NamedSingleton<T, N> {
+    /// The resolved type.
+    inner: Ref<T>,
+    /// Marker for the name.
+    _marker: PhantomData<fn() -> N>,
+}
+
+impl<T: std::fmt::Debug, N> std::fmt::Debug for NamedSingleton<T, N> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("NamedSingleton")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<T: RegisterableSingleton, N: DepName> From<NamedSingleton<T, N>>
+    for Ref<T>
+{
+    fn from(value: NamedSingleton<T, N>) -> Self {
+        value.inner
+    }
+}
+
+impl<T: RegisterableSingleton, N: DepName> std::ops::Deref
+    for NamedSingleton<T, N>
+{
+    type Target = Ref<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: RegisterableSingleton, N: DepName> std::ops::DerefMut
+    for NamedSingleton<T, N>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T: RegisterableSingleton, N: DepName> NamedSingleton<T, N> {
+    /// Access the inner dependency, returns a ref-counted object.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn get(self) -> Ref<T> {
+        self.inner
+    }
+}
+
+// Required for implementing `Dep`.
+impl<T, N> private::Sealed for NamedSingleton<T, N> {}
+
+impl<T: RegisterableSingleton, N: DepName> Dep for NamedSingleton<T, N> {
+    /// Create a new [`NamedSingleton`].
+    ///
+    /// # Errors
+    /// Returns an error if `T` isn't registered with the name `N::NAME`, or
+    /// failed to construct.
+    #[cfg(not(feature = "async"))]
+    fn new(registry: &Registry) -> Result<Self, ResolveError> {
+        Ok(Self {
+            inner: registry.resolve_singleton::<T>(Some(N::NAME))?,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Create a new [`NamedSingleton`], asynchronously.
+    ///
+    /// # Errors
+    /// Returns an error if `T` isn't registered with the name `N::NAME`, or
+    /// failed to construct.
+    #[cfg(feature = "async")]
+    async fn new(registry: &Registry) -> Result<Self, ResolveError> {
+        Ok(Self {
+            inner: registry.resolve_singleton::<T>(Some(N::NAME)).await?,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns [`std::any::TypeId`] of the inner type `T`.
+    fn type_id() -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    /// Returns [`std::any::type_name`] of the inner type `T`.
+    fn type_name() -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    /// Returns `N::NAME`.
+    fn name() -> Option<&'static str> {
+        Some(N::NAME)
+    }
+}
+
+/// Optional transient dependencies.
+///
+/// Same as [`Transient`], except that it resolves to `None` if `T` isn't
+/// registered, instead of failing the construction.
+#[repr(transparent)]
+pub struct OptionalTransient<T> {
+    /// The resolved type, if it's registered.
+    inner: Option<T>,
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for OptionalTransient<T> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("OptionalTransient")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<T: Registerable> std::ops::Deref for OptionalTransient<T> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: Registerable> std::ops::DerefMut for OptionalTransient<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T: Registerable> OptionalTransient<T> {
+    /// Access the inner `T`, or `None` if `T` isn't registered.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn get(self) -> Option<T> {
+        self.inner
+    }
+}
+
+// Required for implementing `Dep`.
+impl<T> private::Sealed for OptionalTransient<T> {}
+
+impl<T: Registerable> Dep for OptionalTransient<T> {
+    /// Create a new [`OptionalTransient`].
+    #[cfg(not(feature = "async"))]
+    fn new(registry: &Registry) -> Result<Self, ResolveError> {
+        Ok(Self {
+            inner: registry.get_transient::<T>(),
+        })
+    }
+
+    /// Create a new [`OptionalTransient`], asynchronously.
+    #[cfg(feature = "async")]
+    async fn new(registry: &Registry) -> Result<Self, ResolveError> {
+        Ok(Self {
+            inner: registry.get_transient::<T>().await,
+        })
+    }
+
+    /// Returns [`std::any::TypeId`] of the inner type `T`.
+    fn type_id() -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    /// Returns [`std::any::type_name`] of the inner type `T`.
+    fn type_name() -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    /// Returns `true`.
+    fn optional() -> bool {
+        true
+    }
+}
+
+/// Optional singleton dependencies.
+///
+/// Same as [`Singleton`], except that it resolves to `None` if `T` isn't
+/// registered, instead of failing the construction.
+#[repr(transparent)]
+pub struct OptionalSingleton<T> {
+    /// The resolved type, if it's registered.
+    inner: Option<Ref<T>>,
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for OptionalSingleton<T> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("OptionalSingleton")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<T: RegisterableSingleton> std::ops::Deref for OptionalSingleton<T> {
+    type Target = Option<Ref<T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: RegisterableSingleton> std::ops::DerefMut for OptionalSingleton<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T: RegisterableSingleton> OptionalSingleton<T> {
+    /// Access the inner dependency, returns a ref-counted object, or `None`
+    /// if `T` isn't registered.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn get(self) -> Option<Ref<T>> {
+        self.inner
+    }
+}
+
+// Required for implementing `Dep`.
+impl<T> private::Sealed for OptionalSingleton<T> {}
+
+impl<T: RegisterableSingleton> Dep for OptionalSingleton<T> {
+    /// Create a new [`OptionalSingleton`].
+    #[cfg(not(feature = "async"))]
+    fn new(registry: &Registry) -> Result<Self, ResolveError> {
+        Ok(Self {
+            inner: registry.get_singleton::<T>(),
+        })
+    }
+
+    /// Create a new [`OptionalSingleton`], asynchronously.
+    #[cfg(feature = "async")]
+    async fn new(registry: &Registry) -> Result<Self, ResolveError> {
+        Ok(Self {
+            inner: registry.get_singleton::<T>().await,
+        })
+    }
+
+    /// Returns [`std::any::TypeId`] of the inner type `T`.
+    fn type_id() -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    /// Returns [`std::any::type_name`] of the inner type `T`.
+    fn type_name() -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    /// Returns `true`.
+    fn optional() -> bool {
+        true
+    }
+}
+
+/// Weak singleton dependencies.
+///
+/// Same as [`Singleton`], except that it doesn't keep `T` alive, and doesn't
+/// construct `T`. This allows two singletons to reference each other, without
+/// leaking memory. The back-reference isn't considered a cycle by
+/// [`Registry::validate_all`].
+///
+/// `T` is only accessible after it has been constructed, therefore, the
+/// [`WeakSingleton`] should be stored, and [`WeakSingleton::get`] called when
+/// `T` is needed.
+pub struct WeakSingleton<T> {
+    /// Weak handle to the singleton `T`, or the cell it's constructed into.
+    inner: WeakAny,
+    /// Marker for `T`.
+    _marker: PhantomData<T>,
+}
+
+impl<T> std::fmt::Debug for WeakSingleton<T> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("WeakSingleton")
+            .field("type", &std::any::type_name::<T>())
+            .finish()
+    }
+}
+
+impl<T: RegisterableSingleton> WeakSingleton<T> {
+    /// Access the inner dependency, returns a weak reference to `T`.
+    ///
+    /// The weak reference can't be upgraded if `T` hasn't been constructed
+    /// yet, or has already been dropped.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn get(&self) -> WeakRef<T> {
+        let Some(handle) = self.inner.upgrade() else {
+            return WeakRef::new();
+        };
+
+        handle.downcast::<OnceCell<Ref<T>>>().map_or_else(
+            |handle| {
+                handle
+                    .downcast::<T>()
+                    .map_or_else(|_| WeakRef::new(), |rc| Ref::downgrade(&rc))
+            },
+            |cell| cell.get().map_or_else(WeakRef::new, Ref::downgrade),
+        )
+    }
+}
+
+// Required for implementing `Dep`.
+impl<T> private::Sealed for WeakSingleton<T> {}
+
+impl<T: RegisterableSingleton> Dep for WeakSingleton<T> {
+    /// Create a new [`WeakSingleton`], without constructing `T`.
+    ///
+    /// # Errors
+    /// Returns an error if `T` isn't registered.
+    #[cfg(not(feature = "async"))]
+    fn new(registry: &Registry) -> Result<Self, ResolveError> {
+        Ok(Self {
+            inner: registry.resolve_weak_singleton::<T>(None)?,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Create a new [`WeakSingleton`], asynchronously, without constructing
+    /// `T`.
+    ///
+    /// # Errors
+    /// Returns an error if `T` isn't registered.
+    #[cfg(feature = "async")]
+    async fn new(registry: &Registry) -> Result<Self, ResolveError> {
+        Ok(Self {
+            inner: registry.resolve_weak_singleton::<T>(None).await?,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns [`std::any::TypeId`] of the inner type `T`.
+    fn type_id() -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    /// Returns [`std::any::type_name`] of the inner type `T`.
+    fn type_name() -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    /// Returns `true`.
+    fn weak() -> bool {
+        true
+    }
+}
+
+/// Lazy transient dependencies.
+///
+/// Same as [`Transient`], except that `T` isn't constructed along with the
+/// dependent type, but on the first call to [`Lazy::get`], from the registry
+/// that's passed to it. The constructed `T` is kept, every further call to
+/// [`Lazy::get`] returns the same `T`.
+///
+/// Like [`WeakSingleton`], a [`Lazy`] dependency isn't considered a cycle by
+/// [`Registry::validate_all`].
+pub struct Lazy<T> {
+    /// The constructed `T`, after the first call to [`Lazy::get`].
+    cell: OnceCell<T>,
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for Lazy<T> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("Lazy")
+            .field("inner", &self.cell.get())
+            .finish()
+    }
+}
+
+impl<T: Registerable> Lazy<T> {
+    /// Access the inner `T`, constructing it from `registry` on the first
+    /// call.
+    ///
+    /// # Errors
+    /// Returns an error if `T` isn't registered, or failed to construct. The
+    /// construction is retried on the next call.
+    #[cfg(not(feature = "async"))]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn get(&self, registry: &Registry) -> Result<&T, ResolveError> {
+        self.cell
+            .get_or_try_init(|| registry.resolve_transient::<T>(None))
+    }
+
+    /// Access the inner `T`, constructing it from `registry` on the first
+    /// call.
+    ///
+    /// # Errors
+    /// Returns an error if `T` isn't registered, or failed to construct. The
+    /// construction is retried on the next call.
+    #[cfg(feature = "async")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get(&self, registry: &Registry) -> Result<&T, ResolveError> {
+        self.cell
+            .get_or_try_init(|| registry.resolve_transient::<T>(None))
+            .await
+    }
+
+    /// Returns the inner `T`, if it has been constructed already.
+    #[must_use]
+    pub fn get_if_init(&self) -> Option<&T> {
+        self.cell.get()
+    }
+}
+
+// Required for implementing `Dep`.
+impl<T> private::Sealed for Lazy<T> {}
+
+impl<T: Registerable> Dep for Lazy<T> {
+    /// Create a new [`Lazy`], without constructing `T`.
+    ///
+    /// # Errors
+    /// Never returns an error, `T` is only required when it's accessed.
+    #[cfg(not(feature = "async"))]
+    fn new(_: &Registry) -> Result<Self, ResolveError> {
+        Ok(Self {
+            cell: OnceCell::new(),
+        })
+    }
+
+    /// Create a new [`Lazy`], without constructing `T`.
+    ///
+    /// # Errors
+    /// Never returns an error, `T` is only required when it's accessed.
+    #[cfg(feature = "async")]
+    async fn new(_: &Registry) -> Result<Self, ResolveError> {
+        Ok(Self {
+            cell: OnceCell::new(),
+        })
+    }
+
+    /// Returns [`std::any::TypeId`] of the inner type `T`.
+    fn type_id() -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    /// Returns [`std::any::type_name`] of the inner type `T`.
+    fn type_name() -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    /// Returns `true`.
+    fn weak() -> bool {
+        true
+    }
+}
+
+/// Dependencies on all transients registered with [`Registry::register_all`].
+///
+/// Every registered constructor of `T` is called, in the order they were
+/// registered, see [`Registry::transient_all`]. If no constructor of `T` is
+/// registered, the dependency resolves to an empty [`Vec`].
+#[repr(transparent)]
+pub struct TransientAll<T> {
+    /// All resolved types.
+    inner: Vec<T>,
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for TransientAll<T> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("TransientAll")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<T: Registerable> std::ops::Deref for TransientAll<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: Registerable> std::ops::DerefMut for TransientAll<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T: Registerable> TransientAll<T> {
+    /// Access the inner `Vec<T>`.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn get(self) -> Vec<T> {
+        self.inner
+    }
+}
+
+// Required for implementing `Dep`.
+impl<T> private::Sealed for TransientAll<T> {}
+
+impl<T: Registerable> Dep for TransientAll<T> {
+    /// Create a new [`TransientAll`].
+    ///
+    /// # Errors
+    /// Returns an error if any of the `T`s failed to construct.
+    #[cfg(not(feature = "async"))]
+    fn new(registry: &Registry) -> Result<Self, ResolveError> {
+        Ok(Self {
+            inner: registry.transient_all::<T>()?,
+        })
+    }
+
+    /// Create a new [`TransientAll`], asynchronously.
+    ///
+    /// # Errors
+    /// Returns an error if any of the `T`s failed to construct.
+    #[cfg(feature = "async")]
+    async fn new(registry: &Registry) -> Result<Self, ResolveError> {
+        Ok(Self {
+            inner: registry.transient_all::<T>().await?,
+        })
+    }
+
+    /// Returns [`std::any::TypeId`] of the inner type `T`.
+    fn type_id() -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    /// Returns [`std::any::type_name`] of the inner type `T`.
+    fn type_name() -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    /// Returns `true`, the collection might be empty.
+    fn optional() -> bool {
+        true
+    }
+
+    /// Returns `true`.
+    fn collection() -> bool {
+        true
+    }
+}
+
+/// The registry itself, as dependency.
+///
+/// This allows constructors to look up types dynamically, at runtime. It
+/// resolves to the registry the dependent type is resolved from, which must
+/// be ref-counted (see [`Registry::into_ref`]).
+///
+/// A [`RegistryRef`] keeps the registry alive. Storing it in a singleton of
+/// the same registry leaks both, prefer resolving everything that's needed
+/// in the constructor.
+pub struct RegistryRef {
+    /// The resolved registry.
+    inner: Ref<Registry>,
+}
+
+impl std::fmt::Debug for RegistryRef {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("RegistryRef").finish()
+    }
+}
+
+impl From<RegistryRef> for Ref<Registry> {
+    fn from(value: RegistryRef) -> Self {
+        value.inner
+    }
+}
+
+impl std::ops::Deref for RegistryRef {
+    type Target = Ref<Registry>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl RegistryRef {
+    /// Access the inner registry.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn get(self) -> Ref<Registry> {
+        self.inner
+    }
+}
+
+// Required for implementing `Dep`.
+impl private::Sealed for RegistryRef {}
+
+impl Dep for RegistryRef {
+    /// Create a new [`RegistryRef`].
+    ///
+    /// # Errors
+    /// Returns [`ResolveError::RegistryNotRef`] if `registry` isn't
+    /// ref-counted.
+    #[cfg(not(feature = "async"))]
+    fn new(registry: &Registry) -> Result<Self, ResolveError> {
+        let inner = registry.this().ok_or(ResolveError::RegistryNotRef)?;
+        Ok(Self { inner })
+    }
+
+    /// Create a new [`RegistryRef`].
+    ///
+    /// # Errors
+    /// Returns [`ResolveError::RegistryNotRef`] if `registry` isn't
+    /// ref-counted.
+    #[cfg(feature = "async")]
+    async fn new(registry: &Registry) -> Result<Self, ResolveError> {
+        let inner = registry.this().ok_or(ResolveError::RegistryNotRef)?;
+        Ok(Self { inner })
+    }
+
+    /// Returns [`std::any::TypeId`] of [`Registry`].
+    fn type_id() -> TypeId {
+        TypeId::of::<Registry>()
+    }
+
+    /// Returns [`std::any::type_name`] of [`Registry`].
+    fn type_name() -> &'static str {
+        std::any::type_name::<Registry>()
+    }
+
+    /// Returns `true`, the registry is never registered, but it's always
+    /// available.
+    fn optional() -> bool {
+        true
+    }
+}
+
+/// Constructor of a [`Constructed`] dependency.
+///
+/// The constructor is passed the registry the dependent type is resolved
+/// from. It's implemented by `#[derive(Inject)]` for fields constructed with
+/// `ctor_async`.
+///
+/// # Example
+/// ```rust,ignore
+/// use ferrunix_core::dependencies::{Constructed, DepCtor};
+/// use ferrunix_core::Registry;
+///
+/// struct AdminPort;
+///
+/// impl DepCtor<u16> for AdminPort {
+///     fn construct(
+///         registry: &Registry,
+///     ) -> Pin<Box<dyn Future<Output = u16> + Send + '_>> {
+///         Box::pin(async move {
+///             registry.get_transient::<u16>().await.unwrap_or(8080) + 1
+///         })
+///     }
+/// }
+///
+/// type AdminPortDep = Constructed<u16, AdminPort>;
+/// ```
+#[cfg(feature = "async")]
+pub trait DepCtor<T>: 'static {
+    /// Constructs a new `T`, using `registry`.
+    fn construct(
+        registry: &Registry,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send + '_>>;
+}
+
+/// A dependency constructed by `C`, see [`DepCtor`].
+///
+/// The constructor may resolve any types from the registry, they aren't
+/// validated as dependencies of the dependent type.
+#[cfg(feature = "async")]
+#[repr(transparent)]
+pub struct Constructed<T, C> {
+    /// The constructed type.
+    inner: T,
+    /// Marker for the constructor.
+    _marker: PhantomData<fn() -> C>,
+}
+
+#[cfg(feature = "async")]
+impl<T: std::fmt::Debug, C> std::fmt::Debug for Constructed<T, C> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("Constructed")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: Registerable, C: DepCtor<T>> Constructed<T, C> {
+    /// Access the inner `T`.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn get(self) -> T {
+        self.inner
+    }
+}
+
+// Required for implementing `Dep`.
+#[cfg(feature = "async")]
+impl<T, C> private::Sealed for Constructed<T, C> {}
+
+#[cfg(feature = "async")]
+impl<T: Registerable, C: DepCtor<T>> Dep for Constructed<T, C> {
+    /// Create a new [`Constructed`], by calling `C::construct`.
+    ///
+    /// # Errors
+    /// Never fails.
+    async fn new(registry: &Registry) -> Result<Self, ResolveError> {
+        Ok(Self {
+            inner: C::construct(registry).await,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns [`std::any::TypeId`] of the constructor `C`, which is never
+    /// registered.
+    fn type_id() -> TypeId {
+        TypeId::of::<C>()
+    }
+
+    /// Returns [`std::any::type_name`] of the inner type `T`.
+    fn type_name() -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    /// Returns `true`, the constructor is always available.
+    fn optional() -> bool {
+        true
+    }
+}
+
+/// A group of dependencies, resolved as a single dependency.
+///
+/// `G` is a tuple of dependencies, e.g., `(Singleton<Logger>,
+/// Singleton<Metrics>)`. This allows sharing a bundle of dependencies between
+/// many types, instead of repeating all of them in every dependency tuple.
+/// Groups can be nested.
+///
+/// # Example
+/// ```rust,ignore
+/// use ferrunix_core::dependencies::DepGroup;
+/// use ferrunix_core::{Singleton, Transient};
+///
+/// type Observability = DepGroup<(Singleton<Logger>, Singleton<Metrics>)>;
+///
+/// registry
+///     .with_deps::<_, (Observability, Transient<Config>)>()
+///     .transient(|(observability, config)| {
+///         let (logger, metrics) = observability.get();
+///         Service::new(logger.get(), metrics.get(), config.get())
+///     });
+/// ```
+pub struct DepGroup<G> {
+    /// The resolved dependencies.
+    inner: G,
+}
+
+impl<G: std::fmt::Debug> std::fmt::Debug for DepGroup<G> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("DepGroup")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<G> std::ops::Deref for DepGroup<G> {
+    type Target = G;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<G> std::ops::DerefMut for DepGroup<G> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<G> DepGroup<G> {
+    /// Access the inner tuple of dependencies.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn get(self) -> G {
+        self.inner
+    }
+}
+
+// Required for implementing `Dep`.
+impl<G> private::Sealed for DepGroup<G> {}
+
+/// Generates the implementation of [`Dep`] for [`DepGroup`]s of tuples.
+macro_rules! DepGroupImpl {
+    ({ $($ts:ident),+ }) => {
+        impl<$($ts,)*> Dep for DepGroup<($($ts,)*)>
+        where
+            $($ts: Dep,)*
+        {
+            /// Create a new [`DepGroup`], resolving all of its dependencies.
+            ///
+            /// # Errors
+            /// Returns an error if any of the dependencies couldn't be
+            /// resolved.
+            #[cfg(not(feature = "async"))]
+            fn new(registry: &Registry) -> Result<Self, ResolveError> {
+                Ok(Self {
+                    inner: ($(<$ts>::new(registry)?,)*),
+                })
+            }
+
+            /// Create a new [`DepGroup`], resolving all of its dependencies,
+            /// asynchronously.
+            ///
+            /// # Errors
+            /// Returns an error if any of the dependencies couldn't be
+            /// resolved.
+            #[cfg(feature = "async")]
+            async fn new(registry: &Registry) -> Result<Self, ResolveError> {
+                Ok(Self {
+                    inner: ($(<$ts>::new(registry).await?,)*),
+                })
+            }
+
+            /// Returns [`std::any::TypeId`] of the tuple of dependencies.
+            fn type_id() -> TypeId {
+                TypeId::of::<($($ts,)*)>()
+            }
+
+            /// Returns [`std::any::type_name`] of the tuple of dependencies.
+            fn type_name() -> &'static str {
+                std::any::type_name::<($($ts,)*)>()
+            }
+
+            /// Returns the [`DepInfo`]s of all dependencies in the group.
+            fn dep_infos(token: SealToken) -> Vec<DepInfo> {
+                let mut infos = Vec::new();
+                $(infos.extend(<$ts>::dep_infos(token));)*
+                infos
+            }
+        }
+    };
+}
+
+DepGroupImpl!({ T1 });
+DepGroupImpl!({ T1, T2 });
+DepGroupImpl!({ T1, T2, T3 });
+DepGroupImpl!({ T1, T2, T3, T4 });
+DepGroupImpl!({ T1, T2, T3, T4, T5 });
+DepGroupImpl!({ T1, T2, T3, T4, T5, T6 });
+DepGroupImpl!({ T1, T2, T3, T4, T5, T6, T7 });
+DepGroupImpl!({ T1, T2, T3, T4, T5, T6, T7, T8 });
@@ -3,6 +3,7 @@
 
 use std::any::TypeId;
 
+use crate::error::ResolveError;
 use crate::types::{Registerable, SingletonCtorDeps};
 use crate::Registry;
 
@@ -15,10 +16,32 @@ pub(crate) mod private {
     pub struct SealToken;
 }
 
+/// Description of a single dependency, as returned by
+/// [`DepBuilder::as_typeids`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DepInfo {
+    /// [`TypeId`] of the registered type the dependency resolves to.
+    pub(crate) type_id: TypeId,
+    /// Name of the registration, for named dependencies.
+    pub(crate) name: Option<&'static str>,
+    /// Type name of the registered type the dependency resolves to (as
+    /// returned by [`std::any::type_name`]).
+    pub(crate) type_name: &'static str,
+    /// Whether the dependency is optional, and isn't required to be
+    /// registered.
+    pub(crate) optional: bool,
+    /// Whether the dependency is a weak reference, which doesn't form a
+    /// cycle in the dependency graph.
+    pub(crate) weak: bool,
+    /// Whether the dependency resolves to all constructors registered with
+    /// [`Registry::register_all`].
+    pub(crate) collection: bool,
+}
+
 /// The [`DepBuilder`] trait is the key to specify a variable amount of
 /// dependencies in the [`Registry::with_deps`] call from [`Registry`].
 ///
-/// The trait is implemented by the `DepBuilderImpl!` macro for 0-ary, to 10-ary
+/// The trait is implemented by the `DepBuilderImpl!` macro for 0-ary, to 16-ary
 /// tuples (e.g., `(T1,)`, `(T1, T2)`, etc.), which allows these tuples to be
 /// passed as a single type parameter into [`Registry::with_deps`].
 ///
@@ -27,7 +50,7 @@ pub(crate) mod private {
 pub trait DepBuilder<R> {
     /// When implemented, this should validate that all dependencies which are
     /// part of `Self` exist to construct the type `R`. If the dependencies
-    /// cannot be fulfilled, `None` must be returned.
+    /// cannot be fulfilled, an error must be returned.
     ///
     /// If the dependencies can be fulfilled, they must be constructed as an
     /// N-ary tuple (same length and types as `Self`) and passed as the
@@ -37,34 +60,40 @@ pub trait DepBuilder<R> {
     /// An implementation for tuples is provided by `DepBuilderImpl!`.
     ///
     /// It's advised to avoid *manually* implementing `build`.
-    #[cfg(not(feature = "tokio"))]
+    ///
+    /// # Errors
+    /// Returns an error if any of the dependencies couldn't be constructed.
+    #[cfg(not(feature = "async"))]
     fn build(
         registry: &Registry,
         ctor: fn(Self) -> R,
         _: private::SealToken,
-    ) -> Option<R>
+    ) -> Result<R, ResolveError>
     where
         R: Sized;
 
-    /// Similar to [`DepBuilder::build`], except that it takes a boxed `dyn FnOnce` closure.
+    /// Similar to [`DepBuilder::build`], except that it takes a `dyn Fn` closure.
     /// This constructor is used for singletons.
     ///
     /// Similarly to `build`, this is also implemented by `DepBuilderImpl!`.
     ///
     /// It's advised to avoid *manually* implementing `build`.
-    #[cfg(not(feature = "tokio"))]
+    ///
+    /// # Errors
+    /// Returns an error if any of the dependencies couldn't be constructed.
+    #[cfg(not(feature = "async"))]
     fn build_once(
         registry: &Registry,
-        ctor: Box<dyn SingletonCtorDeps<R, Self>>,
+        ctor: &dyn SingletonCtorDeps<R, Self>,
         _: private::SealToken,
-    ) -> Option<R>
+    ) -> Result<R, ResolveError>
     where
         R: Sized,
         Self: Sized;
 
     /// When implemented, this should validate that all dependencies which are
     /// part of `Self` exist to construct the type `R`. If the dependencies
-    /// cannot be fulfilled, `None` must be returned.
+    /// cannot be fulfilled, an error must be returned.
     ///
     /// If the dependencies can be fulfilled, they must be constructed as an
     /// N-ary tuple (same length and types as `Self`) and passed as the
@@ -74,7 +103,7 @@ pub trait DepBuilder<R> {
     /// An implementation for tuples is provided by `DepBuilderImpl!`.
     ///
     /// We advise against *manually* implementing `build`.
-    #[cfg(feature = "tokio")]
+    #[cfg(feature = "async")]
     fn build(
         registry: &Registry,
         ctor: fn(
@@ -84,65 +113,106 @@ pub trait DepBuilder<R> {
         >,
         _: private::SealToken,
     ) -> std::pin::Pin<
-        Box<dyn std::future::Future<Output = Option<R>> + Send + '_>,
+        Box<
+            dyn std::future::Future<Output = Result<R, ResolveError>>
+                + Send
+                + '_,
+        >,
     >
     where
         R: Sized;
 
-    /// Similar to [`DepBuilder::build`], except that it takes a boxed `dyn FnOnce` closure.
+    /// Similar to [`DepBuilder::build`], except that it takes a `dyn Fn` closure.
     /// This constructor is used for singletons.
     ///
     /// Similarly to `build`, this is also implemented by `DepBuilderImpl!`.
     ///
     /// It's advised to avoid *manually* implementing `build`.
-    #[cfg(feature = "tokio")]
-    fn build_once(
-        registry: &Registry,
-        ctor: Box<dyn SingletonCtorDeps<R, Self>>,
+    #[cfg(feature = "async")]
+    fn build_once<'reg>(
+        registry: &'reg Registry,
+        ctor: &'reg dyn SingletonCtorDeps<R, Self>,
         _: private::SealToken,
     ) -> std::pin::Pin<
-        Box<dyn std::future::Future<Output = Option<R>> + Send + '_>,
+        Box<
+            dyn std::future::Future<Output = Result<R, ResolveError>>
+                + Send
+                + 'reg,
+        >,
     >
     where
         R: Sized,
         Self: Sized;
 
-    /// Constructs a [`Vec`] of [`std::any::TypeId`]s from the types in `Self`.
+    /// Resolves all dependencies in `Self`, without constructing `R`. This is
+    /// used by fallible constructors, which construct `R` themselves.
+    ///
+    /// Similarly to `build`, this is also implemented by `DepBuilderImpl!`.
+    ///
+    /// # Errors
+    /// Returns an error if any of the dependencies couldn't be constructed.
+    #[cfg(not(feature = "async"))]
+    fn resolve(
+        registry: &Registry,
+        _: private::SealToken,
+    ) -> Result<Self, ResolveError>
+    where
+        Self: Sized;
+
+    /// Resolves all dependencies in `Self`, without constructing `R`. This is
+    /// used by fallible constructors, which construct `R` themselves.
+    ///
+    /// Similarly to `build`, this is also implemented by `DepBuilderImpl!`.
+    #[cfg(feature = "async")]
+    fn resolve(
+        registry: &Registry,
+        _: private::SealToken,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<Output = Result<Self, ResolveError>>
+                + Send
+                + '_,
+        >,
+    >
+    where
+        Self: Sized;
+
+    /// Constructs a [`Vec`] of [`DepInfo`]s from the types in `Self`.
     /// The resulting vector must have the same length as `Self`.
     ///
     /// An implementation for tuples is provided by `DepBuilderImpl!`.
     ///
     /// We advise against *manually* implementing `as_typeids`.
-    fn as_typeids(_: private::SealToken) -> Vec<(TypeId, &'static str)>;
+    fn as_typeids(_: private::SealToken) -> Vec<DepInfo>;
 }
 
 impl<R> DepBuilder<R> for ()
 where
     R: Registerable,
 {
-    #[cfg(not(feature = "tokio"))]
+    #[cfg(not(feature = "async"))]
     fn build(
         _registry: &Registry,
         ctor: fn(Self) -> R,
         _: private::SealToken,
-    ) -> Option<R> {
-        Some(ctor(()))
+    ) -> Result<R, ResolveError> {
+        Ok(ctor(()))
     }
 
-    #[cfg(not(feature = "tokio"))]
+    #[cfg(not(feature = "async"))]
     fn build_once(
         _registry: &Registry,
-        ctor: Box<dyn SingletonCtorDeps<R, Self>>,
+        ctor: &dyn SingletonCtorDeps<R, Self>,
         _: private::SealToken,
-    ) -> Option<R>
+    ) -> Result<R, ResolveError>
     where
         R: Sized,
-        Self: Sized
+        Self: Sized,
     {
-        Some(ctor(()))
+        Ok(ctor(()))
     }
 
-    #[cfg(feature = "tokio")]
+    #[cfg(feature = "async")]
     fn build(
         _registry: &Registry,
         ctor: fn(
@@ -152,24 +222,53 @@ where
         >,
         _: private::SealToken,
     ) -> std::pin::Pin<
-        Box<dyn std::future::Future<Output = Option<R>> + Send + '_>,
+        Box<
+            dyn std::future::Future<Output = Result<R, ResolveError>>
+                + Send
+                + '_,
+        >,
     > {
-        Box::pin(async move { Some(ctor(()).await) })
+        Box::pin(async move { Ok(ctor(()).await) })
     }
 
-    #[cfg(feature = "tokio")]
-    fn build_once(
+    #[cfg(feature = "async")]
+    fn build_once<'reg>(
+        _registry: &'reg Registry,
+        ctor: &'reg dyn SingletonCtorDeps<R, Self>,
+        _: private::SealToken,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<Output = Result<R, ResolveError>>
+                + Send
+                + 'reg,
+        >,
+    > {
+        Box::pin(async move { Ok(ctor(()).await) })
+    }
+
+    #[cfg(not(feature = "async"))]
+    fn resolve(
+        _registry: &Registry,
+        _: private::SealToken,
+    ) -> Result<Self, ResolveError> {
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    fn resolve(
         _registry: &Registry,
-        ctor: Box<dyn SingletonCtorDeps<R, Self>>,
         _: private::SealToken,
     ) -> std::pin::Pin<
-        Box<dyn std::future::Future<Output = Option<R>> + Send + '_>,
-    >
-    {
-        Box::pin(async move { Some(ctor(()).await) })
+        Box<
+            dyn std::future::Future<Output = Result<Self, ResolveError>>
+                + Send
+                + '_,
+        >,
+    > {
+        Box::pin(async move { Ok(()) })
     }
 
-    fn as_typeids(_: private::SealToken) -> Vec<(TypeId, &'static str)> {
+    fn as_typeids(_: private::SealToken) -> Vec<DepInfo> {
         Vec::new()
     }
 }
@@ -182,46 +281,48 @@ macro_rules! DepBuilderImpl {
             R: $crate::types::Registerable,
             $($ts: $crate::dependencies::Dep,)*
         {
-            #[cfg(not(feature = "tokio"))]
-            fn build(registry: &$crate::registry::Registry, ctor: fn(Self) -> R, _: private::SealToken) -> Option<R> {
+            #[cfg(not(feature = "async"))]
+            fn build(registry: &$crate::registry::Registry, ctor: fn(Self) -> R, _: private::SealToken) -> Result<R, ResolveError> {
                 if registry.validate::<R>().is_err() {
-                    return None;
+                    return Err(registry.missing_dependencies_error::<R>());
                 }
 
                 let deps = (
                     $(
-                        <$ts>::new(registry),
+                        <$ts>::new(registry)
+                            .map_err(|err| err.with_type(::std::any::type_name::<R>()))?,
                     )*
                 );
 
-                Some(ctor(deps))
+                Ok(ctor(deps))
             }
 
-            #[cfg(not(feature = "tokio"))]
+            #[cfg(not(feature = "async"))]
             fn build_once(
                 registry: &Registry,
-                ctor: Box<dyn SingletonCtorDeps<R, Self>>,
+                ctor: &dyn SingletonCtorDeps<R, Self>,
                 _: private::SealToken,
-                ) -> Option<R>
+                ) -> Result<R, ResolveError>
                 where
                     R: Sized,
                     Self: Sized
                 {
                     if registry.validate::<R>().is_err() {
-                        return None;
+                        return Err(registry.missing_dependencies_error::<R>());
                     }
 
                     let deps = (
                         $(
-                            <$ts>::new(registry),
+                            <$ts>::new(registry)
+                                .map_err(|err| err.with_type(::std::any::type_name::<R>()))?,
                             )*
                     );
 
-                    Some(ctor(deps))
+                    Ok(ctor(deps))
                 }
 
 
-            #[cfg(feature = "tokio")]
+            #[cfg(feature = "async")]
             fn build(
                 registry: &Registry,
                 ctor: fn(
@@ -231,49 +332,96 @@ macro_rules! DepBuilderImpl {
                 >,
                 _: private::SealToken,
             ) -> std::pin::Pin<
-                Box<dyn std::future::Future<Output = Option<R>> + Send + '_>,
+                Box<dyn std::future::Future<Output = Result<R, ResolveError>> + Send + '_>,
             > {
                 if registry.validate::<R>().is_err() {
-                    return Box::pin(async move { None });
+                    let err = registry.missing_dependencies_error::<R>();
+                    return Box::pin(async move { Err(err) });
                 }
 
                 Box::pin(async move {
                     let deps = (
                         $(
-                            <$ts>::new(registry).await,
+                            <$ts>::new(registry).await
+                                .map_err(|err| err.with_type(::std::any::type_name::<R>()))?,
                         )*
                     );
 
-                    Some(ctor(deps).await)
+                    Ok(ctor(deps).await)
                 })
             }
 
-            #[cfg(feature = "tokio")]
-            fn build_once(
-                registry: &Registry,
-                ctor: Box<dyn SingletonCtorDeps<R, Self>>,
+            #[cfg(feature = "async")]
+            fn build_once<'reg>(
+                registry: &'reg Registry,
+                ctor: &'reg dyn SingletonCtorDeps<R, Self>,
                 _: private::SealToken,
                 ) -> std::pin::Pin<
-                Box<dyn std::future::Future<Output = Option<R>> + Send + '_>,
+                Box<dyn std::future::Future<Output = Result<R, ResolveError>> + Send + 'reg>,
                 >
             {
                 if registry.validate::<R>().is_err() {
-                    return Box::pin(async move { None });
+                    let err = registry.missing_dependencies_error::<R>();
+                    return Box::pin(async move { Err(err) });
                 }
 
                 Box::pin(async move {
                     let deps = (
                         $(
-                            <$ts>::new(registry).await,
+                            <$ts>::new(registry).await
+                                .map_err(|err| err.with_type(::std::any::type_name::<R>()))?,
                         )*
                     );
 
-                    Some(ctor(deps).await)
+                    Ok(ctor(deps).await)
+                })
+            }
+
+            #[cfg(not(feature = "async"))]
+            fn resolve(
+                registry: &Registry,
+                _: private::SealToken,
+            ) -> Result<Self, ResolveError> {
+                if registry.validate::<R>().is_err() {
+                    return Err(registry.missing_dependencies_error::<R>());
+                }
+
+                Ok((
+                    $(
+                        <$ts>::new(registry)
+                            .map_err(|err| err.with_type(::std::any::type_name::<R>()))?,
+                    )*
+                ))
+            }
+
+            #[cfg(feature = "async")]
+            fn resolve(
+                registry: &Registry,
+                _: private::SealToken,
+            ) -> std::pin::Pin<
+                Box<dyn std::future::Future<Output = Result<Self, ResolveError>> + Send + '_>,
+            > {
+                if registry.validate::<R>().is_err() {
+                    let err = registry.missing_dependencies_error::<R>();
+                    return Box::pin(async move { Err(err) });
+                }
+
+                Box::pin(async move {
+                    Ok((
+                        $(
+                            <$ts>::new(registry).await
+                                .map_err(|err| err.with_type(::std::any::type_name::<R>()))?,
+                        )*
+                    ))
                 })
             }
 
-            fn as_typeids(_: private::SealToken) -> ::std::vec::Vec<(::std::any::TypeId, &'static str)> {
-                ::std::vec![ $((<$ts>::type_id(), ::std::any::type_name::<$ts>()),)* ]
+            fn as_typeids(token: private::SealToken) -> ::std::vec::Vec<$crate::dependency_builder::DepInfo> {
+                let mut infos = ::std::vec::Vec::with_capacity($n);
+                $(
+                    infos.extend(<$ts>::dep_infos(token));
+                )*
+                infos
             }
         }
     };
@@ -285,5 +433,25 @@ DepBuilderImpl!(3, { T1, T2, T3 });
 DepBuilderImpl!(4, { T1, T2, T3, T4 });
 DepBuilderImpl!(5, { T1, T2, T3, T4, T5 });
 DepBuilderImpl!(6, { T1, T2, T3, T4, T5, T6 });
-DepBuilderImpl!(7, { T1, T2, T3, T4, T5, T6, T8 });
-DepBuilderImpl!(8, { T1, T2, T3, T4, T5, T6, T8, T9 });
+DepBuilderImpl!(7, { T1, T2, T3, T4, T5, T6, T7 });
+DepBuilderImpl!(8, { T1, T2, T3, T4, T5, T6, T7, T8 });
+DepBuilderImpl!(9, { T1, T2, T3, T4, T5, T6, T7, T8, T9 });
+DepBuilderImpl!(10, { T1, T2, T3, T4, T5, T6, T7, T8, T9, T10 });
+DepBuilderImpl!(11, { T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11 });
+DepBuilderImpl!(12, { T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12 });
+DepBuilderImpl!(13, {
+    T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12,
+    T13
+});
+DepBuilderImpl!(14, {
+    T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12,
+    T13, T14
+});
+DepBuilderImpl!(15, {
+    T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12,
+    T13, T14, T15
+});
+DepBuilderImpl!(16, {
+    T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12,
+    T13, T14, T15, T16
+});
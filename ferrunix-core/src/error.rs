@@ -1,8 +1,13 @@
 //! All errors that might happen.
 #![allow(clippy::module_name_repetitions)]
 
+use std::any::TypeId;
+
 use thiserror::Error;
 
+/// A boxed error, as returned by fallible constructors.
+pub type BoxErr = Box<dyn std::error::Error + Send + Sync + 'static>;
+
 /// Errors happening during resolving of lazy types.
 #[derive(Debug, Error)]
 #[non_exhaustive]
@@ -13,4 +18,215 @@ pub enum ResolveError {
     /// Some of the required dependencies are missing.
     #[error("couldn't resolve dependencies")]
     DependenciesMissing,
+    /// A cycle between dependencies has been detected.
+    #[error("dependency cycle detected")]
+    Cycle,
+    /// The requested type isn't registered.
+    #[error("type isn't registered")]
+    TypeMissing,
+    /// The registered object couldn't be downcast to the requested type.
+    ///
+    /// This indicates a bug in the registry, it's never returned for types
+    /// registered with the public API.
+    #[error(
+        "type mismatch resolving `{type_name}`: registered object was not a \
+         `{type_name}`"
+    )]
+    TypeMismatch {
+        /// Name of the requested type, as returned by
+        /// [`std::any::type_name`].
+        type_name: &'static str,
+        /// [`TypeId`] of the requested type.
+        type_id: TypeId,
+    },
+    /// The construction of the type took longer than its timeout.
+    #[error("construction timed out")]
+    ConstructionTimeout,
+    /// The registry was requested as dependency, but it isn't ref-counted.
+    ///
+    /// See [`Registry::into_ref`](crate::Registry::into_ref).
+    #[error("registry isn't ref-counted")]
+    RegistryNotRef,
+    /// The requested type isn't registered, and a parent registry has been
+    /// dropped while the child registry outlived it. The type might have
+    /// been registered on the dropped parent.
+    ///
+    /// See [`Registry::child`](crate::Registry::child).
+    #[error("type isn't registered, and a parent registry has been dropped")]
+    ParentDropped,
+    /// The fallible constructor of the type returned an error.
+    ///
+    /// The error of the constructor is returned by
+    /// [`std::error::Error::source`], for error reporters walking the chain.
+    #[error("constructor failed: {0}")]
+    Ctor(#[source] BoxErr),
+    /// Resolving a type along the dependency chain failed.
+    ///
+    /// The chain contains the names of all types along the resolution path,
+    /// from the requested type to the type that failed to resolve, e.g.,
+    /// `["A", "B", "D"]` for `A -> B -> D`.
+    #[error("couldn't resolve {}: {source}", .chain.join(" -> "))]
+    DependencyChain {
+        /// Names of the types along the resolution path, as returned by
+        /// [`std::any::type_name`].
+        chain: Vec<&'static str>,
+        /// The error of the last type in the chain.
+        source: Box<ResolveError>,
+    },
+}
+
+impl ResolveError {
+    /// Returns the names of all types along the resolution path that failed,
+    /// from the requested type to the type that failed to resolve.
+    ///
+    /// Returns an empty slice, if the error has no dependency chain.
+    #[must_use]
+    pub fn chain(&self) -> &[&'static str] {
+        match self {
+            Self::DependencyChain { chain, .. } => chain,
+            _ => &[],
+        }
+    }
+
+    /// Whether the error was caused by a type, or one of its dependencies,
+    /// that isn't registered.
+    #[must_use]
+    pub fn is_missing(&self) -> bool {
+        matches!(
+            self.root_cause(),
+            Self::TypeMissing | Self::DependenciesMissing
+        )
+    }
+
+    /// Whether the error was caused by a fallible constructor that returned
+    /// an error, see [`ResolveError::Ctor`].
+    #[must_use]
+    pub fn is_ctor_err(&self) -> bool {
+        matches!(self.root_cause(), Self::Ctor(_))
+    }
+
+    /// Whether the error was caused by a registered object of the wrong
+    /// type, see [`ResolveError::TypeMismatch`].
+    #[must_use]
+    pub fn is_type_mismatch(&self) -> bool {
+        matches!(self.root_cause(), Self::TypeMismatch { .. })
+    }
+
+    /// Returns the name of the type that isn't registered, if the error was
+    /// caused by a missing type.
+    ///
+    /// Returns `None` for all other errors, or if the name of the missing
+    /// type isn't known.
+    #[must_use]
+    pub fn missing_type_name(&self) -> Option<&'static str> {
+        match self.root_cause() {
+            Self::TypeMissing => self.chain().last().copied(),
+            _ => None,
+        }
+    }
+
+    /// Returns the error at the end of the dependency chain, or `self`, if
+    /// the error has no dependency chain.
+    fn root_cause(&self) -> &Self {
+        match self {
+            Self::DependencyChain { source, .. } => source.root_cause(),
+            err => err,
+        }
+    }
+
+    /// Returns the error for a registered object that isn't a `T`.
+    #[must_use]
+    pub(crate) fn type_mismatch<T: 'static>() -> Self {
+        Self::TypeMismatch {
+            type_name: std::any::type_name::<T>(),
+            type_id: TypeId::of::<T>(),
+        }
+    }
+
+    /// Returns the error for a failed fallible constructor of `T`.
+    #[must_use]
+    pub(crate) fn ctor<T>(err: BoxErr) -> Self {
+        Self::Ctor(err).with_type(std::any::type_name::<T>())
+    }
+
+    /// Prepends `type_name` to the dependency chain of this error.
+    #[must_use]
+    pub(crate) fn with_type(self, type_name: &'static str) -> Self {
+        match self {
+            Self::DependencyChain { mut chain, source } => {
+                chain.insert(0, type_name);
+                Self::DependencyChain { chain, source }
+            }
+            err => Self::DependencyChain {
+                chain: vec![type_name],
+                source: Box::new(err),
+            },
+        }
+    }
+}
+
+/// Errors happening during registration of types.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum RegistryError {
+    /// The type has already been registered.
+    #[error("type '{type_name}' is already registered")]
+    AlreadyRegistered {
+        /// Name of the type, as returned by [`std::any::type_name`].
+        type_name: &'static str,
+    },
+    /// The registry is frozen, no further types can be registered.
+    ///
+    /// See [`RegistryBuilder::build`](crate::registry::RegistryBuilder::build).
+    #[error("registry is frozen, can't register '{type_name}'")]
+    Frozen {
+        /// Name of the type, as returned by [`std::any::type_name`].
+        type_name: &'static str,
+    },
+}
+
+/// Errors happening during merging of registries.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum MergeError {
+    /// The type is registered in both registries.
+    #[error("type '{type_name}' is registered in both registries")]
+    Conflict {
+        /// Name of the type, as returned by [`std::any::type_name`].
+        type_name: &'static str,
+    },
+    /// The registry is frozen, no further types can be registered.
+    #[error("registry is frozen")]
+    Frozen,
+}
+
+/// Errors happening during the registration of types, see
+/// [`Registry::autoregistered_lenient`](crate::Registry::autoregistered_lenient).
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum RegistrationError {
+    /// A registration function panicked.
+    #[error("registration function panicked: {message}")]
+    Panicked {
+        /// The message of the panic, or `"unknown panic"` if the panic
+        /// payload isn't a string.
+        message: String,
+    },
+}
+
+impl RegistrationError {
+    /// Create a [`RegistrationError::Panicked`] from the `payload` of a
+    /// caught panic.
+    pub(crate) fn panicked(payload: Box<dyn std::any::Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => payload
+                .downcast_ref::<&'static str>()
+                .copied()
+                .unwrap_or("unknown panic")
+                .to_owned(),
+        };
+
+        Self::Panicked { message }
+    }
 }
@@ -7,20 +7,38 @@
     doc = "_You're viewing the documentation with the **`tokio` feature** turned on._\n\n"
 )]
 #![cfg_attr(
-    all(not(feature = "tokio"), not(feature = "multithread")),
+    all(feature = "async-std", not(feature = "tokio")),
+    doc = "_You're viewing the documentation with the **`async-std` feature** turned on._\n\n"
+)]
+#![cfg_attr(
+    all(not(feature = "async"), not(feature = "multithread")),
     doc = "#### _You're viewing the documentation with **`no features`** turned on._\n\n"
 )]
 //! Core types, traits, and implementations for [`ferrunix`].
 //!
 //! [`ferrunix`]: https://crates.io/crates/ferrunix
 
+#[cfg(all(
+    feature = "async",
+    not(any(feature = "tokio", feature = "async-std"))
+))]
+compile_error!(
+    "the `async` feature requires an async runtime, enable either the `tokio` \
+    or the `async-std` feature"
+);
+
 pub mod cycle_detection;
 pub mod dependencies;
 pub mod dependency_builder;
 pub mod error;
+#[cfg(all(not(feature = "multithread"), not(feature = "async")))]
+pub mod local;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 pub mod object_builder;
 pub mod registration;
 pub mod registry;
+pub mod scope;
 pub mod types;
 
 // Public re-exports for easier access.
@@ -0,0 +1,234 @@
+//! A single-threaded registry without interior mutability.
+//!
+//! [`LocalRegistry`] is an alternative to [`Registry`] for single-threaded
+//! programs that register all types upfront, and only resolve afterwards.
+//! Registration requires `&mut self`, which allows the registered objects
+//! to be stored in a plain hashtable, without the borrow-flag checks of a
+//! [`std::cell::RefCell`] on every resolve.
+//!
+//! Dependencies aren't declared, but resolved from within the constructor,
+//! and are therefore not validated. A type that (transitively) depends on
+//! itself overflows the stack when resolved.
+//!
+//! [`Registry`]: crate::Registry
+
+use std::any::{Any, TypeId};
+
+use crate::types::{
+    HashMap, OnceCell, Ref, Registerable, RegisterableSingleton,
+};
+
+/// Registry for single-threaded programs, that's mutably borrowed for
+/// registration, and immutably borrowed for resolution.
+///
+/// ```
+/// use ferrunix_core::local::LocalRegistry;
+///
+/// struct Config(u16);
+/// struct Server(u16);
+///
+/// let mut registry = LocalRegistry::empty();
+/// registry.singleton(|| Config(8080));
+/// registry.transient_with(|registry| {
+///     let config = registry.get_singleton::<Config>()?;
+///     Some(Server(config.0))
+/// });
+///
+/// let server = registry.get_transient::<Server>().unwrap();
+/// assert_eq!(server.0, 8080);
+/// ```
+pub struct LocalRegistry {
+    /// Internal hashtable of all registered objects, either a
+    /// [`LocalTransient`] or a [`LocalSingleton`].
+    objects: HashMap<TypeId, Box<dyn Any>>,
+}
+
+/// Constructor of a registered object.
+enum LocalCtor<T> {
+    /// Constructor without dependencies.
+    NoDeps(fn() -> T),
+    /// Constructor resolving its dependencies from the registry.
+    Deps(fn(&LocalRegistry) -> Option<T>),
+}
+
+impl<T> LocalCtor<T> {
+    /// Construct a new `T`, `None` if any dependency couldn't be resolved.
+    #[inline]
+    fn construct(&self, registry: &LocalRegistry) -> Option<T> {
+        match *self {
+            Self::NoDeps(ctor) => Some((ctor)()),
+            Self::Deps(ctor) => (ctor)(registry),
+        }
+    }
+}
+
+/// A registered transient object.
+struct LocalTransient<T> {
+    /// Constructor called for every requested `T`.
+    ctor: LocalCtor<T>,
+}
+
+/// A registered singleton object.
+struct LocalSingleton<T> {
+    /// Constructor called for the first requested `T`.
+    ctor: LocalCtor<T>,
+    /// The constructed singleton.
+    cell: OnceCell<Ref<T>>,
+}
+
+impl LocalRegistry {
+    /// Create a new, empty, registry.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self {
+            objects: HashMap::new(),
+        }
+    }
+
+    /// Register a new transient object, without dependencies.
+    ///
+    /// # Panics
+    /// When the type has been registered already.
+    pub fn transient<T>(&mut self, ctor: fn() -> T)
+    where
+        T: Registerable,
+    {
+        self.insert_or_panic::<T>(LocalTransient {
+            ctor: LocalCtor::NoDeps(ctor),
+        });
+    }
+
+    /// Register a new transient object, that resolves its dependencies from
+    /// the registry passed to `ctor`.
+    ///
+    /// `ctor` returns `None` if any of its dependencies couldn't be resolved.
+    ///
+    /// # Panics
+    /// When the type has been registered already.
+    pub fn transient_with<T>(&mut self, ctor: fn(&Self) -> Option<T>)
+    where
+        T: Registerable,
+    {
+        self.insert_or_panic::<T>(LocalTransient {
+            ctor: LocalCtor::Deps(ctor),
+        });
+    }
+
+    /// Register a new singleton object, without dependencies.
+    ///
+    /// The singleton is constructed lazily, when it's first requested.
+    ///
+    /// # Panics
+    /// When the type has been registered already.
+    pub fn singleton<T>(&mut self, ctor: fn() -> T)
+    where
+        T: RegisterableSingleton,
+    {
+        self.insert_or_panic::<T>(LocalSingleton {
+            ctor: LocalCtor::NoDeps(ctor),
+            cell: OnceCell::new(),
+        });
+    }
+
+    /// Register a new singleton object, that resolves its dependencies from
+    /// the registry passed to `ctor`.
+    ///
+    /// `ctor` returns `None` if any of its dependencies couldn't be resolved,
+    /// it's called again on the next request in that case.
+    ///
+    /// # Panics
+    /// When the type has been registered already.
+    pub fn singleton_with<T>(&mut self, ctor: fn(&Self) -> Option<T>)
+    where
+        T: RegisterableSingleton,
+    {
+        self.insert_or_panic::<T>(LocalSingleton {
+            ctor: LocalCtor::Deps(ctor),
+            cell: OnceCell::new(),
+        });
+    }
+
+    /// Retrieves a newly constructed `T` from this registry.
+    ///
+    /// Returns `None` if `T` wasn't registered as a transient, or any of its
+    /// dependencies couldn't be resolved.
+    #[must_use]
+    pub fn get_transient<T>(&self) -> Option<T>
+    where
+        T: Registerable,
+    {
+        let transient = self
+            .objects
+            .get(&TypeId::of::<T>())?
+            .downcast_ref::<LocalTransient<T>>()?;
+        transient.ctor.construct(self)
+    }
+
+    /// Retrieves the singleton `T` from this registry.
+    ///
+    /// Returns `None` if `T` wasn't registered as a singleton, or any of its
+    /// dependencies couldn't be resolved.
+    #[must_use]
+    pub fn get_singleton<T>(&self) -> Option<Ref<T>>
+    where
+        T: RegisterableSingleton,
+    {
+        let singleton = self
+            .objects
+            .get(&TypeId::of::<T>())?
+            .downcast_ref::<LocalSingleton<T>>()?;
+        if let Some(value) = singleton.cell.get() {
+            return Some(Ref::clone(value));
+        }
+
+        let value = singleton.ctor.construct(self)?;
+        Some(Ref::clone(singleton.cell.get_or_init(|| Ref::new(value))))
+    }
+
+    /// Check whether the type `T` is registered in this registry.
+    #[must_use]
+    pub fn contains<T>(&self) -> bool
+    where
+        T: Registerable,
+    {
+        self.objects.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Returns the number of types registered in this registry.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.objects.len()
+    }
+
+    /// Returns `true` if no types are registered in this registry.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty()
+    }
+
+    /// Inserts `value` for `T`, and panics if the type was previously
+    /// registered.
+    fn insert_or_panic<T: 'static>(&mut self, value: impl Any) {
+        assert!(
+            !self.objects.contains_key(&TypeId::of::<T>()),
+            "Type '{}' ({:?}) is already registered",
+            std::any::type_name::<T>(),
+            TypeId::of::<T>(),
+        );
+        self.objects.insert(TypeId::of::<T>(), Box::new(value));
+    }
+}
+
+impl Default for LocalRegistry {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl std::fmt::Debug for LocalRegistry {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("LocalRegistry")
+            .field("len", &self.objects.len())
+            .finish()
+    }
+}
@@ -0,0 +1,42 @@
+//! Metrics about resolved and constructed objects.
+//!
+//! Metrics are only collected when the `metrics` feature is enabled, and a
+//! sink is registered with [`Registry::set_metrics_sink`].
+//!
+//! [`Registry::set_metrics_sink`]: crate::Registry::set_metrics_sink
+
+use std::time::Duration;
+
+use crate::types::Ref;
+
+/// An event emitted to the metrics sink of a [`Registry`].
+///
+/// [`Registry`]: crate::Registry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MetricEvent {
+    /// An object of the type has been resolved.
+    ///
+    /// The duration includes the construction of the object, and of all its
+    /// dependencies, if any of them had to be constructed.
+    Resolved {
+        /// Name of the resolved type, as returned by [`std::any::type_name`].
+        type_name: &'static str,
+        /// Time it took to resolve the object.
+        duration: Duration,
+    },
+    /// The singleton of the type has been constructed.
+    ///
+    /// The duration includes the construction of all its dependencies, if
+    /// any of them had to be constructed.
+    SingletonConstructed {
+        /// Name of the constructed type, as returned by
+        /// [`std::any::type_name`].
+        type_name: &'static str,
+        /// Time it took to construct the singleton.
+        duration: Duration,
+    },
+}
+
+/// The metrics sink, as stored by the registry.
+pub(crate) type MetricsSink = Ref<dyn Fn(MetricEvent) + Send + Sync>;
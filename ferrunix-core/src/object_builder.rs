@@ -1,29 +1,162 @@
 //! Builder singleton or transient objects, with our without dependencies.
 
-#[cfg(not(feature = "tokio"))]
+#[cfg(not(feature = "async"))]
 #[path = "./object_builder_sync.rs"]
 pub(crate) mod inner;
 
-#[cfg(feature = "tokio")]
+#[cfg(feature = "async")]
 #[path = "./object_builder_async.rs"]
 pub(crate) mod inner;
 
 pub(crate) use inner::*;
 
+/// Measures the time it takes to resolve, or construct, an object, and
+/// reports it to the metrics sink of the registry.
+///
+/// Without the `metrics` feature, this is zero-sized and does nothing.
+#[derive(Clone, Copy)]
+pub(crate) struct Stopwatch {
+    /// When the measurement started.
+    #[cfg(feature = "metrics")]
+    start: std::time::Instant,
+}
+
+impl Stopwatch {
+    /// Start a new measurement.
+    #[inline]
+    pub(crate) fn start() -> Self {
+        Self {
+            #[cfg(feature = "metrics")]
+            start: std::time::Instant::now(),
+        }
+    }
+
+    /// Report that an object of type `T` has been resolved.
+    #[inline]
+    #[cfg_attr(
+        not(feature = "metrics"),
+        allow(unused_variables, clippy::unused_self)
+    )]
+    pub(crate) fn resolved<T>(self, registry: &crate::Registry) {
+        #[cfg(feature = "metrics")]
+        registry.emit_metric(crate::metrics::MetricEvent::Resolved {
+            type_name: std::any::type_name::<T>(),
+            duration: self.start.elapsed(),
+        });
+    }
+
+    /// Report that the singleton of type `T` has been constructed.
+    #[inline]
+    #[cfg_attr(
+        not(feature = "metrics"),
+        allow(unused_variables, clippy::unused_self)
+    )]
+    pub(crate) fn singleton_constructed<T>(self, registry: &crate::Registry) {
+        #[cfg(feature = "metrics")]
+        registry.emit_metric(
+            crate::metrics::MetricEvent::SingletonConstructed {
+                type_name: std::any::type_name::<T>(),
+                duration: self.start.elapsed(),
+            },
+        );
+    }
+}
+
 /// All possible "objects" that can be held by the registry.
-#[cfg(not(feature = "tokio"))]
+///
+/// The objects are ref-counted, so that they're constructed after the lock
+/// on the registered objects has been released. Constructors resolve their
+/// dependencies, which acquires the lock again, and would deadlock behind a
+/// registration that's waiting for the exclusive lock.
+#[cfg(not(feature = "async"))]
 pub(crate) enum Object {
     /// An object with transient lifetime.
-    Transient(crate::types::BoxedTransientBuilder),
+    Transient(crate::types::RefTransientBuilder),
     /// An object with singleton lifetime.
-    Singleton(crate::types::BoxedSingletonGetter),
+    Singleton(crate::types::RefSingletonGetter),
+    /// An object with scoped lifetime, constructed once per scope.
+    Scoped(crate::types::RefTransientBuilder),
+    /// A factory, constructing a new object from runtime arguments.
+    Factory(crate::types::RefFactory),
 }
 
 /// All possible "objects" that can be held by the registry.
-#[cfg(feature = "tokio")]
+///
+/// The objects are ref-counted, so that they're constructed after the lock
+/// on the registered objects has been released. Constructors resolve their
+/// dependencies, which acquires the lock again, and would deadlock behind a
+/// registration that's waiting for the exclusive lock.
+#[cfg(feature = "async")]
+#[allow(clippy::enum_variant_names)]
 pub(crate) enum Object {
     /// An object with transient lifetime.
-    AsyncTransient(Box<dyn AsyncTransientBuilder + Send + Sync>),
+    AsyncTransient(crate::types::RefTransientBuilder),
     /// An object with singleton lifetime.
-    AsyncSingleton(Box<dyn AsyncSingleton + Send + Sync>),
+    AsyncSingleton(crate::types::RefSingletonGetter),
+    /// An object with scoped lifetime, constructed once per scope.
+    AsyncScoped(crate::types::RefTransientBuilder),
+    /// A factory, constructing a new object from runtime arguments.
+    AsyncFactory(crate::types::RefFactory),
+}
+
+impl Object {
+    /// Returns a new object, using the same constructor as `self`. Objects
+    /// with singleton lifetime aren't constructed in the duplicate, unless
+    /// they were registered as pre-constructed instance.
+    #[cfg(not(feature = "async"))]
+    pub(crate) fn duplicate(&self) -> Self {
+        match self {
+            Self::Transient(transient) => {
+                Self::Transient(transient.duplicate().into())
+            }
+            Self::Singleton(singleton) => {
+                Self::Singleton(singleton.duplicate().into())
+            }
+            Self::Scoped(scoped) => Self::Scoped(scoped.duplicate().into()),
+            Self::Factory(factory) => {
+                Self::Factory(crate::types::Ref::clone(factory))
+            }
+        }
+    }
+
+    /// Returns a new object, using the same constructor as `self`. Objects
+    /// with singleton lifetime aren't constructed in the duplicate, unless
+    /// they were registered as pre-constructed instance.
+    #[cfg(feature = "async")]
+    pub(crate) fn duplicate(&self) -> Self {
+        match self {
+            Self::AsyncTransient(transient) => {
+                Self::AsyncTransient(transient.duplicate().into())
+            }
+            Self::AsyncSingleton(singleton) => {
+                Self::AsyncSingleton(singleton.duplicate().into())
+            }
+            Self::AsyncScoped(scoped) => {
+                Self::AsyncScoped(scoped.duplicate().into())
+            }
+            Self::AsyncFactory(factory) => {
+                Self::AsyncFactory(crate::types::Ref::clone(factory))
+            }
+        }
+    }
+}
+
+/// An [`Object`] held by the registry, together with information about the
+/// registered type.
+pub(crate) struct RegisteredObject {
+    /// Name of the registered type, as returned by [`std::any::type_name`].
+    pub(crate) type_name: &'static str,
+    /// The registered object.
+    pub(crate) object: Object,
+}
+
+impl RegisteredObject {
+    /// Returns a new registered object, using the same constructor as
+    /// `self`. See [`Object::duplicate`].
+    pub(crate) fn duplicate(&self) -> Self {
+        Self {
+            type_name: self.type_name,
+            object: self.object.duplicate(),
+        }
+    }
 }
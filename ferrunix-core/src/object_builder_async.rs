@@ -1,11 +1,34 @@
 //! Abstraction layer to build transient and singleton dependencies, asynchronously.
 use crate::dependency_builder::DepBuilder;
+use crate::error::{BoxErr, ResolveError};
+use crate::object_builder::Stopwatch;
 use crate::types::{
-    BoxedAny, Ref, RefAny, Registerable, RegisterableSingleton, RwLock,
-    SingletonCtor, SingletonCtorDeps,
+    BoxedAny, NonAsyncRwLock, OnceCell, Ref, RefAny, Registerable,
+    RegisterableSingleton, SingletonCtor, SingletonCtorDeps,
+    StatefulTransientCtor, WeakAny,
 };
 use crate::Registry;
 
+/// Awaits `future`, and fails with [`ResolveError::ConstructionTimeout`] if it
+/// doesn't complete within `timeout`.
+///
+/// # Panics
+/// With `tokio`, if the time driver of the runtime isn't enabled.
+async fn with_timeout<F>(
+    timeout: std::time::Duration,
+    future: F,
+) -> Result<F::Output, ResolveError>
+where
+    F: std::future::Future + Send,
+{
+    #[cfg(feature = "tokio")]
+    let output = ::tokio::time::timeout(timeout, future).await;
+    #[cfg(all(feature = "async-std", not(feature = "tokio")))]
+    let output = ::async_std::future::timeout(timeout, future).await;
+
+    output.ok().ok_or(ResolveError::ConstructionTimeout)
+}
+
 /// Trait to build a new object with transient lifetime.
 ///
 /// This trait is implemented twice, once to build objects without dependencies, and
@@ -22,8 +45,14 @@ pub(crate) trait AsyncTransientBuilder {
     ///
     /// <div class="warning">It must not use the global registry.</div>
     ///
-    /// May return `None` if the dependencies couldn't be fulfilled.
-    async fn make_transient(&self, registry: &Registry) -> Option<BoxedAny>;
+    /// Returns an error if the dependencies couldn't be fulfilled.
+    async fn make_transient(
+        &self,
+        registry: &Registry,
+    ) -> Result<BoxedAny, ResolveError>;
+
+    /// Returns a new builder, using the same constructor as `self`.
+    fn duplicate(&self) -> Box<dyn AsyncTransientBuilder + Send + Sync>;
 }
 
 /// Trait to build a new object with singleton lifetime.
@@ -33,6 +62,8 @@ pub(crate) trait AsyncTransientBuilder {
 ///
 ///   * [`AsyncSingletonNoDeps`]
 ///   * [`AsyncSingletonWithDeps`]
+///
+/// Pre-constructed objects are held by [`AsyncSingletonInstance`].
 #[async_trait::async_trait]
 pub(crate) trait AsyncSingleton {
     /// Constructs a new object; it may use the [`Registry`] to construct any
@@ -40,8 +71,33 @@ pub(crate) trait AsyncSingleton {
     ///
     /// <div class="warning">It must not use the global registry.</div>
     ///
-    /// May return `None` if the dependencies couldn't be fulfilled.
-    async fn get_singleton(&self, registry: &Registry) -> Option<RefAny>;
+    /// Returns an error if the dependencies couldn't be fulfilled.
+    async fn get_singleton(
+        &self,
+        registry: &Registry,
+    ) -> Result<RefAny, ResolveError>;
+
+    /// Returns the object, if it has already been constructed, without
+    /// constructing it.
+    fn get_if_init(&self) -> Option<RefAny>;
+
+    /// Drops the constructed object, if any, so that the next call to
+    /// [`AsyncSingleton::get_singleton`] constructs it again.
+    ///
+    /// Returns the dropped object, so that it can be dropped outside of any
+    /// locks.
+    fn invalidate(&mut self) -> Option<RefAny>;
+
+    /// Returns a weak handle to the object, which doesn't construct the
+    /// object, and doesn't keep it alive.
+    ///
+    /// The handle points to either the constructed `T`, or to the
+    /// `OnceCell<Ref<T>>` the object is constructed into.
+    fn weak_handle(&self) -> WeakAny;
+
+    /// Returns a new getter, using the same constructor as `self`, whose
+    /// object hasn't been constructed yet.
+    fn duplicate(&self) -> Box<dyn AsyncSingleton + Send + Sync>;
 }
 
 //          ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
@@ -74,9 +130,18 @@ where
     Self: Send + Sync,
     T: Registerable,
 {
-    async fn make_transient(&self, _: &Registry) -> Option<BoxedAny> {
+    async fn make_transient(
+        &self,
+        registry: &Registry,
+    ) -> Result<BoxedAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
         let obj = (self.ctor)().await;
-        Option::<BoxedAny>::Some(Box::new(obj))
+        stopwatch.resolved::<T>(registry);
+        Ok(Box::new(obj))
+    }
+
+    fn duplicate(&self) -> Box<dyn AsyncTransientBuilder + Send + Sync> {
+        Box::new(Self::new(self.ctor))
     }
 }
 
@@ -119,18 +184,23 @@ where
     Deps: DepBuilder<T> + 'static,
     T: Registerable,
 {
-    async fn make_transient(&self, registry: &Registry) -> Option<BoxedAny> {
-        #[allow(clippy::option_if_let_else)]
-        match Deps::build(
+    async fn make_transient(
+        &self,
+        registry: &Registry,
+    ) -> Result<BoxedAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
+        let obj = Deps::build(
             registry,
             self.ctor,
             crate::dependency_builder::private::SealToken,
         )
-        .await
-        {
-            Some(obj) => Some(Box::new(obj)),
-            None => None,
-        }
+        .await?;
+        stopwatch.resolved::<T>(registry);
+        Ok(Box::new(obj))
+    }
+
+    fn duplicate(&self) -> Box<dyn AsyncTransientBuilder + Send + Sync> {
+        Box::new(Self::new(self.ctor))
     }
 }
 
@@ -141,24 +211,44 @@ where
 /// Construct, and returns, a new singleton with no dependencies. Usually used through `dyn
 /// AsyncSingleton`.
 pub(crate) struct AsyncSingletonNoDeps<T> {
-    /// Constructor, returns a boxed future to `T`.
-    ctor: RwLock<Option<Box<dyn SingletonCtor<T>>>>,
+    /// Constructor, returns a boxed future to `T`. It's shared with the
+    /// duplicates of this singleton.
+    ctor: Ref<dyn SingletonCtor<T>>,
     /// Cell containing the constructed `T`.
-    cell: ::tokio::sync::OnceCell<Ref<T>>,
+    cell: Ref<OnceCell<Ref<T>>>,
+    /// Maximum duration of the construction, if any.
+    timeout: Option<std::time::Duration>,
 }
 
 impl<T> AsyncSingletonNoDeps<T> {
     /// Create a new [`SingletonGetter`] using `ctor` to create new objects.
     /// Objects are stored internally in `cell`.
     ///
-    /// `ctor` may contain side-effects. It's guaranteed to be only called once (for each thread).
+    /// `ctor` may contain side-effects. It's only called again after the
+    /// singleton has been invalidated.
     pub(crate) fn new<F>(ctor: F) -> Self
     where
         F: SingletonCtor<T>,
     {
         Self {
-            ctor: RwLock::new(Some(Box::new(ctor))),
-            cell: ::tokio::sync::OnceCell::new(),
+            ctor: Ref::new(ctor),
+            cell: Ref::new(OnceCell::new()),
+            timeout: None,
+        }
+    }
+
+    /// Create a new [`AsyncSingleton`] using `ctor` to create new objects,
+    /// which fails if the construction takes longer than `timeout`.
+    ///
+    /// A construction that timed out isn't cached, the next request calls
+    /// `ctor` again.
+    pub(crate) fn with_timeout<F>(ctor: F, timeout: std::time::Duration) -> Self
+    where
+        F: SingletonCtor<T>,
+    {
+        Self {
+            timeout: Some(timeout),
+            ..Self::new(ctor)
         }
     }
 }
@@ -169,20 +259,51 @@ where
     Self: Send,
     T: RegisterableSingleton,
 {
-    async fn get_singleton(&self, _registry: &Registry) -> Option<RefAny> {
+    async fn get_singleton(
+        &self,
+        registry: &Registry,
+    ) -> Result<RefAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
         let rc = self
             .cell
-            .get_or_init(move || async move {
-                let ctor = {
-                    let mut lock = self.ctor.write().await;
-                    lock.take().expect("to be called only once")
+            .get_or_try_init(move || async move {
+                let obj = match self.timeout {
+                    Some(timeout) => {
+                        with_timeout(timeout, (self.ctor)()).await?
+                    }
+                    None => (self.ctor)().await,
                 };
-                let obj = (ctor)().await;
-                Ref::new(obj)
+                stopwatch.singleton_constructed::<T>(registry);
+                Ok::<_, ResolveError>(Ref::new(obj))
             })
-            .await;
+            .await?;
         let rc = Ref::clone(rc) as RefAny;
-        Option::<RefAny>::Some(rc)
+        stopwatch.resolved::<T>(registry);
+        Ok(rc)
+    }
+
+    fn get_if_init(&self) -> Option<RefAny> {
+        self.cell.get().map(|rc| Ref::clone(rc) as RefAny)
+    }
+
+    /// Replaces the cell, instead of taking its value, since [`WeakAny`]
+    /// handles to the cell might exist. They're not updated after
+    /// invalidation.
+    fn invalidate(&mut self) -> Option<RefAny> {
+        let cell = std::mem::replace(&mut self.cell, Ref::new(OnceCell::new()));
+        cell.get().map(|rc| Ref::clone(rc) as RefAny)
+    }
+
+    fn weak_handle(&self) -> WeakAny {
+        Ref::downgrade(&self.cell) as WeakAny
+    }
+
+    fn duplicate(&self) -> Box<dyn AsyncSingleton + Send + Sync> {
+        Box::new(Self {
+            ctor: Ref::clone(&self.ctor),
+            cell: Ref::new(OnceCell::new()),
+            timeout: self.timeout,
+        })
     }
 }
 
@@ -195,24 +316,26 @@ where
 ///
 /// The dependency tuple `Deps` must implement [`DepBuilder<T>`].
 pub(crate) struct AsyncSingletonWithDeps<T, Deps> {
-    /// Constructor, returns a boxed future to `T`.
-    ctor: RwLock<Option<Box<dyn SingletonCtorDeps<T, Deps>>>>,
+    /// Constructor, returns a boxed future to `T`. It's shared with the
+    /// duplicates of this singleton.
+    ctor: Ref<dyn SingletonCtorDeps<T, Deps>>,
     /// Cell containing the constructed `T`.
-    cell: ::tokio::sync::OnceCell<Ref<T>>,
+    cell: Ref<OnceCell<Ref<T>>>,
 }
 
 impl<T, Deps> AsyncSingletonWithDeps<T, Deps> {
     /// Create a new [`SingletonGetter`] using `ctor` to create new objects.
     /// Objects are stored internally in `cell`.
     ///
-    /// `ctor` may contain side-effects. It's guaranteed to be only called once (for each thread).
+    /// `ctor` may contain side-effects. It's only called again after the
+    /// singleton has been invalidated.
     pub(crate) fn new<F>(ctor: F) -> Self
     where
         F: SingletonCtorDeps<T, Deps>,
     {
         Self {
-            ctor: RwLock::new(Some(Box::new(ctor))),
-            cell: ::tokio::sync::OnceCell::new(),
+            ctor: Ref::new(ctor),
+            cell: Ref::new(OnceCell::new()),
         }
     }
 }
@@ -224,29 +347,409 @@ where
     Deps: DepBuilder<T> + 'static,
     T: RegisterableSingleton,
 {
-    async fn get_singleton(&self, registry: &Registry) -> Option<RefAny> {
-        let ctor = {
-            let mut lock = self.ctor.write().await;
-            lock.take().expect("to be called only once")
-        };
+    async fn get_singleton(
+        &self,
+        registry: &Registry,
+    ) -> Result<RefAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
+        let rc = self
+            .cell
+            .get_or_try_init(move || async move {
+                let obj = Deps::build_once(
+                    registry,
+                    &*self.ctor,
+                    crate::dependency_builder::private::SealToken,
+                )
+                .await
+                .map(Ref::new)?;
+                stopwatch.singleton_constructed::<T>(registry);
+                Ok::<_, ResolveError>(obj)
+            })
+            .await?;
+        let rc = Ref::clone(rc) as RefAny;
+        stopwatch.resolved::<T>(registry);
+        Ok(rc)
+    }
+
+    fn get_if_init(&self) -> Option<RefAny> {
+        self.cell.get().map(|rc| Ref::clone(rc) as RefAny)
+    }
+
+    /// Replaces the cell, instead of taking its value, since [`WeakAny`]
+    /// handles to the cell might exist. They're not updated after
+    /// invalidation.
+    fn invalidate(&mut self) -> Option<RefAny> {
+        let cell = std::mem::replace(&mut self.cell, Ref::new(OnceCell::new()));
+        cell.get().map(|rc| Ref::clone(rc) as RefAny)
+    }
+
+    fn weak_handle(&self) -> WeakAny {
+        Ref::downgrade(&self.cell) as WeakAny
+    }
+
+    fn duplicate(&self) -> Box<dyn AsyncSingleton + Send + Sync> {
+        Box::new(Self {
+            ctor: Ref::clone(&self.ctor),
+            cell: Ref::new(OnceCell::new()),
+        })
+    }
+}
+
+//          ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+//          ┃              TRANSIENT (fallible, no deps)              ┃
+//          ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+/// Construct a new transient with no dependencies, using a fallible
+/// constructor. Usually used through `dyn AsyncTransientBuilder`.
+pub(crate) struct AsyncTryTransientBuilderNoDeps<T> {
+    /// Constructor, returns a boxed future to `T`, or to the error why it
+    /// couldn't be constructed.
+    ctor: fn() -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<T, BoxErr>> + Send>,
+    >,
+}
 
-        #[allow(clippy::option_if_let_else)]
-        match Deps::build_once(
+impl<T> AsyncTryTransientBuilderNoDeps<T> {
+    /// Create a new [`AsyncTransientBuilder`] using `ctor` to create new objects.
+    ///
+    /// `ctor` should not have side-effects. It may be called multiple times.
+    pub(crate) fn new(
+        ctor: fn() -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = Result<T, BoxErr>> + Send>,
+        >,
+    ) -> Self {
+        Self { ctor }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T> AsyncTransientBuilder for AsyncTryTransientBuilderNoDeps<T>
+where
+    Self: Send + Sync,
+    T: Registerable,
+{
+    async fn make_transient(
+        &self,
+        registry: &Registry,
+    ) -> Result<BoxedAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
+        let obj = (self.ctor)().await.map_err(ResolveError::ctor::<T>)?;
+        stopwatch.resolved::<T>(registry);
+        Ok(Box::new(obj))
+    }
+
+    fn duplicate(&self) -> Box<dyn AsyncTransientBuilder + Send + Sync> {
+        Box::new(Self::new(self.ctor))
+    }
+}
+
+//          ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+//          ┃             TRANSIENT (fallible, with deps)             ┃
+//          ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+/// Construct a new transient with any number of dependencies, using a
+/// fallible constructor. Usually used through `dyn AsyncTransientBuilder`.
+///
+/// The dependency tuple `Deps` must implement [`DepBuilder<T>`].
+pub(crate) struct AsyncTryTransientBuilderWithDeps<T, Deps> {
+    /// Constructor, returns a boxed future to `T`, or to the error why it
+    /// couldn't be constructed.
+    ctor: fn(
+        Deps,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<T, BoxErr>> + Send>,
+    >,
+}
+
+impl<T, Deps> AsyncTryTransientBuilderWithDeps<T, Deps> {
+    /// Create a new [`AsyncTransientBuilder`] using `ctor` to create new objects.
+    ///
+    /// `ctor` should not have side-effects. It may be called multiple times.
+    pub(crate) fn new(
+        ctor: fn(
+            Deps,
+        ) -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = Result<T, BoxErr>> + Send>,
+        >,
+    ) -> Self {
+        Self { ctor }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, Deps> AsyncTransientBuilder
+    for AsyncTryTransientBuilderWithDeps<T, Deps>
+where
+    Self: Send,
+    Deps: DepBuilder<T> + 'static,
+    T: Registerable,
+{
+    async fn make_transient(
+        &self,
+        registry: &Registry,
+    ) -> Result<BoxedAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
+        let deps = Deps::resolve(
             registry,
-            ctor,
             crate::dependency_builder::private::SealToken,
         )
-        .await
-        {
-            Some(obj) => {
-                let rc = self
-                    .cell
-                    .get_or_init(move || async move { Ref::new(obj) })
-                    .await;
-                let rc = Ref::clone(rc) as RefAny;
-                Option::<RefAny>::Some(rc)
-            }
-            None => None,
+        .await?;
+        let obj = (self.ctor)(deps).await.map_err(ResolveError::ctor::<T>)?;
+        stopwatch.resolved::<T>(registry);
+        Ok(Box::new(obj))
+    }
+
+    fn duplicate(&self) -> Box<dyn AsyncTransientBuilder + Send + Sync> {
+        Box::new(Self::new(self.ctor))
+    }
+}
+
+//          ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+//          ┃                  SINGLETON (fallible)                   ┃
+//          ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+/// Construct a new singleton with any number of dependencies, including
+/// none, using a fallible constructor. Usually used through `dyn
+/// AsyncSingleton`.
+///
+/// The dependency tuple `Deps` must implement [`DepBuilder<T>`].
+pub(crate) struct AsyncTrySingleton<T, Deps> {
+    /// Constructor, returns a boxed future to `T`, or to the error why it
+    /// couldn't be constructed. It's shared with the duplicates of this
+    /// singleton.
+    ctor: Ref<dyn SingletonCtorDeps<Result<T, BoxErr>, Deps>>,
+    /// Cell containing the constructed `T`.
+    cell: Ref<OnceCell<Ref<T>>>,
+}
+
+impl<T, Deps> AsyncTrySingleton<T, Deps> {
+    /// Create a new [`AsyncSingleton`] using `ctor` to create new objects.
+    /// Objects are stored internally in `cell`.
+    ///
+    /// `ctor` may contain side-effects. It's called again on the next
+    /// request, if it failed, or after the singleton has been invalidated.
+    pub(crate) fn new<F>(ctor: F) -> Self
+    where
+        F: SingletonCtorDeps<Result<T, BoxErr>, Deps>,
+    {
+        Self {
+            ctor: Ref::new(ctor),
+            cell: Ref::new(OnceCell::new()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, Deps> AsyncSingleton for AsyncTrySingleton<T, Deps>
+where
+    Self: Send,
+    Deps: DepBuilder<T> + 'static,
+    T: RegisterableSingleton,
+{
+    async fn get_singleton(
+        &self,
+        registry: &Registry,
+    ) -> Result<RefAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
+        let rc = self
+            .cell
+            .get_or_try_init(move || async move {
+                let deps = Deps::resolve(
+                    registry,
+                    crate::dependency_builder::private::SealToken,
+                )
+                .await?;
+                let obj =
+                    (self.ctor)(deps).await.map_err(ResolveError::ctor::<T>)?;
+                stopwatch.singleton_constructed::<T>(registry);
+                Ok::<_, ResolveError>(Ref::new(obj))
+            })
+            .await?;
+        let rc = Ref::clone(rc) as RefAny;
+        stopwatch.resolved::<T>(registry);
+        Ok(rc)
+    }
+
+    fn get_if_init(&self) -> Option<RefAny> {
+        self.cell.get().map(|rc| Ref::clone(rc) as RefAny)
+    }
+
+    /// Replaces the cell, instead of taking its value, since [`WeakAny`]
+    /// handles to the cell might exist. They're not updated after
+    /// invalidation.
+    fn invalidate(&mut self) -> Option<RefAny> {
+        let cell = std::mem::replace(&mut self.cell, Ref::new(OnceCell::new()));
+        cell.get().map(|rc| Ref::clone(rc) as RefAny)
+    }
+
+    fn weak_handle(&self) -> WeakAny {
+        Ref::downgrade(&self.cell) as WeakAny
+    }
+
+    fn duplicate(&self) -> Box<dyn AsyncSingleton + Send + Sync> {
+        Box::new(Self {
+            ctor: Ref::clone(&self.ctor),
+            cell: Ref::new(OnceCell::new()),
+        })
+    }
+}
+
+//          ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+//          ┃                  SINGLETON (instance)                   ┃
+//          ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+/// Holds a pre-constructed singleton. Usually used through `dyn
+/// AsyncSingleton`.
+pub(crate) struct AsyncSingletonInstance<T> {
+    /// The pre-constructed `T`.
+    instance: Ref<T>,
+}
+
+impl<T> AsyncSingletonInstance<T> {
+    /// Create a new [`AsyncSingleton`] returning `value`, without ever
+    /// calling a constructor.
+    pub(crate) fn new(value: T) -> Self {
+        Self {
+            instance: Ref::new(value),
         }
     }
 }
+
+#[async_trait::async_trait]
+impl<T> AsyncSingleton for AsyncSingletonInstance<T>
+where
+    Self: Send,
+    T: RegisterableSingleton,
+{
+    async fn get_singleton(
+        &self,
+        registry: &Registry,
+    ) -> Result<RefAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
+        let rc = Ref::clone(&self.instance) as RefAny;
+        stopwatch.resolved::<T>(registry);
+        Ok(rc)
+    }
+
+    fn get_if_init(&self) -> Option<RefAny> {
+        Some(Ref::clone(&self.instance) as RefAny)
+    }
+
+    /// Pre-constructed instances can't be constructed again, therefore,
+    /// they're never invalidated.
+    fn invalidate(&mut self) -> Option<RefAny> {
+        None
+    }
+
+    fn weak_handle(&self) -> WeakAny {
+        Ref::downgrade(&self.instance) as WeakAny
+    }
+
+    /// Pre-constructed instances can't be constructed again, therefore, the
+    /// duplicate shares the instance.
+    fn duplicate(&self) -> Box<dyn AsyncSingleton + Send + Sync> {
+        Box::new(Self {
+            instance: Ref::clone(&self.instance),
+        })
+    }
+}
+
+//          ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+//          ┃                    TRANSIENT (alias)                    ┃
+//          ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+/// Construct a new transient `A` by converting the transient `C`, which is
+/// resolved from the registry. Usually used through
+/// `dyn AsyncTransientBuilder`.
+pub(crate) struct AsyncAliasTransientBuilder<C, A> {
+    /// Conversion from the resolved `C` into the alias `A`.
+    convert: fn(C) -> A,
+}
+
+impl<C, A> AsyncAliasTransientBuilder<C, A> {
+    /// Create a new [`AsyncTransientBuilder`] converting the transient `C`
+    /// into new objects, using `convert`.
+    pub(crate) fn new(convert: fn(C) -> A) -> Self {
+        Self { convert }
+    }
+}
+
+#[async_trait::async_trait]
+impl<C, A> AsyncTransientBuilder for AsyncAliasTransientBuilder<C, A>
+where
+    C: Registerable,
+    A: Registerable,
+{
+    async fn make_transient(
+        &self,
+        registry: &Registry,
+    ) -> Result<BoxedAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
+        let concrete = registry
+            .resolve_transient::<C>(None)
+            .await
+            .map_err(|err| err.with_type(std::any::type_name::<A>()))?;
+        let obj = (self.convert)(concrete);
+        stopwatch.resolved::<A>(registry);
+        Ok(Box::new(obj))
+    }
+
+    fn duplicate(&self) -> Box<dyn AsyncTransientBuilder + Send + Sync> {
+        Box::new(Self::new(self.convert))
+    }
+}
+
+//          ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+//          ┃                  TRANSIENT (stateful)                   ┃
+//          ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+/// Construct a new transient with a stateful constructor, that's called with
+/// exclusive access. Usually used through `dyn AsyncTransientBuilder`.
+pub(crate) struct AsyncStatefulTransientBuilder<T, C> {
+    /// Constructor, returns a future of a new `T`. It's shared with all
+    /// duplicates of the builder.
+    ctor: Ref<NonAsyncRwLock<C>>,
+    /// The constructed type.
+    _marker: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T, C> AsyncStatefulTransientBuilder<T, C> {
+    /// Create a new [`AsyncTransientBuilder`] using `ctor` to create new
+    /// objects.
+    pub(crate) fn new(ctor: C) -> Self {
+        Self {
+            ctor: Ref::new(NonAsyncRwLock::new(ctor)),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, C> AsyncTransientBuilder for AsyncStatefulTransientBuilder<T, C>
+where
+    T: Registerable,
+    C: StatefulTransientCtor<T>,
+{
+    async fn make_transient(
+        &self,
+        registry: &Registry,
+    ) -> Result<BoxedAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
+        // Only the call of the constructor is exclusive, the lock is released
+        // before the returned future is awaited.
+        let future = {
+            let mut ctor = self.ctor.write();
+            (*ctor)()
+        };
+        let obj = future.await;
+        stopwatch.resolved::<T>(registry);
+        Ok(Box::new(obj))
+    }
+
+    fn duplicate(&self) -> Box<dyn AsyncTransientBuilder + Send + Sync> {
+        Box::new(Self {
+            ctor: Ref::clone(&self.ctor),
+            _marker: std::marker::PhantomData,
+        })
+    }
+}
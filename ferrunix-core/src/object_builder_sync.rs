@@ -1,8 +1,11 @@
 //! Abstraction layer to build transient and singleton dependencies.
 use crate::dependency_builder::DepBuilder;
+use crate::error::{BoxErr, ResolveError};
+use crate::object_builder::Stopwatch;
 use crate::types::{
-    BoxedAny, OnceCell, Ref, RefAny, Registerable, RegisterableSingleton,
-    RwLock, SingletonCtor, SingletonCtorDeps,
+    BoxedAny, BoxedSingletonGetter, BoxedTransientBuilder, NonAsyncRwLock,
+    OnceCell, Ref, RefAny, Registerable, RegisterableSingleton, SingletonCtor,
+    SingletonCtorDeps, StatefulTransientCtor, WeakAny,
 };
 use crate::Registry;
 
@@ -19,8 +22,14 @@ pub(crate) trait TransientBuilder {
     ///
     /// <div class="warning">It must not use the global registry.</div>
     ///
-    /// May return `None` if the dependencies couldn't be fulfilled.
-    fn make_transient(&self, registry: &Registry) -> Option<BoxedAny>;
+    /// Returns an error if the dependencies couldn't be fulfilled.
+    fn make_transient(
+        &self,
+        registry: &Registry,
+    ) -> Result<BoxedAny, ResolveError>;
+
+    /// Returns a new builder, using the same constructor as `self`.
+    fn duplicate(&self) -> BoxedTransientBuilder;
 }
 
 /// Trait to build a new object with singleton lifetime.
@@ -30,14 +39,43 @@ pub(crate) trait TransientBuilder {
 ///
 ///   * [`SingletonGetterNoDeps`]
 ///   * [`SingletonGetterWithDeps`]
+///
+/// Pre-constructed objects are held by [`SingletonGetterInstance`].
 pub(crate) trait SingletonGetter {
     /// Constructs a new object; it may use the [`Registry`] to construct any
     /// dependencies.
     ///
     /// <div class="warning">It must not use the global registry.</div>
     ///
-    /// May return `None` if the dependencies couldn't be fulfilled.
-    fn get_singleton(&self, registry: &Registry) -> Option<RefAny>;
+    /// Returns an error if the dependencies couldn't be fulfilled.
+    ///
+    /// Once the object is constructed, this must not acquire any lock.
+    fn get_singleton(
+        &self,
+        registry: &Registry,
+    ) -> Result<RefAny, ResolveError>;
+
+    /// Returns the object, if it has already been constructed, without
+    /// constructing it.
+    fn get_if_init(&self) -> Option<RefAny>;
+
+    /// Drops the constructed object, if any, so that the next call to
+    /// [`SingletonGetter::get_singleton`] constructs it again.
+    ///
+    /// Returns the dropped object, so that it can be dropped outside of any
+    /// locks.
+    fn invalidate(&mut self) -> Option<RefAny>;
+
+    /// Returns a weak handle to the object, which doesn't construct the
+    /// object, and doesn't keep it alive.
+    ///
+    /// The handle points to either the constructed `T`, or to the
+    /// `OnceCell<Ref<T>>` the object is constructed into.
+    fn weak_handle(&self) -> WeakAny;
+
+    /// Returns a new getter, using the same constructor as `self`, whose
+    /// object hasn't been constructed yet.
+    fn duplicate(&self) -> BoxedSingletonGetter;
 }
 
 //          ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
@@ -63,9 +101,18 @@ impl<T> TransientBuilder for TransientBuilderImplNoDeps<T>
 where
     T: Registerable,
 {
-    fn make_transient(&self, _registry: &Registry) -> Option<BoxedAny> {
+    fn make_transient(
+        &self,
+        registry: &Registry,
+    ) -> Result<BoxedAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
         let obj = (self.ctor)();
-        Some(Box::new(obj))
+        stopwatch.resolved::<T>(registry);
+        Ok(Box::new(obj))
+    }
+
+    fn duplicate(&self) -> BoxedTransientBuilder {
+        Box::new(Self::new(self.ctor))
     }
 }
 
@@ -96,16 +143,22 @@ where
     Deps: DepBuilder<T> + 'static,
     T: Registerable,
 {
-    fn make_transient(&self, registry: &Registry) -> Option<BoxedAny> {
-        #[allow(clippy::option_if_let_else)]
-        match Deps::build(
+    fn make_transient(
+        &self,
+        registry: &Registry,
+    ) -> Result<BoxedAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
+        let obj = Deps::build(
             registry,
             self.ctor,
             crate::dependency_builder::private::SealToken,
-        ) {
-            Some(obj) => Some(Box::new(obj)),
-            None => None,
-        }
+        )?;
+        stopwatch.resolved::<T>(registry);
+        Ok(Box::new(obj))
+    }
+
+    fn duplicate(&self) -> BoxedTransientBuilder {
+        Box::new(Self::new(self.ctor))
     }
 }
 
@@ -116,24 +169,26 @@ where
 /// Construct, and returns, a new singleton with no dependencies. Usually used through `dyn
 /// SingletonGetter`.
 pub(crate) struct SingletonGetterNoDeps<T> {
-    /// Constructor, returns a new `T`.
-    ctor: RwLock<Option<Box<dyn SingletonCtor<T>>>>,
+    /// Constructor, returns a new `T`. It's shared with the duplicates of
+    /// this getter.
+    ctor: Ref<dyn SingletonCtor<T>>,
     /// Cell containing the constructed `T`.
-    cell: OnceCell<Ref<T>>,
+    cell: Ref<OnceCell<Ref<T>>>,
 }
 
 impl<T> SingletonGetterNoDeps<T> {
     /// Create a new [`SingletonGetter`] using `ctor` to create new objects.
     /// Objects are stored internally in `cell`.
     ///
-    /// `ctor` may contain side-effects. It's guaranteed to be only called once (for each thread).
+    /// `ctor` may contain side-effects. It's only called again after the
+    /// singleton has been invalidated.
     pub(crate) fn new<F>(ctor: F) -> Self
     where
         F: SingletonCtor<T>,
     {
         Self {
-            ctor: RwLock::new(Some(Box::new(ctor))),
-            cell: OnceCell::new(),
+            ctor: Ref::new(ctor),
+            cell: Ref::new(OnceCell::new()),
         }
     }
 }
@@ -142,16 +197,47 @@ impl<T> SingletonGetter for SingletonGetterNoDeps<T>
 where
     T: RegisterableSingleton,
 {
-    fn get_singleton(&self, _registry: &Registry) -> Option<RefAny> {
+    fn get_singleton(
+        &self,
+        registry: &Registry,
+    ) -> Result<RefAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
+        if let Some(rc) = self.get_if_init() {
+            stopwatch.resolved::<T>(registry);
+            return Ok(rc);
+        }
+
         let rc = self.cell.get_or_init(|| {
-            let ctor = {
-                let mut lock = self.ctor.write();
-                lock.take().expect("to be called only once")
-            };
-            Ref::new((ctor)())
+            let obj = Ref::new((self.ctor)());
+            stopwatch.singleton_constructed::<T>(registry);
+            obj
         });
         let rc = Ref::clone(rc) as RefAny;
-        Some(rc)
+        stopwatch.resolved::<T>(registry);
+        Ok(rc)
+    }
+
+    fn get_if_init(&self) -> Option<RefAny> {
+        self.cell.get().map(|rc| Ref::clone(rc) as RefAny)
+    }
+
+    /// Replaces the cell, instead of taking its value, since [`WeakAny`]
+    /// handles to the cell might exist. They're not updated after
+    /// invalidation.
+    fn invalidate(&mut self) -> Option<RefAny> {
+        let cell = std::mem::replace(&mut self.cell, Ref::new(OnceCell::new()));
+        cell.get().map(|rc| Ref::clone(rc) as RefAny)
+    }
+
+    fn weak_handle(&self) -> WeakAny {
+        Ref::downgrade(&self.cell) as WeakAny
+    }
+
+    fn duplicate(&self) -> BoxedSingletonGetter {
+        Box::new(Self {
+            ctor: Ref::clone(&self.ctor),
+            cell: Ref::new(OnceCell::new()),
+        })
     }
 }
 
@@ -164,24 +250,26 @@ where
 ///
 /// The dependency tuple `Deps` must implement [`DepBuilder<T>`].
 pub(crate) struct SingletonGetterWithDeps<T, Deps> {
-    /// Constructor, returns a new `T`.
-    ctor: RwLock<Option<Box<dyn SingletonCtorDeps<T, Deps>>>>,
+    /// Constructor, returns a new `T`. It's shared with the duplicates of
+    /// this getter.
+    ctor: Ref<dyn SingletonCtorDeps<T, Deps>>,
     /// Cell containing the constructed `T`.
-    cell: OnceCell<Ref<T>>,
+    cell: Ref<OnceCell<Ref<T>>>,
 }
 
 impl<T, Deps> SingletonGetterWithDeps<T, Deps> {
     /// Create a new [`SingletonGetter`] using `ctor` to create new objects.
     /// Objects are stored internally in `cell`.
     ///
-    /// `ctor` may contain side-effects. It's guaranteed to be only called once (for each thread).
+    /// `ctor` may contain side-effects. It's only called again after the
+    /// singleton has been invalidated.
     pub(crate) fn new<F>(ctor: F) -> Self
     where
         F: SingletonCtorDeps<T, Deps>,
     {
         Self {
-            ctor: RwLock::new(Some(Box::new(ctor))),
-            cell: OnceCell::new(),
+            ctor: Ref::new(ctor),
+            cell: Ref::new(OnceCell::new()),
         }
     }
 }
@@ -191,24 +279,376 @@ where
     Deps: DepBuilder<T> + 'static,
     T: RegisterableSingleton,
 {
-    fn get_singleton(&self, registry: &Registry) -> Option<RefAny> {
-        let ctor = {
-            let mut lock = self.ctor.write();
-            lock.take().expect("to be called only once")
-        };
+    fn get_singleton(
+        &self,
+        registry: &Registry,
+    ) -> Result<RefAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
+        if let Some(rc) = self.get_if_init() {
+            stopwatch.resolved::<T>(registry);
+            return Ok(rc);
+        }
+
+        let rc = self.cell.get_or_try_init(|| {
+            let obj = Deps::build_once(
+                registry,
+                &*self.ctor,
+                crate::dependency_builder::private::SealToken,
+            )
+            .map(Ref::new)?;
+            stopwatch.singleton_constructed::<T>(registry);
+            Ok::<_, ResolveError>(obj)
+        })?;
+        let rc = Ref::clone(rc) as RefAny;
+        stopwatch.resolved::<T>(registry);
+        Ok(rc)
+    }
+
+    fn get_if_init(&self) -> Option<RefAny> {
+        self.cell.get().map(|rc| Ref::clone(rc) as RefAny)
+    }
+
+    /// Replaces the cell, instead of taking its value, since [`WeakAny`]
+    /// handles to the cell might exist. They're not updated after
+    /// invalidation.
+    fn invalidate(&mut self) -> Option<RefAny> {
+        let cell = std::mem::replace(&mut self.cell, Ref::new(OnceCell::new()));
+        cell.get().map(|rc| Ref::clone(rc) as RefAny)
+    }
+
+    fn weak_handle(&self) -> WeakAny {
+        Ref::downgrade(&self.cell) as WeakAny
+    }
+
+    fn duplicate(&self) -> BoxedSingletonGetter {
+        Box::new(Self {
+            ctor: Ref::clone(&self.ctor),
+            cell: Ref::new(OnceCell::new()),
+        })
+    }
+}
+
+//          ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+//          ┃              TRANSIENT (fallible, no deps)              ┃
+//          ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+/// Construct a new transient with no dependencies, using a fallible
+/// constructor. Usually used through `dyn TransientBuilder`.
+pub(crate) struct TryTransientBuilderNoDeps<T> {
+    /// Constructor, returns a new `T`, or the error why it couldn't be
+    /// constructed.
+    ctor: fn() -> Result<T, BoxErr>,
+}
+
+impl<T> TryTransientBuilderNoDeps<T> {
+    /// Create a new [`TransientBuilder`] using `ctor` to create new objects.
+    ///
+    /// `ctor` should not have side-effects. It may be called multiple times.
+    pub(crate) fn new(ctor: fn() -> Result<T, BoxErr>) -> Self {
+        Self { ctor }
+    }
+}
+
+impl<T> TransientBuilder for TryTransientBuilderNoDeps<T>
+where
+    T: Registerable,
+{
+    fn make_transient(
+        &self,
+        registry: &Registry,
+    ) -> Result<BoxedAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
+        let obj = (self.ctor)().map_err(ResolveError::ctor::<T>)?;
+        stopwatch.resolved::<T>(registry);
+        Ok(Box::new(obj))
+    }
+
+    fn duplicate(&self) -> BoxedTransientBuilder {
+        Box::new(Self::new(self.ctor))
+    }
+}
+
+//          ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+//          ┃             TRANSIENT (fallible, with deps)             ┃
+//          ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+/// Construct a new transient with any number of dependencies, using a
+/// fallible constructor. Usually used through `dyn TransientBuilder`.
+///
+/// The dependency tuple `Deps` must implement [`DepBuilder<T>`].
+pub(crate) struct TryTransientBuilderWithDeps<T, Deps> {
+    /// Constructor, returns a new `T`, or the error why it couldn't be
+    /// constructed.
+    ctor: fn(Deps) -> Result<T, BoxErr>,
+}
 
-        #[allow(clippy::option_if_let_else)]
-        match Deps::build_once(
+impl<T, Deps> TryTransientBuilderWithDeps<T, Deps> {
+    /// Create a new [`TransientBuilder`] using `ctor` to create new objects.
+    ///
+    /// `ctor` should not have side-effects. It may be called multiple times.
+    pub(crate) fn new(ctor: fn(Deps) -> Result<T, BoxErr>) -> Self {
+        Self { ctor }
+    }
+}
+
+impl<T, Deps> TransientBuilder for TryTransientBuilderWithDeps<T, Deps>
+where
+    Deps: DepBuilder<T> + 'static,
+    T: Registerable,
+{
+    fn make_transient(
+        &self,
+        registry: &Registry,
+    ) -> Result<BoxedAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
+        let deps = Deps::resolve(
             registry,
-            ctor,
             crate::dependency_builder::private::SealToken,
-        ) {
-            Some(obj) => {
-                let rc = self.cell.get_or_init(|| Ref::new(obj));
-                let rc = Ref::clone(rc) as RefAny;
-                Some(rc)
-            }
-            None => None,
+        )?;
+        let obj = (self.ctor)(deps).map_err(ResolveError::ctor::<T>)?;
+        stopwatch.resolved::<T>(registry);
+        Ok(Box::new(obj))
+    }
+
+    fn duplicate(&self) -> BoxedTransientBuilder {
+        Box::new(Self::new(self.ctor))
+    }
+}
+
+//          ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+//          ┃                  SINGLETON (fallible)                   ┃
+//          ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+/// Construct a new singleton with any number of dependencies, including
+/// none, using a fallible constructor. Usually used through `dyn
+/// SingletonGetter`.
+///
+/// The dependency tuple `Deps` must implement [`DepBuilder<T>`].
+pub(crate) struct TrySingletonGetter<T, Deps> {
+    /// Constructor, returns a new `T`, or the error why it couldn't be
+    /// constructed. It's shared with the duplicates of this getter.
+    ctor: Ref<dyn SingletonCtorDeps<Result<T, BoxErr>, Deps>>,
+    /// Cell containing the constructed `T`.
+    cell: Ref<OnceCell<Ref<T>>>,
+}
+
+impl<T, Deps> TrySingletonGetter<T, Deps> {
+    /// Create a new [`SingletonGetter`] using `ctor` to create new objects.
+    /// Objects are stored internally in `cell`.
+    ///
+    /// `ctor` may contain side-effects. It's called again on the next
+    /// request, if it failed, or after the singleton has been invalidated.
+    pub(crate) fn new<F>(ctor: F) -> Self
+    where
+        F: SingletonCtorDeps<Result<T, BoxErr>, Deps>,
+    {
+        Self {
+            ctor: Ref::new(ctor),
+            cell: Ref::new(OnceCell::new()),
+        }
+    }
+}
+
+impl<T, Deps> SingletonGetter for TrySingletonGetter<T, Deps>
+where
+    Deps: DepBuilder<T> + 'static,
+    T: RegisterableSingleton,
+{
+    fn get_singleton(
+        &self,
+        registry: &Registry,
+    ) -> Result<RefAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
+        if let Some(rc) = self.get_if_init() {
+            stopwatch.resolved::<T>(registry);
+            return Ok(rc);
+        }
+
+        let rc = self.cell.get_or_try_init(|| {
+            let deps = Deps::resolve(
+                registry,
+                crate::dependency_builder::private::SealToken,
+            )?;
+            let obj = (self.ctor)(deps)
+                .map(Ref::new)
+                .map_err(ResolveError::ctor::<T>)?;
+            stopwatch.singleton_constructed::<T>(registry);
+            Ok::<_, ResolveError>(obj)
+        })?;
+        let rc = Ref::clone(rc) as RefAny;
+        stopwatch.resolved::<T>(registry);
+        Ok(rc)
+    }
+
+    fn get_if_init(&self) -> Option<RefAny> {
+        self.cell.get().map(|rc| Ref::clone(rc) as RefAny)
+    }
+
+    /// Replaces the cell, instead of taking its value, since [`WeakAny`]
+    /// handles to the cell might exist. They're not updated after
+    /// invalidation.
+    fn invalidate(&mut self) -> Option<RefAny> {
+        let cell = std::mem::replace(&mut self.cell, Ref::new(OnceCell::new()));
+        cell.get().map(|rc| Ref::clone(rc) as RefAny)
+    }
+
+    fn weak_handle(&self) -> WeakAny {
+        Ref::downgrade(&self.cell) as WeakAny
+    }
+
+    fn duplicate(&self) -> BoxedSingletonGetter {
+        Box::new(Self {
+            ctor: Ref::clone(&self.ctor),
+            cell: Ref::new(OnceCell::new()),
+        })
+    }
+}
+
+//          ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+//          ┃                  SINGLETON (instance)                   ┃
+//          ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+/// Holds a pre-constructed singleton. Usually used through `dyn SingletonGetter`.
+pub(crate) struct SingletonGetterInstance<T> {
+    /// The pre-constructed `T`.
+    instance: Ref<T>,
+}
+
+impl<T> SingletonGetterInstance<T> {
+    /// Create a new [`SingletonGetter`] returning `value`, without ever
+    /// calling a constructor.
+    pub(crate) fn new(value: T) -> Self {
+        Self {
+            instance: Ref::new(value),
+        }
+    }
+}
+
+impl<T> SingletonGetter for SingletonGetterInstance<T>
+where
+    T: RegisterableSingleton,
+{
+    fn get_singleton(
+        &self,
+        registry: &Registry,
+    ) -> Result<RefAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
+        let rc = Ref::clone(&self.instance) as RefAny;
+        stopwatch.resolved::<T>(registry);
+        Ok(rc)
+    }
+
+    fn get_if_init(&self) -> Option<RefAny> {
+        Some(Ref::clone(&self.instance) as RefAny)
+    }
+
+    /// Pre-constructed instances can't be constructed again, therefore,
+    /// they're never invalidated.
+    fn invalidate(&mut self) -> Option<RefAny> {
+        None
+    }
+
+    fn weak_handle(&self) -> WeakAny {
+        Ref::downgrade(&self.instance) as WeakAny
+    }
+
+    /// Pre-constructed instances can't be constructed again, therefore, the
+    /// duplicate shares the instance.
+    fn duplicate(&self) -> BoxedSingletonGetter {
+        Box::new(Self {
+            instance: Ref::clone(&self.instance),
+        })
+    }
+}
+
+//          ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+//          ┃                    TRANSIENT (alias)                    ┃
+//          ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+/// Construct a new transient `A` by converting the transient `C`, which is
+/// resolved from the registry. Usually used through `dyn TransientBuilder`.
+pub(crate) struct AliasTransientBuilder<C, A> {
+    /// Conversion from the resolved `C` into the alias `A`.
+    convert: fn(C) -> A,
+}
+
+impl<C, A> AliasTransientBuilder<C, A> {
+    /// Create a new [`TransientBuilder`] converting the transient `C` into
+    /// new objects, using `convert`.
+    pub(crate) fn new(convert: fn(C) -> A) -> Self {
+        Self { convert }
+    }
+}
+
+impl<C, A> TransientBuilder for AliasTransientBuilder<C, A>
+where
+    C: Registerable,
+    A: Registerable,
+{
+    fn make_transient(
+        &self,
+        registry: &Registry,
+    ) -> Result<BoxedAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
+        let concrete = registry
+            .resolve_transient::<C>(None)
+            .map_err(|err| err.with_type(std::any::type_name::<A>()))?;
+        let obj = (self.convert)(concrete);
+        stopwatch.resolved::<A>(registry);
+        Ok(Box::new(obj))
+    }
+
+    fn duplicate(&self) -> BoxedTransientBuilder {
+        Box::new(Self::new(self.convert))
+    }
+}
+
+//          ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+//          ┃                  TRANSIENT (stateful)                   ┃
+//          ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+/// Construct a new transient with a stateful constructor, that's called with
+/// exclusive access. Usually used through `dyn TransientBuilder`.
+pub(crate) struct StatefulTransientBuilder<T, C> {
+    /// Constructor, returns a new `T`. It's shared with all duplicates of the
+    /// builder.
+    ctor: Ref<NonAsyncRwLock<C>>,
+    /// The constructed type.
+    _marker: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T, C> StatefulTransientBuilder<T, C> {
+    /// Create a new [`TransientBuilder`] using `ctor` to create new objects.
+    pub(crate) fn new(ctor: C) -> Self {
+        Self {
+            ctor: Ref::new(NonAsyncRwLock::new(ctor)),
+            _marker: std::marker::PhantomData,
         }
     }
 }
+
+impl<T, C> TransientBuilder for StatefulTransientBuilder<T, C>
+where
+    T: Registerable,
+    C: StatefulTransientCtor<T>,
+{
+    fn make_transient(
+        &self,
+        registry: &Registry,
+    ) -> Result<BoxedAny, ResolveError> {
+        let stopwatch = Stopwatch::start();
+        let obj = {
+            let mut ctor = self.ctor.write();
+            (*ctor)()
+        };
+        stopwatch.resolved::<T>(registry);
+        Ok(Box::new(obj))
+    }
+
+    fn duplicate(&self) -> BoxedTransientBuilder {
+        Box::new(Self {
+            ctor: Ref::clone(&self.ctor),
+            _marker: std::marker::PhantomData,
+        })
+    }
+}
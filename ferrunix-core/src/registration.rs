@@ -6,10 +6,10 @@ use crate::{types::OnceCell, Registry};
 
 /// The global, `'static` default [`Registry`]. It's constructed and accessible
 /// via [`Registry::global`].
-#[cfg(all(feature = "multithread", not(feature = "tokio")))]
+#[cfg(all(feature = "multithread", not(feature = "async")))]
 pub(crate) static DEFAULT_REGISTRY: OnceCell<Registry> = OnceCell::new();
 
-#[cfg(all(not(feature = "multithread"), not(feature = "tokio")))]
+#[cfg(all(not(feature = "multithread"), not(feature = "async")))]
 thread_local! {
     /// The global, `'static` default [`Registry`]. It's constructed and accessible via
     /// [`Registry::global`] from the current thread only.
@@ -25,8 +25,13 @@ thread_local! {
 #[cfg(feature = "tokio")]
 pub(crate) static DEFAULT_REGISTRY: OnceCell<Registry> = OnceCell::const_new();
 
+/// The global, `'static` default [`Registry`]. It's constructed and accessible
+/// via [`Registry::global`].
+#[cfg(all(feature = "async-std", not(feature = "tokio")))]
+pub(crate) static DEFAULT_REGISTRY: OnceCell<Registry> = OnceCell::new();
+
 /// Synchronous registration.
-#[cfg(not(feature = "tokio"))]
+#[cfg(not(feature = "async"))]
 mod unsync {
     use crate::Registry;
 
@@ -37,7 +42,10 @@ mod unsync {
     ///
     /// This is, usually, used by the derive macro, and not manually.
     #[non_exhaustive]
-    pub struct RegistrationFunc(pub(crate) RegisterFn);
+    pub struct RegistrationFunc(
+        pub(crate) RegisterFn,
+        pub(crate) Option<&'static str>,
+    );
 
     impl RegistrationFunc {
         /// Create a new [`RegistrationFunc`] from a `register` function.
@@ -72,13 +80,25 @@ mod unsync {
         /// ));
         /// ```
         pub const fn new(register: RegisterFn) -> Self {
-            Self(register)
+            Self(register, None)
+        }
+
+        /// Create a new [`RegistrationFunc`] from a `register` function, that's
+        /// part of the group `group`.
+        ///
+        /// Grouped registration functions aren't part of the global registry,
+        /// they're only called by [`Registry::populate_group`].
+        pub const fn with_group(
+            register: RegisterFn,
+            group: &'static str,
+        ) -> Self {
+            Self(register, Some(group))
         }
     }
 
     impl std::fmt::Debug for RegistrationFunc {
         fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            fmt.debug_tuple("RegistrationFunc").finish()
+            fmt.debug_tuple("RegistrationFunc").field(&self.1).finish()
         }
     }
 
@@ -87,7 +107,7 @@ mod unsync {
 }
 
 /// Asynchronous registration.
-#[cfg(feature = "tokio")]
+#[cfg(feature = "async")]
 mod sync {
     use crate::Registry;
 
@@ -102,7 +122,10 @@ mod sync {
     ///
     /// This is, usually, used by the derive macro, and not manually.
     #[non_exhaustive]
-    pub struct RegistrationFunc(pub(crate) RegisterFn);
+    pub struct RegistrationFunc(
+        pub(crate) RegisterFn,
+        pub(crate) Option<&'static str>,
+    );
 
     impl RegistrationFunc {
         /// Create a new [`RegistrationFunc`] from a `register` function.
@@ -137,13 +160,25 @@ mod sync {
         /// ));
         /// ```
         pub const fn new(register: RegisterFn) -> Self {
-            Self(register)
+            Self(register, None)
+        }
+
+        /// Create a new [`RegistrationFunc`] from a `register` function, that's
+        /// part of the group `group`.
+        ///
+        /// Grouped registration functions aren't part of the global registry,
+        /// they're only called by [`Registry::populate_group`].
+        pub const fn with_group(
+            register: RegisterFn,
+            group: &'static str,
+        ) -> Self {
+            Self(register, Some(group))
         }
     }
 
     impl std::fmt::Debug for RegistrationFunc {
         fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            fmt.debug_tuple("RegistrationFunc").finish()
+            fmt.debug_tuple("RegistrationFunc").field(&self.1).finish()
         }
     }
 
@@ -151,11 +186,58 @@ mod sync {
     inventory::collect!(RegistrationFunc);
 }
 
-#[cfg(feature = "tokio")]
+#[cfg(feature = "async")]
 pub use sync::*;
 
-#[cfg(not(feature = "tokio"))]
+#[cfg(not(feature = "async"))]
 pub use unsync::*;
 
 /// Use `autoregister` to register a new [`RegistrationFunc`].
 pub use inventory::submit as autoregister;
+
+/// Marker for post-construction hooks taking `&mut self`, see
+/// [`PostConstruct`].
+#[derive(Debug, Clone, Copy)]
+pub struct ByRef(());
+
+/// Marker for post-construction hooks taking `self`, see [`PostConstruct`].
+#[derive(Debug, Clone, Copy)]
+pub struct ByValue(());
+
+/// A post-construction hook of `T`, which is either a `fn(&mut T)`, or a
+/// `fn(T) -> T`. `M` is one of the markers [`ByRef`] or [`ByValue`].
+///
+/// This is, usually, used by the derive macro, and not manually.
+pub trait PostConstruct<T, M> {
+    /// Run the hook on the freshly constructed `obj`.
+    fn run(self, obj: T) -> T;
+}
+
+impl<T, F> PostConstruct<T, ByRef> for F
+where
+    F: FnOnce(&mut T),
+{
+    fn run(self, mut obj: T) -> T {
+        self(&mut obj);
+        obj
+    }
+}
+
+impl<T, F> PostConstruct<T, ByValue> for F
+where
+    F: FnOnce(T) -> T,
+{
+    fn run(self, obj: T) -> T {
+        self(obj)
+    }
+}
+
+/// Run the post-construction `hook` on `obj`, and return the result.
+///
+/// This is, usually, used by the derive macro, and not manually.
+pub fn post_construct<T, M, F>(obj: T, hook: F) -> T
+where
+    F: PostConstruct<T, M>,
+{
+    hook.run(obj)
+}
@@ -3,26 +3,61 @@
 
 use std::any::TypeId;
 use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(feature = "async")]
+use futures_util::stream::{FuturesUnordered, Stream};
 
 use crate::cycle_detection::{
-    DependencyValidator, FullValidationError, ValidationError,
+    DependencyGraph, DependencyValidator, FullValidationError, LifetimeWarning,
+    ValidationError,
 };
+use crate::dependencies::Dep;
 use crate::dependency_builder::DepBuilder;
-use crate::object_builder::Object;
+use crate::error::{
+    BoxErr, MergeError, RegistrationError, RegistryError, ResolveError,
+};
+#[cfg(feature = "metrics")]
+use crate::metrics::{MetricEvent, MetricsSink};
+use crate::object_builder::{Object, RegisteredObject};
+use crate::scope::ScopeGuard;
 use crate::types::{
-    Registerable, RegisterableSingleton, SingletonCtor, SingletonCtorDeps,
+    AnyCtor, BoxedAny, DefaultProvider, Factory, NonAsyncRwLock, RefAny,
+    RefSingletonGetter, Registerable, RegisterableSingleton, RegistrationKey,
+    SingletonCtor, SingletonCtorDeps, StatefulTransientCtor, TransientCtor,
+    WeakAny, WeakRef,
 };
 use crate::{
     registration::RegistrationFunc, registration::DEFAULT_REGISTRY,
-    types::HashMap, types::Ref, types::RwLock,
+    types::HashMap, types::ObjectMap, types::Ref, types::RwLock,
 };
 
 /// Registry for all types that can be constructed or otherwise injected.
 pub struct Registry {
     /// Internal hashtable of all registered objects.
-    objects: RwLock<HashMap<TypeId, Object>>,
+    objects: RwLock<ObjectMap<RegistrationKey, RegisteredObject>>,
+    /// Internal hashtable of all objects registered with
+    /// [`Registry::register_all`].
+    multi_objects: RwLock<HashMap<TypeId, Vec<Object>>>,
     /// Validation.
     validator: DependencyValidator,
+    /// The parent registry, for registries created with [`Registry::child`].
+    parent: Option<WeakRef<Registry>>,
+    /// Weak reference to `self`, for ref-counted registries, see
+    /// [`Registry::into_ref`].
+    this: Option<WeakRef<Registry>>,
+    /// Whether further registrations are rejected, see
+    /// [`RegistryBuilder::build`].
+    frozen: AtomicBool,
+    /// Constructors registered with [`Registry::register_named_any`], by
+    /// their key.
+    named_any: NonAsyncRwLock<HashMap<String, Ref<dyn AnyCtor>>>,
+    /// Fallback for transients that aren't registered, see
+    /// [`Registry::set_default_provider`].
+    default_provider: NonAsyncRwLock<Option<DefaultProvider>>,
+    /// Sink for the emitted metrics, see [`Registry::set_metrics_sink`].
+    #[cfg(feature = "metrics")]
+    metrics_sink: NonAsyncRwLock<Option<MetricsSink>>,
 }
 
 #[allow(clippy::multiple_inherent_impl)]
@@ -37,14 +72,95 @@ impl Registry {
     /// be used.
     #[must_use]
     pub fn empty() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Create a new, empty, registry, with space for at least `capacity`
+    /// registered types, before it reallocates.
+    ///
+    /// Like [`Registry::empty`], this registry contains no pre-registered
+    /// types.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            objects: RwLock::new(HashMap::new()),
-            validator: DependencyValidator::new(),
+            objects: RwLock::new(ObjectMap::with_capacity_and_hasher(
+                capacity,
+                Default::default(),
+            )),
+            multi_objects: RwLock::new(HashMap::new()),
+            validator: DependencyValidator::with_capacity(capacity),
+            parent: None,
+            this: None,
+            frozen: AtomicBool::new(false),
+            named_any: NonAsyncRwLock::new(HashMap::new()),
+            default_provider: NonAsyncRwLock::new(None),
+            #[cfg(feature = "metrics")]
+            metrics_sink: NonAsyncRwLock::new(None),
         }
     }
 
-    /// Register a new transient or singleton with dependencies.
+    /// Create a new, empty, registry, that's ready to be used as the parent
+    /// of [`Registry::child`] registries.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use ferrunix_core::Registry;
+    /// let root = Registry::root();
+    /// let child = root.child();
+    /// let grandchild = child.child();
+    /// ```
+    #[must_use]
+    pub fn root() -> Ref<Self> {
+        Self::empty().into_ref()
+    }
+
+    /// Move the registry into a new [`Ref`].
+    ///
+    /// Only ref-counted registries can be injected into constructors with
+    /// the [`RegistryRef`] dependency. Registries created with
+    /// [`Registry::root`], [`Registry::child`], and
+    /// [`Registry::fresh_autoregistered`] are ref-counted already.
+    ///
+    /// [`RegistryRef`]: crate::dependencies::RegistryRef
+    #[must_use]
+    pub fn into_ref(mut self) -> Ref<Self> {
+        Ref::new_cyclic(|this| {
+            self.this = Some(WeakRef::clone(this));
+            self
+        })
+    }
+
+    /// Create a new, empty, child registry of `self`.
+    ///
+    /// Types that aren't registered on the child are resolved from `self`
+    /// (and its parents). Types registered on the child take precedence over
+    /// the types registered on `self`, even if `self` has already constructed
+    /// the singleton of the type.
+    ///
+    /// Types registered on `self` are always resolved by `self`, they never
+    /// depend on types registered on the child.
+    ///
+    /// The child only holds a weak reference to `self`, types can't be
+    /// resolved from `self` after it's dropped.
+    ///
+    /// Use [`Registry::root`] to create the first registry of the chain.
+    #[must_use]
     #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn child(self: &Ref<Self>) -> Ref<Self> {
+        let mut child = Self::empty();
+        child.parent = Some(Ref::downgrade(self));
+        child.validator.set_parent(Ref::downgrade(self));
+        child.into_ref()
+    }
+
+    /// Register a new transient or singleton with dependencies.
+    ///
+    /// `Deps` might be the empty tuple `()` to register a type without any
+    /// dependencies, the constructor is then called with `()`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
     pub fn with_deps<T, Deps>(&self) -> Builder<'_, T, Deps>
     where
         Deps: DepBuilder<T>,
@@ -56,6 +172,180 @@ impl Registry {
         }
     }
 
+    /// Enter a new scope.
+    ///
+    /// Types registered with [`Registry::register_scoped`] are constructed
+    /// once per scope, and shared within it. They're resolved through the
+    /// returned [`ScopeGuard`], see [`ScopeGuard::get`]. Every scope is
+    /// independent of all other scopes, e.g., one scope per request, even if
+    /// they're active at the same time, or nested.
+    ///
+    /// The scope is left, and all objects constructed in it are dropped, when
+    /// the returned [`ScopeGuard`] is dropped.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn enter_scope(&self) -> ScopeGuard<'_> {
+        ScopeGuard::new(self)
+    }
+
+    /// Set the sink that receives the [`MetricEvent`]s emitted while
+    /// resolving, or constructing, objects registered on this registry.
+    ///
+    /// Replaces the previously set sink, if any. Child registries don't
+    /// inherit the sink; types registered on `self`, but resolved through a
+    /// child, are reported to the sink of `self`.
+    ///
+    /// Unlike registrations, the sink can be set on a frozen registry (see
+    /// [`Registry::freeze`]), it doesn't affect how types are resolved.
+    ///
+    /// <div class="warning">The sink must not resolve, or register, any types
+    /// on the registry.</div>
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use ferrunix_core::Registry;
+    /// # use ferrunix_core::metrics::MetricEvent;
+    /// let registry = Registry::empty();
+    /// registry.set_metrics_sink(|event| {
+    ///     if let MetricEvent::Resolved { type_name, duration, .. } = event {
+    ///         println!("resolved {type_name} in {duration:?}");
+    ///     }
+    /// });
+    /// ```
+    #[cfg(feature = "metrics")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(sink)))]
+    pub fn set_metrics_sink<F>(&self, sink: F)
+    where
+        F: Fn(MetricEvent) + Send + Sync + 'static,
+    {
+        let sink: MetricsSink = Ref::new(sink);
+        let previous = self.metrics_sink.write().replace(sink);
+        drop(previous);
+    }
+
+    /// Emit `event` to the metrics sink, if one is set.
+    ///
+    /// The sink is called after the lock is released, in case it accesses the
+    /// registry.
+    #[cfg(feature = "metrics")]
+    pub(crate) fn emit_metric(&self, event: MetricEvent) {
+        let sink = self.metrics_sink.read().clone();
+        if let Some(sink) = sink {
+            sink(event);
+        }
+    }
+
+    /// Set the fallback that's called for transients that are neither
+    /// registered on this registry, nor on any of its parents, e.g., to
+    /// resolve types from a legacy service locator during a migration.
+    ///
+    /// `provider` is called with the [`TypeId`] of the requested type, and
+    /// returns `None` if it can't provide the type either. The returned object
+    /// is downcast to the requested type, like a registered transient.
+    ///
+    /// The fallback only applies to unnamed transients, singletons are never
+    /// resolved from it. Types only provided by the fallback aren't
+    /// registered, and are reported as missing by the validation.
+    ///
+    /// Replaces the previously set fallback, if any. Child registries don't
+    /// inherit the fallback.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use std::any::TypeId;
+    /// # use ferrunix_core::Registry;
+    /// let registry = Registry::empty();
+    /// registry.set_default_provider(|id, _registry| {
+    ///     if id == TypeId::of::<u16>() {
+    ///         return Some(Box::new(8080_u16));
+    ///     }
+    ///     None
+    /// });
+    /// ```
+    ///
+    /// # Panics
+    /// When the registry is frozen, see [`Registry::freeze`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(provider)))]
+    pub fn set_default_provider<F>(&self, provider: F)
+    where
+        F: Fn(TypeId, &Self) -> Option<BoxedAny> + Send + Sync + 'static,
+    {
+        // The fallback provides types, like a registration.
+        self.assert_not_frozen::<BoxedAny>();
+
+        let provider: DefaultProvider = Ref::new(provider);
+        let previous = self.default_provider.write().replace(provider);
+        drop(previous);
+    }
+
+    /// Constructs the object with the [`TypeId`] `id` with the fallback set
+    /// with [`Registry::set_default_provider`].
+    ///
+    /// Returns `None`, if no fallback is set, or it can't provide `id`.
+    ///
+    /// The fallback is called after the lock is released, in case it
+    /// accesses the registry.
+    fn resolve_default(&self, id: TypeId) -> Option<BoxedAny> {
+        let provider = self.default_provider.read().clone()?;
+        provider(id, self)
+    }
+
+    /// Register a new constructor, under the string `key`, for types that are
+    /// only known at runtime, e.g., plugins listed in a configuration file.
+    ///
+    /// The string keys are separate from the registered types, the object
+    /// is resolved with [`Registry::resolve_named_any`] only. The registered
+    /// constructors aren't validated, and can't be used as dependencies.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use ferrunix_core::Registry;
+    /// let registry = Registry::empty();
+    /// registry.register_named_any("port", || Box::new(8080_u16));
+    ///
+    /// let port = registry.resolve_named_any("port").unwrap();
+    /// assert_eq!(port.downcast_ref::<u16>(), Some(&8080));
+    /// ```
+    ///
+    /// # Panics
+    /// When `key` has been registered already, or the registry is frozen.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(ctor)))]
+    pub fn register_named_any<F>(&self, key: &str, ctor: F)
+    where
+        F: AnyCtor,
+    {
+        self.assert_not_frozen::<Box<dyn std::any::Any>>();
+
+        let mut lock = self.named_any.write();
+        assert!(!lock.contains_key(key), "key '{key}' is already registered");
+        lock.insert(key.to_owned(), Ref::new(ctor));
+    }
+
+    /// Constructs a new object with the constructor registered under the
+    /// string `key`, see [`Registry::register_named_any`].
+    ///
+    /// If `key` isn't registered on this registry, the parent registries are
+    /// searched, starting with the closest one.
+    ///
+    /// Returns `None` if `key` isn't registered.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn resolve_named_any(
+        &self,
+        key: &str,
+    ) -> Option<Box<dyn std::any::Any>> {
+        // The constructor is called after the lock is released, in case it
+        // accesses the registry.
+        let ctor = self.named_any.read().get(key).map(Ref::clone);
+        if let Some(ctor) = ctor {
+            return Some(ctor());
+        }
+
+        self.ancestors().find_map(|parent| {
+            let ctor = parent.named_any.read().get(key).map(Ref::clone);
+            ctor.map(|ctor| ctor())
+        })
+    }
+
     /// Check whether all registered types have the required dependencies.
     ///
     /// This is a potentially expensive call since it needs to go through the
@@ -85,12 +375,78 @@ impl Registry {
         self.validator.validate_all_full()
     }
 
+    /// Returns `true` if the registry has been validated successfully, and
+    /// nothing has been registered since.
+    ///
+    /// This doesn't validate the registry, it only inspects the cached result
+    /// of the last validation, e.g., of [`Registry::validate_all`], which
+    /// makes it cheap enough to be asserted before resolving types:
+    ///
+    /// ```rust,no_run
+    /// # use ferrunix_core::Registry;
+    /// # let registry = Registry::empty();
+    /// registry.validate_all().unwrap();
+    /// debug_assert!(registry.is_validated());
+    /// ```
+    ///
+    /// A type registered on a parent registry (see [`Registry::child`])
+    /// invalidates the cached result of all its children.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn is_validated(&self) -> bool {
+        self.validator.is_validated()
+    }
+
+    /// Returns a warning for every registered singleton that depends on a
+    /// transient. The singleton is only constructed once, and holds on to a
+    /// single instance of the transient forever, which often isn't intended.
+    ///
+    /// This doesn't fail validation, see [`Registry::validate_all`].
+    /// Dependencies registered on a parent registry aren't checked.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn validate_lifetimes(&self) -> Vec<LifetimeWarning> {
+        self.validator.validate_lifetimes()
+    }
+
+    /// Freeze the registry, rejecting all further registrations, overrides,
+    /// and removals of types.
+    ///
+    /// Afterwards, registering a type, or setting a fallback with
+    /// [`Registry::set_default_provider`], panics with "registry is frozen",
+    /// the fallible registration functions, like
+    /// [`Registry::checked_transient`], return [`RegistryError::Frozen`], and
+    /// merging returns [`MergeError::Frozen`]. Resolving types is unaffected.
+    ///
+    /// This prevents accidental late registrations, after the application
+    /// has been started. A frozen registry can't be unfrozen.
+    ///
+    /// See [`RegistryBuilder::build`] to validate and freeze a registry.
+    pub fn freeze(&self) {
+        self.frozen.store(true, Ordering::Release);
+    }
+
+    /// Returns `true` if the registry is frozen, see [`Registry::freeze`].
+    #[must_use]
+    pub fn is_frozen(&self) -> bool {
+        self.frozen.load(Ordering::Acquire)
+    }
+
     /// Check whether the type `T` is registered in this registry, and all
     /// dependencies of the type `T` are also registered.
     ///
+    /// Only the dependencies reachable from `T` are validated, missing
+    /// dependencies or cycles between unrelated types are ignored.
+    ///
+    /// If `T` isn't registered on this registry, it's validated on the
+    /// closest parent registry it's registered on.
+    ///
     /// # Errors
     /// Returns a [`ValidationError`] when the dependency graph is missing dependencies or has cycles.
-    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
     pub fn validate<T>(&self) -> Result<(), ValidationError>
     where
         T: Registerable,
@@ -107,11 +463,95 @@ impl Registry {
         self.validator.dotgraph()
     }
 
+    /// Return a string of the dependency graph visualized using graphviz's
+    /// `dot` language, like [`Registry::dotgraph`], but styled by lifetime:
+    /// singletons are drawn as boxes, scoped types as dashed boxes, and
+    /// transients as ellipses. Edges are labeled with the lifetime of the
+    /// dependency, and a legend explains the shapes.
+    ///
+    /// # Errors
+    /// Returns a [`ValidationError`] when the dependency graph is missing dependencies or has cycles.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn dotgraph_styled(&self) -> Result<String, ValidationError> {
+        self.validator.dotgraph_styled()
+    }
+
+    /// Return a string of the dependency graph visualized as `graph TD`
+    /// [mermaid](https://mermaid.js.org) diagram.
+    ///
+    /// Edges point from the dependent type to its dependency, like in
+    /// [`Registry::dotgraph`].
+    ///
+    /// # Errors
+    /// Returns a [`ValidationError`] when the dependency graph is missing dependencies or has cycles.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn mermaid_graph(&self) -> Result<String, ValidationError> {
+        self.validator.mermaid_graph()
+    }
+
+    /// Return a string of the dependency graph serialized as JSON, in the
+    /// form of `{"nodes":[...],"edges":[...]}`.
+    ///
+    /// Each node is an object with the `id`, the `type_name` and the `name`
+    /// (for named registrations, otherwise `null`) of the registered type.
+    /// Each edge is an object with the `id` of the dependent type (`from`),
+    /// and the `id` of its dependency (`to`).
+    ///
+    /// # Errors
+    /// Returns a [`ValidationError`] when the dependency graph is missing dependencies or has cycles.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn graph_json(&self) -> Result<String, ValidationError> {
+        self.validator.graph_json()
+    }
+
+    /// Return the dependency graph of all registered types, e.g., to render
+    /// or query it.
+    ///
+    /// The graph is returned even if dependencies are missing or it has
+    /// cycles. Missing dependencies aren't part of the graph.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn dependency_graph(&self) -> DependencyGraph {
+        self.validator.dependency_graph()
+    }
+
+    /// Returns all registered types that no other registered type depends
+    /// on.
+    ///
+    /// These are either the entry points of the program, i.e., the types
+    /// that are retrieved from the registry directly, or unused. See
+    /// [`Registry::unreachable_types`] to find types that are unused by a
+    /// known set of entry points.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn unused_types(&self) -> Vec<(TypeId, &'static str)> {
+        self.dependency_graph()
+            .unused()
+            .iter()
+            .map(|node| (node.type_id(), node.type_name()))
+            .collect()
+    }
+
+    /// Returns all registered types that aren't required to construct any
+    /// of the types in `roots`, neither directly nor transitively.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn unreachable_types(
+        &self,
+        roots: &[TypeId],
+    ) -> Vec<(TypeId, &'static str)> {
+        self.dependency_graph()
+            .unreachable_from(roots)
+            .iter()
+            .map(|node| (node.type_id(), node.type_name()))
+            .collect()
+    }
+
     /// Access the global registry.
     ///
     /// This registry contains the types that are marked for auto-registration
     /// via the derive macro.
-    #[cfg(all(not(feature = "tokio"), not(feature = "multithread")))]
+    #[cfg(all(not(feature = "async"), not(feature = "multithread")))]
     #[cfg_attr(feature = "tracing", tracing::instrument)]
     pub fn global() -> std::rc::Rc<Self> {
         DEFAULT_REGISTRY.with(|val| {
@@ -120,9 +560,86 @@ impl Registry {
             std::rc::Rc::clone(ret)
         })
     }
+
+    /// Returns the parent registry, if this is a child registry, and the
+    /// parent is still alive.
+    pub(crate) fn parent(&self) -> Option<Ref<Self>> {
+        self.parent.as_ref().and_then(WeakRef::upgrade)
+    }
+
+    /// Returns the [`Ref`] holding this registry, if it's ref-counted.
+    pub(crate) fn this(&self) -> Option<Ref<Self>> {
+        self.this.as_ref().and_then(WeakRef::upgrade)
+    }
+
+    /// Returns an iterator over all parent registries, starting with the
+    /// closest one.
+    fn ancestors(&self) -> impl Iterator<Item = Ref<Self>> {
+        std::iter::successors(self.parent(), |registry| registry.parent())
+    }
+
+    /// Returns the error for a type that's neither registered on this
+    /// registry, nor on any of its parents.
+    ///
+    /// If any of the parents has been dropped, the type might have been
+    /// registered on it, and [`ResolveError::ParentDropped`] is returned
+    /// instead of [`ResolveError::TypeMissing`].
+    fn missing_error(&self) -> ResolveError {
+        let mut parent = self.parent.clone();
+        while let Some(weak) = parent {
+            let Some(registry) = weak.upgrade() else {
+                return ResolveError::ParentDropped;
+            };
+            parent = registry.parent.clone();
+        }
+
+        ResolveError::TypeMissing
+    }
+
+    /// Returns the dependency validator of this registry.
+    pub(crate) fn validator(&self) -> &DependencyValidator {
+        &self.validator
+    }
+
+    /// Returns [`RegistryError::Frozen`] if the registry is frozen, and `T`
+    /// can't be registered.
+    fn ensure_not_frozen<T>(&self) -> Result<(), RegistryError> {
+        if self.is_frozen() {
+            return Err(RegistryError::Frozen {
+                type_name: std::any::type_name::<T>(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Panics if the registry is frozen, and `T` can't be registered.
+    #[allow(clippy::panic)]
+    fn assert_not_frozen<T>(&self) {
+        if let Err(err) = self.ensure_not_frozen::<T>() {
+            panic!("{err}");
+        }
+    }
+
+    /// Returns the error for `T` failing validation, carrying the dependency
+    /// chain from `T` to the first missing dependency, if there is one.
+    pub(crate) fn missing_dependencies_error<T>(&self) -> ResolveError
+    where
+        T: Registerable,
+    {
+        let chain = self.validator.missing_chain::<T>();
+        if chain.is_empty() {
+            return ResolveError::DependenciesMissing;
+        }
+
+        ResolveError::DependencyChain {
+            chain,
+            source: Box::new(ResolveError::TypeMissing),
+        }
+    }
 }
 
-#[cfg(all(feature = "multithread", not(feature = "tokio")))]
+#[cfg(all(feature = "multithread", not(feature = "async")))]
 impl Registry {
     /// Access the global registry.
     ///
@@ -134,7 +651,7 @@ impl Registry {
     }
 }
 
-#[cfg(not(feature = "tokio"))]
+#[cfg(not(feature = "async"))]
 impl Registry {
     /// Register a new transient object, without dependencies.
     ///
@@ -146,8 +663,11 @@ impl Registry {
     ///     This constructor will be called for every `T` that is requested.
     ///
     /// # Panics
-    /// When the type has been registered already.
-    #[cfg_attr(feature = "tracing", tracing::instrument(skip(ctor)))]
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
     pub fn transient<T>(&self, ctor: fn() -> T)
     where
         T: Registerable,
@@ -161,10 +681,221 @@ impl Registry {
         );
 
         let transient =
-            Object::Transient(Box::new(TransientBuilderImplNoDeps::new(ctor)));
+            Object::Transient(Ref::new(TransientBuilderImplNoDeps::new(ctor)));
+
+        self.insert_or_panic::<T>(None, transient);
+        self.validator.add_transient_no_deps::<T>(None);
+    }
+
+    /// Register a new transient object `C`, without dependencies, and
+    /// additionally as its alias `A`, e.g., a trait object.
+    ///
+    /// `ctor` is registered once, for `C`. Every request for `A` constructs
+    /// a new `C`, and converts it into `A` using `alias`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use ferrunix_core::Registry;
+    /// # let registry = Registry::empty();
+    /// trait Processor {}
+    /// struct Paypal;
+    /// impl Processor for Paypal {}
+    ///
+    /// registry.register_transient_aliased(|| Paypal, |paypal| {
+    ///     Box::new(paypal) as Box<dyn Processor>
+    /// });
+    ///
+    /// let paypal = registry.get_transient::<Paypal>();
+    /// let processor = registry.get_transient::<Box<dyn Processor>>();
+    /// ```
+    ///
+    /// # Panics
+    /// When `C` or `A` has been registered already, or the registry is
+    /// frozen. Neither is registered in that case.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip(ctor, alias),
+            fields(
+                type_name = std::any::type_name::<C>(),
+                alias = std::any::type_name::<A>(),
+            )
+        )
+    )]
+    pub fn register_transient_aliased<C, A>(
+        &self,
+        ctor: fn() -> C,
+        alias: fn(C) -> A,
+    ) where
+        C: Registerable,
+        A: Registerable,
+    {
+        #[allow(clippy::panic)]
+        if let Err(err) = self.checked_register_transient_aliased(ctor, alias) {
+            panic!("{err}");
+        }
+    }
+
+    /// Register a new transient object `C`, without dependencies, and
+    /// additionally as its alias `A`.
+    ///
+    /// This is the non-panicking version of
+    /// [`Registry::register_transient_aliased`].
+    ///
+    /// # Errors
+    /// Returns [`RegistryError::AlreadyRegistered`] when `C` or `A` has been
+    /// registered already, or [`RegistryError::Frozen`] if the registry is
+    /// frozen. Neither is registered in that case.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip(ctor, alias),
+            fields(
+                type_name = std::any::type_name::<C>(),
+                alias = std::any::type_name::<A>(),
+            )
+        )
+    )]
+    pub fn checked_register_transient_aliased<C, A>(
+        &self,
+        ctor: fn() -> C,
+        alias: fn(C) -> A,
+    ) -> Result<(), RegistryError>
+    where
+        C: Registerable,
+        A: Registerable,
+    {
+        use crate::object_builder::{
+            AliasTransientBuilder, TransientBuilderImplNoDeps,
+        };
+
+        let transient =
+            Object::Transient(Ref::new(TransientBuilderImplNoDeps::new(ctor)));
+        let aliased =
+            Object::Transient(Ref::new(AliasTransientBuilder::new(alias)));
+
+        self.try_insert_aliased::<C, A>(transient, aliased)?;
+        self.validator.add_transient_no_deps::<C>(None);
+        self.validator
+            .add_transient_deps::<A, (crate::Transient<C>,)>(None);
+        Ok(())
+    }
+
+    /// Register the transient `Base` as an upcast of the already registered,
+    /// or later registered, transient `Sub`, e.g., to resolve a
+    /// `Box<dyn BaseService>` from the `Box<dyn SpecificService>`
+    /// registration, where `SpecificService: BaseService`.
+    ///
+    /// Every request for `Base` constructs a new `Sub`, and converts it into
+    /// `Base` using `upcast`. Trait objects can't be upcast implicitly, the
+    /// sub-trait usually provides the conversion itself.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use ferrunix_core::Registry;
+    /// # let registry = Registry::empty();
+    /// trait BaseService: Send + Sync {}
+    /// trait SpecificService: BaseService {
+    ///     fn into_base(self: Box<Self>) -> Box<dyn BaseService>;
+    /// }
+    ///
+    /// struct Service;
+    /// impl BaseService for Service {}
+    /// impl SpecificService for Service {
+    ///     fn into_base(self: Box<Self>) -> Box<dyn BaseService> {
+    ///         self
+    ///     }
+    /// }
+    ///
+    /// registry.transient(|| Box::new(Service) as Box<dyn SpecificService>);
+    /// registry.register_upcast::<Box<dyn SpecificService>, _>(|service| {
+    ///     service.into_base()
+    /// });
+    ///
+    /// let base = registry.get_transient::<Box<dyn BaseService>>();
+    /// ```
+    ///
+    /// # Panics
+    /// When `Base` has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip(upcast),
+            fields(
+                type_name = std::any::type_name::<Sub>(),
+                base = std::any::type_name::<Base>(),
+            )
+        )
+    )]
+    pub fn register_upcast<Sub, Base>(&self, upcast: fn(Sub) -> Base)
+    where
+        Sub: Registerable,
+        Base: Registerable,
+    {
+        use crate::object_builder::AliasTransientBuilder;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering upcast ({} -> {})",
+            std::any::type_name::<Sub>(),
+            std::any::type_name::<Base>()
+        );
+
+        let transient =
+            Object::Transient(Ref::new(AliasTransientBuilder::new(upcast)));
+
+        self.insert_or_panic::<Base>(None, transient);
+        self.validator
+            .add_transient_deps::<Base, (crate::Transient<Sub>,)>(None);
+    }
+
+    /// Register a new transient object, without dependencies, constructed by
+    /// the stateful constructor `ctor`, e.g., to hand out sequential ids.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ferrunix_core::Registry;
+    /// # let registry = Registry::empty();
+    /// let mut next_id = 0_u32;
+    /// registry.register_transient_stateful(move || {
+    ///     next_id += 1;
+    ///     next_id
+    /// });
+    ///
+    /// assert_eq!(registry.get_transient::<u32>(), Some(1));
+    /// assert_eq!(registry.get_transient::<u32>(), Some(2));
+    /// ```
+    ///
+    /// In contrast to [`Registry::transient`], `ctor` may mutate its captured
+    /// state. Therefore, it's called while holding an exclusive lock, and
+    /// concurrent constructions of `T` are serialized.
+    ///
+    /// Registries duplicated from this registry share the state of `ctor`.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn register_transient_stateful<T, C>(&self, ctor: C)
+    where
+        T: Registerable,
+        C: StatefulTransientCtor<T>,
+    {
+        use crate::object_builder::StatefulTransientBuilder;
 
-        self.insert_or_panic::<T>(transient);
-        self.validator.add_transient_no_deps::<T>();
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering stateful transient ({})",
+            std::any::type_name::<T>()
+        );
+
+        let builder = StatefulTransientBuilder::<T, C>::new(ctor);
+        let transient = Object::Transient(Ref::new(builder));
+
+        self.insert_or_panic::<T>(None, transient);
+        self.validator.add_transient_no_deps::<T>(None);
     }
 
     /// Register a new singleton object, without dependencies.
@@ -178,8 +909,11 @@ impl Registry {
     ///     instance of `T` is requested.
     ///
     /// # Panics
-    /// When the type has been registered already.
-    #[cfg_attr(feature = "tracing", tracing::instrument(skip(ctor)))]
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
     pub fn singleton<T, F>(&self, ctor: F)
     where
         T: RegisterableSingleton,
@@ -194,336 +928,4461 @@ impl Registry {
         );
 
         let singleton =
-            Object::Singleton(Box::new(SingletonGetterNoDeps::new(ctor)));
+            Object::Singleton(Ref::new(SingletonGetterNoDeps::new(ctor)));
 
-        self.insert_or_panic::<T>(singleton);
-        self.validator.add_singleton_no_deps::<T>();
+        self.insert_or_panic::<T>(None, singleton);
+        self.validator.add_singleton_no_deps::<T>(None);
     }
 
-    /// Retrieves a newly constructed `T` from this registry.
+    /// Register a new transient object, without dependencies.
     ///
-    /// Returns `None` if `T` wasn't registered or failed to construct.
-    #[must_use]
-    #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn get_transient<T>(&self) -> Option<T>
+    /// This is the non-panicking version of [`Registry::transient`].
+    ///
+    /// # Errors
+    /// Returns [`RegistryError::AlreadyRegistered`] when the type has been
+    /// registered already, or [`RegistryError::Frozen`] if the registry is
+    /// frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn checked_transient<T>(
+        &self,
+        ctor: fn() -> T,
+    ) -> Result<(), RegistryError>
     where
         T: Registerable,
     {
-        let lock = self.objects.read();
-        if let Some(Object::Transient(transient)) = lock.get(&TypeId::of::<T>())
-        {
-            let resolved = transient.make_transient(self)?;
-            drop(lock);
-            if let Ok(obj) = resolved.downcast::<T>() {
-                return Some(*obj);
-            }
-        }
+        use crate::object_builder::TransientBuilderImplNoDeps;
 
-        None
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering transient ({})",
+            std::any::type_name::<T>()
+        );
+
+        let transient =
+            Object::Transient(Ref::new(TransientBuilderImplNoDeps::new(ctor)));
+
+        self.try_insert::<T>(None, transient)?;
+        self.validator.add_transient_no_deps::<T>(None);
+        Ok(())
     }
 
-    /// Retrieves the singleton `T` from this registry.
+    /// Register an already constructed `value` as singleton.
     ///
-    /// Returns `None` if `T` wasn't registered or failed to construct. The
-    /// singleton is a ref-counted pointer object (either `Arc` or `Rc`).
-    #[must_use]
-    #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn get_singleton<T>(&self) -> Option<Ref<T>>
+    /// The first request for the singleton `T` returns `value`, no
+    /// constructor is ever run. This is useful for values that are loaded or
+    /// constructed before the registry is populated, e.g. the configuration
+    /// of the program.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use ferrunix_core::Registry;
+    /// # let registry = Registry::empty();
+    /// struct Config {
+    ///     port: u16,
+    /// }
+    ///
+    /// registry.register_instance(Config { port: 8080 });
+    ///
+    /// let config = registry.get_singleton::<Config>().unwrap();
+    /// assert_eq!(config.port, 8080);
+    /// ```
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(value), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn register_instance<T>(&self, value: T)
     where
         T: RegisterableSingleton,
     {
-        let lock = self.objects.read();
-        if let Some(Object::Singleton(singleton)) = lock.get(&TypeId::of::<T>())
-        {
-            let resolved = singleton.get_singleton(self)?;
-            drop(lock);
-            if let Ok(obj) = resolved.downcast::<T>() {
-                return Some(obj);
-            }
-        }
-
-        None
-    }
+        use crate::object_builder::SingletonGetterInstance;
 
-    /// Reset the global registry, removing all previously registered types, and
-    /// re-running the auto-registration routines.
-    ///
-    /// # Safety
-    /// Ensure that no other thread is currently using [`Registry::global()`].
-    #[allow(unsafe_code)]
-    #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub unsafe fn reset_global() {
-        let registry = Self::global();
-        {
-            let mut lock = registry.objects.write();
-            lock.clear();
-        }
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering singleton instance ({})",
+            std::any::type_name::<T>()
+        );
 
-        for register in inventory::iter::<RegistrationFunc> {
-            #[cfg(not(feature = "multithread"))]
-            (register.0)(&registry);
+        let singleton =
+            Object::Singleton(Ref::new(SingletonGetterInstance::new(value)));
 
-            #[cfg(feature = "multithread")]
-            (register.0)(registry);
-        }
+        self.insert_or_panic::<T>(None, singleton);
+        self.validator.add_singleton_no_deps::<T>(None);
     }
 
-    /// Create an empty registry, and add all autoregistered types into it.
+    /// Register a new transient object, constructed with
+    /// [`Default::default`].
     ///
-    /// This is the constructor for the global registry that can be acquired
-    /// with [`Registry::global`].
-    #[must_use]
-    #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub fn autoregistered() -> Self {
-        let registry = Self::empty();
-        for register in inventory::iter::<RegistrationFunc> {
-            (register.0)(&registry);
-        }
-
-        registry
+    /// Shorthand for `registry.transient(T::default)`.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn register_transient_default<T>(&self)
+    where
+        T: Registerable + Default,
+    {
+        self.transient::<T>(T::default);
     }
 
-    /// Inserts a new object into the objecs hashtable.
+    /// Register a new singleton object, constructed with
+    /// [`Default::default`].
     ///
-    /// This acquires an exclusive lock on `self.objects`.
+    /// Shorthand for `registry.singleton(T::default)`.
     ///
     /// # Panics
-    /// If the key already exists (=> the type was previously registered).
-    #[inline]
-    fn insert_or_panic<T: 'static>(&self, value: Object) {
-        let mut lock = self.objects.write();
-        let entry = lock.entry(TypeId::of::<T>());
-        match entry {
-            #[allow(clippy::panic)]
-            hashbrown::hash_map::Entry::Occupied(_) => panic!(
-                "Type '{}' ({:?}) is already registered",
-                std::any::type_name::<T>(),
-                TypeId::of::<T>()
-            ),
-            hashbrown::hash_map::Entry::Vacant(view) => {
-                view.insert(value);
-            }
-        }
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn register_singleton_default<T>(&self)
+    where
+        T: RegisterableSingleton + Default,
+    {
+        self.singleton::<T, _>(T::default);
     }
-}
 
-#[cfg(feature = "tokio")]
-impl Registry {
-    /// Create an empty registry, and add all autoregistered types into it.
+    /// Register a new transient object, without dependencies, using a
+    /// fallible constructor.
     ///
-    /// This is the constructor for the global registry that can be acquired
-    /// with [`Registry::global`].
+    /// If `ctor` returns an error, resolving `T` fails, with
+    /// [`ResolveError::Ctor`] at the end of the dependency chain.
     ///
     /// # Panics
-    /// If any of the constructors panic.
-    #[must_use]
-    #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub async fn autoregistered() -> Self {
-        use std::sync::Arc;
-
-        let registry = Arc::new(Self::empty());
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn try_register_transient<T>(&self, ctor: fn() -> Result<T, BoxErr>)
+    where
+        T: Registerable,
+    {
+        use crate::object_builder::TryTransientBuilderNoDeps;
 
-        let mut set = tokio::task::JoinSet::new();
-        for register in inventory::iter::<RegistrationFunc> {
-            let registry = Arc::clone(&registry);
-            set.spawn(async move {
-                let inner_registry = registry;
-                (register.0)(&inner_registry).await;
-            });
-        }
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering fallible transient ({})",
+            std::any::type_name::<T>()
+        );
 
-        #[allow(clippy::panic)]
-        while let Some(res) = set.join_next().await {
-            match res {
-                Ok(_) => continue,
-                Err(err) if err.is_panic() => {
-                    std::panic::resume_unwind(err.into_panic())
-                }
-                Err(err) => panic!("{err}"),
-            }
-        }
+        let transient =
+            Object::Transient(Ref::new(TryTransientBuilderNoDeps::new(ctor)));
 
-        assert_eq!(
-            Arc::strong_count(&registry), 1,
-            "all of the tasks in the `JoinSet` should've joined, dropping their \
-            Arc's. some task is still holding an Arc");
-        Arc::try_unwrap(registry).expect("all tasks above are joined")
+        self.insert_or_panic::<T>(None, transient);
+        self.validator.add_transient_no_deps::<T>(None);
     }
 
-    /// Register a new singleton object, without dependencies.
-    ///
-    /// To register a type with dependencies, use the builder returned from
-    /// [`Registry::with_deps`].
+    /// Register a new singleton object, without dependencies, using a
+    /// fallible constructor.
     ///
-    /// # Parameters
-    ///   * `ctor`: A constructor function returning the newly constructed `T`.
-    ///     This constructor will be called once, lazily, when the first
-    ///     instance of `T` is requested.
+    /// If `ctor` returns an error, resolving `T` fails, with
+    /// [`ResolveError::Ctor`] at the end of the dependency chain. The error
+    /// isn't cached, the next request of `T` calls `ctor` again.
     ///
     /// # Panics
-    /// When the type has been registered already.
-    #[cfg_attr(feature = "tracing", tracing::instrument(skip(ctor)))]
-    pub async fn singleton<T, F>(&self, ctor: F)
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn try_register_singleton<T, F>(&self, ctor: F)
     where
         T: RegisterableSingleton,
-        F: SingletonCtor<T>,
+        F: SingletonCtor<Result<T, BoxErr>>,
     {
-        use crate::object_builder::AsyncSingletonNoDeps;
+        use crate::object_builder::TrySingletonGetter;
 
         #[cfg(feature = "tracing")]
         tracing::info!(
-            "registering singleton ({})",
+            "registering fallible singleton ({})",
             std::any::type_name::<T>()
         );
 
-        let singleton =
-            Object::AsyncSingleton(Box::new(AsyncSingletonNoDeps::new(ctor)));
+        let singleton = Object::Singleton(Ref::new(
+            TrySingletonGetter::<T, ()>::new(move |()| ctor()),
+        ));
 
-        self.insert_or_panic::<T>(singleton).await;
-        self.validator.add_singleton_no_deps::<T>();
+        self.insert_or_panic::<T>(None, singleton);
+        self.validator.add_singleton_no_deps::<T>(None);
     }
 
-    /// Register a new transient object, without dependencies.
-    ///
-    /// To register a type with dependencies, use the builder returned from
-    /// [`Registry::with_deps`].
+    /// Register a new singleton object, without dependencies.
     ///
-    /// # Parameters
-    ///   * `ctor`: A constructor function returning the newly constructed `T`.
-    ///     This constructor will be called for every `T` that is requested.
+    /// This is the non-panicking version of [`Registry::singleton`].
     ///
-    /// # Panics
-    /// When the type has been registered already.
-    #[cfg_attr(feature = "tracing", tracing::instrument(skip(ctor)))]
-    pub async fn transient<T>(
-        &self,
-        ctor: fn() -> std::pin::Pin<
-            Box<dyn std::future::Future<Output = T> + Send>,
-        >,
-    ) where
-        T: Registerable,
+    /// # Errors
+    /// Returns [`RegistryError::AlreadyRegistered`] when the type has been
+    /// registered already, or [`RegistryError::Frozen`] if the registry is
+    /// frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn checked_singleton<T, F>(&self, ctor: F) -> Result<(), RegistryError>
+    where
+        T: RegisterableSingleton,
+        F: SingletonCtor<T>,
     {
-        use crate::object_builder::AsyncTransientBuilderImplNoDeps;
+        use crate::object_builder::SingletonGetterNoDeps;
 
         #[cfg(feature = "tracing")]
         tracing::info!(
-            "registering transient ({})",
+            "registering singleton ({})",
             std::any::type_name::<T>()
         );
 
-        let transient = Object::AsyncTransient(Box::new(
-            AsyncTransientBuilderImplNoDeps::new(ctor),
-        ));
+        let singleton =
+            Object::Singleton(Ref::new(SingletonGetterNoDeps::new(ctor)));
 
-        self.insert_or_panic::<T>(transient).await;
-        self.validator.add_transient_no_deps::<T>();
+        self.try_insert::<T>(None, singleton)?;
+        self.validator.add_singleton_no_deps::<T>(None);
+        Ok(())
     }
 
-    /// Retrieves a newly constructed `T` from this registry.
+    /// Register a new transient object, without dependencies, replacing any
+    /// previous registration of `T`.
     ///
-    /// Returns `None` if `T` wasn't registered or failed to construct.
-    #[must_use]
-    #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub async fn get_transient<T>(&self) -> Option<T>
+    /// This is useful for replacing dependencies in test fixtures, without
+    /// having to rebuild the whole registry. If `T` isn't registered yet, it's
+    /// registered as with [`Registry::transient`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn override_transient<T>(&self, ctor: fn() -> T)
     where
         T: Registerable,
     {
-        let lock = self.objects.read().await;
-        if let Some(Object::AsyncTransient(ctor)) = lock.get(&TypeId::of::<T>())
-        {
-            let boxed = ctor.make_transient(self).await?;
-            drop(lock);
-            if let Ok(obj) = boxed.downcast::<T>() {
-                return Some(*obj);
-            }
-        }
+        use crate::object_builder::TransientBuilderImplNoDeps;
 
-        None
+        #[cfg(feature = "tracing")]
+        tracing::info!("overriding transient ({})", std::any::type_name::<T>());
+
+        let transient =
+            Object::Transient(Ref::new(TransientBuilderImplNoDeps::new(ctor)));
+
+        self.replace::<T>(None, transient);
+        self.validator.add_transient_no_deps::<T>(None);
     }
 
-    /// Retrieves the singleton `T` from this registry.
+    /// Register a new singleton object, without dependencies, replacing any
+    /// previous registration of `T`.
     ///
-    /// Returns `None` if `T` wasn't registered or failed to construct. The
-    /// singleton is a ref-counted pointer object (either `Arc` or `Rc`).
-    #[must_use]
-    #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub async fn get_singleton<T>(&self) -> Option<Ref<T>>
+    /// A previously constructed instance of `T` is discarded by the registry;
+    /// the next request for `T` constructs a new instance using `ctor`.
+    /// Instances that are still referenced elsewhere are not affected.
+    ///
+    /// This is useful for replacing dependencies in test fixtures, without
+    /// having to rebuild the whole registry. If `T` isn't registered yet, it's
+    /// registered as with [`Registry::singleton`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn override_singleton<T, F>(&self, ctor: F)
     where
         T: RegisterableSingleton,
+        F: SingletonCtor<T>,
     {
-        let lock = self.objects.read().await;
-        if let Some(Object::AsyncSingleton(singleton)) =
-            lock.get(&TypeId::of::<T>())
-        {
-            let resolved = singleton.get_singleton(self).await?;
-            drop(lock);
-            if let Ok(obj) = resolved.downcast::<T>() {
-                return Some(obj);
-            }
-        }
+        use crate::object_builder::SingletonGetterNoDeps;
 
-        None
+        #[cfg(feature = "tracing")]
+        tracing::info!("overriding singleton ({})", std::any::type_name::<T>());
+
+        let singleton =
+            Object::Singleton(Ref::new(SingletonGetterNoDeps::new(ctor)));
+
+        self.replace::<T>(None, singleton);
+        self.validator.add_singleton_no_deps::<T>(None);
     }
 
-    /// Access the global registry.
+    /// Register a new transient object, without dependencies, and return a
+    /// handle to the registration.
     ///
-    /// This registry contains the types that are marked for auto-registration
-    /// via the derive macro.
-    #[cfg_attr(feature = "tracing", tracing::instrument)]
-    pub async fn global() -> &'static Self {
-        DEFAULT_REGISTRY.get_or_init(Self::autoregistered).await
+    /// The handle allows overriding or removing the registration later,
+    /// without naming `T` again. See [`Registry::transient`].
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use ferrunix_core::Registry;
+    /// let registry = Registry::empty();
+    /// let handle = registry.transient_handle(|| 1_u8);
+    ///
+    /// handle.override_transient(|| 2);
+    /// assert_eq!(registry.get_transient::<u8>(), Some(2));
+    /// assert!(handle.remove());
+    /// ```
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn transient_handle<T>(&self, ctor: fn() -> T) -> Registration<'_, T>
+    where
+        T: Registerable,
+    {
+        self.transient(ctor);
+        Registration::new(self)
     }
 
-    /// Reset the global registry, removing all previously registered types, and
-    /// re-running the auto-registration routines.
+    /// Register a new singleton object, without dependencies, and return a
+    /// handle to the registration.
     ///
-    /// # Safety
-    /// Ensure that no other thread is currently using [`Registry::global()`].
-    #[allow(unsafe_code)]
-    pub async unsafe fn reset_global() {
-        // Purposefully not annotated with `tracing::instrument` because it mangles the order of
-        // `async` and `unsafe`, resulting in a compiler error.
-        let registry = Self::global().await;
-        {
-            let mut lock = registry.objects.write().await;
-            lock.clear();
-        }
-
-        for register in inventory::iter::<RegistrationFunc> {
-            (register.0)(registry).await;
-        }
+    /// The handle allows overriding or removing the registration later,
+    /// without naming `T` again. See [`Registry::singleton`].
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn singleton_handle<T, F>(&self, ctor: F) -> Registration<'_, T>
+    where
+        T: RegisterableSingleton,
+        F: SingletonCtor<T>,
+    {
+        self.singleton(ctor);
+        Registration::new(self)
     }
 
-    /// Inserts a new object into the objecs hashtable.
+    /// Register a new transient object, without dependencies, under the name
+    /// `name`.
     ///
-    /// This acquires an exclusive lock on `self.objects`.
+    /// Named registrations allow registering multiple constructors for the
+    /// same type `T`. They're resolved with [`Registry::get_transient_named`],
+    /// or with [`NamedTransient`] as a dependency.
     ///
     /// # Panics
-    /// If the key already exists (=> the type was previously registered).
-    #[inline]
-    async fn insert_or_panic<T: 'static>(&self, value: Object) {
-        let mut lock = self.objects.write().await;
-        let entry = lock.entry(TypeId::of::<T>());
-        match entry {
-            #[allow(clippy::panic)]
-            hashbrown::hash_map::Entry::Occupied(_) => panic!(
-                "Type '{}' ({:?}) is already registered",
-                std::any::type_name::<T>(),
-                TypeId::of::<T>()
-            ),
-            hashbrown::hash_map::Entry::Vacant(view) => {
-                view.insert(value);
-            }
-        }
-    }
-}
+    /// When the type has been registered already with the same name, or the
+    /// registry is frozen.
+    ///
+    /// [`NamedTransient`]: crate::dependencies::NamedTransient
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn transient_named<T>(&self, name: &'static str, ctor: fn() -> T)
+    where
+        T: Registerable,
+    {
+        use crate::object_builder::TransientBuilderImplNoDeps;
 
-impl std::fmt::Debug for Registry {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering named transient ({}, {name})",
+            std::any::type_name::<T>()
+        );
+
+        let transient =
+            Object::Transient(Ref::new(TransientBuilderImplNoDeps::new(ctor)));
+
+        self.insert_or_panic::<T>(Some(name), transient);
+        self.validator.add_transient_no_deps::<T>(Some(name));
+    }
+
+    /// Register a new singleton object, without dependencies, under the name
+    /// `name`.
+    ///
+    /// Named registrations allow registering multiple constructors for the
+    /// same type `T`. They're resolved with [`Registry::get_singleton_named`],
+    /// or with [`NamedSingleton`] as a dependency.
+    ///
+    /// # Panics
+    /// When the type has been registered already with the same name, or the
+    /// registry is frozen.
+    ///
+    /// [`NamedSingleton`]: crate::dependencies::NamedSingleton
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn singleton_named<T, F>(&self, name: &'static str, ctor: F)
+    where
+        T: RegisterableSingleton,
+        F: SingletonCtor<T>,
+    {
+        use crate::object_builder::SingletonGetterNoDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering named singleton ({}, {name})",
+            std::any::type_name::<T>()
+        );
+
+        let singleton =
+            Object::Singleton(Ref::new(SingletonGetterNoDeps::new(ctor)));
+
+        self.insert_or_panic::<T>(Some(name), singleton);
+        self.validator.add_singleton_no_deps::<T>(Some(name));
+    }
+
+    /// Register one more transient constructor of `T`, without dependencies.
+    ///
+    /// Any number of constructors can be registered for the same `T`, all of
+    /// them are constructed with [`Registry::transient_all`]. These are
+    /// independent of the registrations done with [`Registry::transient`],
+    /// and can't be injected as dependencies.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use ferrunix_core::Registry;
+    /// # let registry = Registry::empty();
+    /// trait Handler {}
+    /// struct Logging;
+    /// impl Handler for Logging {}
+    /// struct Metrics;
+    /// impl Handler for Metrics {}
+    ///
+    /// registry.register_all::<Box<dyn Handler>>(|| Box::new(Logging));
+    /// registry.register_all::<Box<dyn Handler>>(|| Box::new(Metrics));
+    ///
+    /// let handlers = registry.transient_all::<Box<dyn Handler>>().unwrap();
+    /// assert_eq!(handlers.len(), 2);
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn register_all<T>(&self, ctor: fn() -> T)
+    where
+        T: Registerable,
+    {
+        use crate::object_builder::TransientBuilderImplNoDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering transient into collection ({})",
+            std::any::type_name::<T>()
+        );
+
+        let transient =
+            Object::Transient(Ref::new(TransientBuilderImplNoDeps::new(ctor)));
+
+        self.push_multi::<T>(transient);
+    }
+
+    /// Register a new scoped object, without dependencies.
+    ///
+    /// A scoped object is constructed once per scope, and shared within it.
+    /// See [`Registry::enter_scope`].
+    ///
+    /// # Parameters
+    ///   * `ctor`: A constructor function returning the newly constructed `T`.
+    ///     This constructor will be called once per scope, lazily, when the
+    ///     first instance of `T` is requested within the scope.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use ferrunix_core::Registry;
+    /// # let registry = Registry::empty();
+    /// struct RequestId(u64);
+    ///
+    /// registry.register_scoped(|| RequestId(1));
+    ///
+    /// let scope = registry.enter_scope();
+    /// let id = scope.get::<RequestId>().unwrap();
+    /// drop(scope);
+    /// ```
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn register_scoped<T>(&self, ctor: fn() -> T)
+    where
+        T: RegisterableSingleton,
+    {
+        use crate::object_builder::TransientBuilderImplNoDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!("registering scoped ({})", std::any::type_name::<T>());
+
+        let scoped =
+            Object::Scoped(Ref::new(TransientBuilderImplNoDeps::new(ctor)));
+
+        self.insert_or_panic::<T>(None, scoped);
+        self.validator.add_scoped_no_deps::<T>();
+    }
+
+    /// Retrieves the singleton `T`, registering it with `ctor` first if `T`
+    /// hasn't been registered yet.
+    ///
+    /// Checking whether `T` is registered, and registering it, happens in a
+    /// single critical section. It's safe to call this concurrently from
+    /// multiple threads, only one `ctor` is ever registered. If `T` has been
+    /// registered already, on this registry or any of its parents, `ctor` is
+    /// dropped without being called.
+    ///
+    /// # Panics
+    /// When `T` has been registered, but not as singleton, when `T` failed to
+    /// construct, or when `T` hasn't been registered and the registry is
+    /// frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn get_or_register_singleton<T, F>(&self, ctor: F) -> Ref<T>
+    where
+        T: RegisterableSingleton,
+        F: SingletonCtor<T>,
+    {
+        use crate::object_builder::SingletonGetterNoDeps;
+
+        if !self.contains::<T>() {
+            self.assert_not_frozen::<T>();
+
+            let singleton =
+                Object::Singleton(Ref::new(SingletonGetterNoDeps::new(ctor)));
+            if self.try_insert::<T>(None, singleton).is_ok() {
+                #[cfg(feature = "tracing")]
+                tracing::info!(
+                    "registered singleton ({})",
+                    std::any::type_name::<T>()
+                );
+
+                self.validator.add_singleton_no_deps::<T>(None);
+            }
+        }
+
+        self.resolve_singleton::<T>(None)
+            .expect("to be registered as constructible singleton")
+    }
+
+    /// Register a new transient object, without dependencies, if `cond` is
+    /// `true`.
+    ///
+    /// Returns whether `T` has been registered.
+    ///
+    /// # Panics
+    /// When `cond` is `true`, and the type has been registered already, or the
+    /// registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn register_transient_if<T>(&self, cond: bool, ctor: fn() -> T) -> bool
+    where
+        T: Registerable,
+    {
+        if cond {
+            self.transient(ctor);
+        }
+        cond
+    }
+
+    /// Register a new singleton object, without dependencies, if `cond` is
+    /// `true`.
+    ///
+    /// Returns whether `T` has been registered. If `cond` is `false`, `ctor`
+    /// is dropped without being called.
+    ///
+    /// # Panics
+    /// When `cond` is `true`, and the type has been registered already, or the
+    /// registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn register_singleton_if<T, F>(&self, cond: bool, ctor: F) -> bool
+    where
+        T: RegisterableSingleton,
+        F: SingletonCtor<T>,
+    {
+        if cond {
+            self.singleton(ctor);
+        }
+        cond
+    }
+
+    /// Register the constructor of the first of `candidates`, whose condition
+    /// is `true`, as transient object without dependencies.
+    ///
+    /// This allows to express fallbacks, e.g., "prefer `a`, else `b`, else
+    /// `default`".
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ferrunix_core::Registry;
+    /// let registry = Registry::empty();
+    /// let (use_a, use_b) = (false, true);
+    /// registry.register_first_available(&[
+    ///     (use_a, || "a"),
+    ///     (use_b, || "b"),
+    ///     (true, || "default"),
+    /// ]);
+    /// assert_eq!(registry.get_transient::<&'static str>(), Some("b"));
+    /// ```
+    ///
+    /// Returns whether `T` has been registered, i.e., `false` if none of the
+    /// conditions are `true`.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(candidates), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn register_first_available<T>(
+        &self,
+        candidates: &[(bool, TransientCtor<T>)],
+    ) -> bool
+    where
+        T: Registerable,
+    {
+        let Some((_, ctor)) = candidates.iter().find(|(cond, _)| *cond) else {
+            return false;
+        };
+
+        self.transient(*ctor);
+        true
+    }
+
+    /// Register a new factory, constructing a new `T` from runtime arguments
+    /// `Args` on every call to [`Registry::make`].
+    ///
+    /// The factory receives this registry, to resolve further dependencies,
+    /// and the arguments passed to [`Registry::make`]. The constructed `T` is
+    /// never cached.
+    ///
+    /// Factories can't be used as dependencies of other types, since their
+    /// arguments are only known at the call site.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(factory), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn register_factory<Args, T, F>(&self, factory: F)
+    where
+        Args: 'static,
+        T: Registerable,
+        F: Factory<Args, T>,
+    {
+        #[cfg(feature = "tracing")]
+        tracing::info!("registering factory ({})", std::any::type_name::<T>());
+
+        let factory: Box<dyn Factory<Args, T>> = Box::new(factory);
+        self.insert_or_panic::<T>(None, Object::Factory(Ref::new(factory)));
+    }
+
+    /// Calls `callback` with every singleton registered on this registry, that has
+    /// been constructed already. Singletons that haven't been constructed
+    /// yet are skipped, nothing is constructed.
+    ///
+    /// The order of the singletons is unspecified, use
+    /// [`Registry::shutdown_order`] to get the singletons in dependency
+    /// order.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(callback)))]
+    pub fn for_each_singleton<F>(&self, mut callback: F)
+    where
+        F: FnMut(&RefAny),
+    {
+        let singletons = {
+            let lock = self.objects.read();
+            lock.values()
+                .filter_map(|entry| match &entry.object {
+                    Object::Singleton(singleton) => singleton.get_if_init(),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        for singleton in &singletons {
+            callback(singleton);
+        }
+    }
+
+    /// Returns all singletons registered on this registry, that have been
+    /// constructed already, in the order they should be shut down: every
+    /// singleton comes before the singletons it depends on.
+    ///
+    /// # Errors
+    /// Returns [`ValidationError::Cycle`] if the dependency graph has cycles.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn shutdown_order(&self) -> Result<Vec<RefAny>, ValidationError> {
+        let order = self.validator.topological_order()?;
+
+        let lock = self.objects.read();
+        Ok(order
+            .iter()
+            .filter_map(|key| match lock.get(key).map(|entry| &entry.object) {
+                Some(Object::Singleton(singleton)) => singleton.get_if_init(),
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Constructs all singletons registered on this registry, that haven't
+    /// been constructed yet, in dependency order: every singleton is
+    /// constructed after the singletons it depends on.
+    ///
+    /// Singletons registered on a parent registry aren't constructed.
+    ///
+    /// # Errors
+    /// Returns [`ResolveError::Cycle`] if the dependency graph has cycles, no
+    /// singleton is constructed in that case. Otherwise, returns the error of
+    /// the first singleton that fails to construct. The singletons that were
+    /// constructed before it stay constructed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn initialize_all_singletons(&self) -> Result<(), ResolveError> {
+        let order = self
+            .validator
+            .topological_order()
+            .map_err(|_| ResolveError::Cycle)?;
+
+        for key in order.iter().rev() {
+            let singleton = {
+                let lock = self.objects.read();
+                let Some(Object::Singleton(singleton)) =
+                    lock.get(key).map(|entry| &entry.object)
+                else {
+                    continue;
+                };
+                Ref::clone(singleton)
+            };
+
+            singleton.get_singleton(self)?;
+        }
+
+        Ok(())
+    }
+
+    /// Resolves the dependency `D`, the same way it's resolved for the
+    /// constructor of a registered type, e.g., [`Transient<T>`] constructs a
+    /// new `T`, and [`Singleton<T>`] returns the shared `T`.
+    ///
+    /// [`Transient<T>`]: crate::Transient
+    /// [`Singleton<T>`]: crate::Singleton
+    ///
+    /// # Errors
+    /// Returns an error if `D` couldn't be resolved, e.g., because the type
+    /// isn't registered, or failed to construct.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn resolve_dep<D>(&self) -> Result<D, ResolveError>
+    where
+        D: Dep,
+    {
+        D::new(self)
+    }
+
+    /// Retrieves a newly constructed `T` from this registry.
+    ///
+    /// Returns `None` if `T` wasn't registered or failed to construct.
+    #[must_use]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn get_transient<T>(&self) -> Option<T>
+    where
+        T: Registerable,
+    {
+        self.resolve_transient::<T>(None).ok()
+    }
+
+    /// Retrieves a newly constructed `T` from this registry, that was
+    /// registered with the name `name`.
+    ///
+    /// Returns `None` if `T` wasn't registered with `name` or failed to
+    /// construct.
+    #[must_use]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn get_transient_named<T>(&self, name: &'static str) -> Option<T>
+    where
+        T: Registerable,
+    {
+        self.resolve_transient::<T>(Some(name)).ok()
+    }
+
+    /// Constructs a new `T` with the factory registered with
+    /// [`Registry::register_factory`], passing `args` to it.
+    ///
+    /// If no factory for `T` is registered on this registry, the parent
+    /// registries are searched, starting with the closest one.
+    ///
+    /// # Errors
+    /// Returns [`ResolveError::TypeMissing`] if no factory for `T`, taking
+    /// `Args`, has been registered.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(args), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn make<T, Args>(&self, args: Args) -> Result<T, ResolveError>
+    where
+        T: Registerable,
+        Args: 'static,
+    {
+        if !self.contains_local::<T>() {
+            for parent in self.ancestors() {
+                if parent.contains_local::<T>() {
+                    return parent.make_local(args);
+                }
+            }
+        }
+
+        self.make_local(args)
+    }
+
+    /// Retrieves the singleton `T` from this registry.
+    ///
+    /// Returns `None` if `T` wasn't registered or failed to construct. The
+    /// singleton is a ref-counted pointer object (either `Arc` or `Rc`).
+    #[must_use]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn get_singleton<T>(&self) -> Option<Ref<T>>
+    where
+        T: RegisterableSingleton,
+    {
+        self.resolve_singleton::<T>(None).ok()
+    }
+
+    /// Retrieves a newly constructed `T` from this registry.
+    ///
+    /// # Errors
+    /// Returns [`ResolveError::DependencyChain`] if `T`, or any of its
+    /// dependencies, weren't registered or failed to construct. The
+    /// [`ResolveError::chain`] contains the names of all types along the path
+    /// that failed.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn try_get_transient<T>(&self) -> Result<T, ResolveError>
+    where
+        T: Registerable,
+    {
+        self.resolve_transient::<T>(None)
+    }
+
+    /// Retrieves a newly constructed object of the transient type with the
+    /// [`TypeId`] `id` from this registry, without downcasting it.
+    ///
+    /// This is useful for dynamic dispatch, where the concrete type isn't
+    /// known at the call site, e.g., deserialization keyed by [`TypeId`].
+    ///
+    /// # Errors
+    /// Returns [`ResolveError::TypeMissing`] if no transient with `id` is
+    /// registered, and the same errors as [`Registry::try_get_transient`] if
+    /// any of its dependencies weren't registered or failed to construct.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn transient_dyn(
+        &self,
+        id: TypeId,
+    ) -> Result<Box<dyn std::any::Any>, ResolveError> {
+        if let Some(resolved) = self.transient_dyn_local(id) {
+            return resolved;
+        }
+
+        for parent in self.ancestors() {
+            if let Some(resolved) = parent.transient_dyn_local(id) {
+                return resolved;
+            }
+        }
+
+        if let Some(resolved) = self.resolve_default(id) {
+            return Ok(resolved);
+        }
+
+        Err(self.missing_error())
+    }
+
+    /// Retrieves the singleton `T` from this registry.
+    ///
+    /// # Errors
+    /// Returns [`ResolveError::DependencyChain`] if `T`, or any of its
+    /// dependencies, weren't registered or failed to construct. The
+    /// [`ResolveError::chain`] contains the names of all types along the path
+    /// that failed.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn try_get_singleton<T>(&self) -> Result<Ref<T>, ResolveError>
+    where
+        T: RegisterableSingleton,
+    {
+        self.resolve_singleton::<T>(None)
+    }
+
+    /// Retrieves a clone of the singleton `T` from this registry, instead of
+    /// the shared `Ref<T>`.
+    ///
+    /// This is useful for small singletons, e.g., configuration structs, that
+    /// are cheap to clone.
+    ///
+    /// # Errors
+    /// Same as [`Registry::try_get_singleton`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn singleton_owned<T>(&self) -> Result<T, ResolveError>
+    where
+        T: RegisterableSingleton + Clone,
+    {
+        self.resolve_singleton::<T>(None).map(|rc| (*rc).clone())
+    }
+
+    /// Retrieves the singleton `T` from this registry, only if it has already
+    /// been constructed.
+    ///
+    /// Returns `None` if `T` wasn't registered, or hasn't been constructed
+    /// yet. In contrast to [`Registry::get_singleton`], this never runs the
+    /// constructor of `T`.
+    #[must_use]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn peek_singleton<T>(&self) -> Option<Ref<T>>
+    where
+        T: RegisterableSingleton,
+    {
+        let lock = self.objects.read();
+        let Some(Object::Singleton(singleton)) = lock
+            .get(&(TypeId::of::<T>(), None))
+            .map(|entry| &entry.object)
+        else {
+            return None;
+        };
+
+        singleton.get_if_init()?.downcast::<T>().ok()
+    }
+
+    /// Drops the constructed singleton `T`, while keeping its registration.
+    /// The next time `T` is requested, it's constructed again with the same
+    /// constructor.
+    ///
+    /// Objects that already hold a reference to `T` keep the old instance.
+    ///
+    /// Returns `true` if a constructed instance was dropped, `false` if `T`
+    /// wasn't registered as singleton, hasn't been constructed yet, or was
+    /// registered with [`Registry::register_instance`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn invalidate_singleton<T>(&self) -> bool
+    where
+        T: RegisterableSingleton,
+    {
+        let invalidated = {
+            let mut lock = self.objects.write();
+            match lock
+                .get_mut(&(TypeId::of::<T>(), None))
+                .map(|entry| &mut entry.object)
+            {
+                Some(Object::Singleton(singleton)) => {
+                    invalidate_shared(singleton)
+                }
+                _ => None,
+            }
+        };
+
+        // The instance is dropped after the lock is released, in case it
+        // accesses the registry on drop.
+        invalidated.is_some()
+    }
+
+    /// Retrieves the singleton `T` from this registry, that was registered
+    /// with the name `name`.
+    ///
+    /// Returns `None` if `T` wasn't registered with `name` or failed to
+    /// construct. The singleton is a ref-counted pointer object (either `Arc`
+    /// or `Rc`).
+    #[must_use]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn get_singleton_named<T>(&self, name: &'static str) -> Option<Ref<T>>
+    where
+        T: RegisterableSingleton,
+    {
+        self.resolve_singleton::<T>(Some(name)).ok()
+    }
+
+    /// Constructs a new scoped `T`, for the scope that's resolving it, see
+    /// [`ScopeGuard::get`].
+    ///
+    /// If `T` isn't registered on this registry, the parent registries are
+    /// searched, starting with the closest one.
+    pub(crate) fn make_scoped<T>(&self) -> Result<T, ResolveError>
+    where
+        T: Registerable,
+    {
+        if let Some(made) = self.make_scoped_local::<T>() {
+            return made;
+        }
+
+        for parent in self.ancestors() {
+            if let Some(made) = parent.make_scoped_local::<T>() {
+                return made;
+            }
+        }
+
+        Err(self.missing_error().with_type(std::any::type_name::<T>()))
+    }
+
+    /// Constructs every `T` registered with [`Registry::register_all`], in
+    /// the order they were registered.
+    ///
+    /// Returns an empty `Vec` if no constructor was registered for `T`.
+    ///
+    /// # Errors
+    /// Returns the error of the dependency that failed to construct any of
+    /// the `T`s, or [`ResolveError::TypeMismatch`] if any of the constructed
+    /// objects isn't a `T`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn transient_all<T>(&self) -> Result<Vec<T>, ResolveError>
+    where
+        T: Registerable,
+    {
+        let ctors = {
+            let lock = self.multi_objects.read();
+            let Some(objects) = lock.get(&TypeId::of::<T>()) else {
+                return Ok(Vec::new());
+            };
+            objects
+                .iter()
+                .filter_map(|object| match object {
+                    Object::Transient(ctor) => Some(Ref::clone(ctor)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut all = Vec::with_capacity(ctors.len());
+        for ctor in ctors {
+            let resolved = ctor.make_transient(self)?;
+            let obj = resolved
+                .downcast::<T>()
+                .ok()
+                .ok_or_else(ResolveError::type_mismatch::<T>)?;
+            all.push(*obj);
+        }
+
+        Ok(all)
+    }
+
+    /// Returns the number of types registered in this registry.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn len(&self) -> usize {
+        let lock = self.objects.read();
+        lock.len()
+    }
+
+    /// Returns `true` if no types are registered in this registry.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn is_empty(&self) -> bool {
+        let lock = self.objects.read();
+        lock.is_empty()
+    }
+
+    /// Returns the [`TypeId`] and type name of all types registered in this
+    /// registry, in no particular order.
+    ///
+    /// The type name is captured at registration time with
+    /// [`std::any::type_name`], therefore, it's "best effort", and might not
+    /// be correct or reproducible.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn registered_types(&self) -> Vec<(TypeId, &'static str)> {
+        let lock = self.objects.read();
+        lock.iter()
+            .map(|((type_id, _), entry)| (*type_id, entry.type_name))
+            .collect()
+    }
+
+    /// Returns the [`TypeId`] and type name of all types registered in this
+    /// registry, or any of its parents, in no particular order.
+    ///
+    /// Registrations of this registry shadow the registrations of its
+    /// parents, the same way they do for resolution. See
+    /// [`Registry::registered_types`] for the caveats of the type name.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn registered_types_recursive(&self) -> Vec<(TypeId, &'static str)> {
+        let mut types = HashMap::new();
+        self.insert_registered_types(&mut types);
+        for parent in self.ancestors() {
+            parent.insert_registered_types(&mut types);
+        }
+
+        types
+            .into_iter()
+            .map(|((type_id, _), type_name)| (type_id, type_name))
+            .collect()
+    }
+
+    /// Inserts the type names of all types registered in this registry into
+    /// `types`, unless their key is in `types` already.
+    ///
+    /// This acquires a shared lock on `self.objects`.
+    fn insert_registered_types(
+        &self,
+        types: &mut HashMap<RegistrationKey, &'static str>,
+    ) {
+        let lock = self.objects.read();
+        for (key, entry) in lock.iter() {
+            types.entry(*key).or_insert(entry.type_name);
+        }
+    }
+
+    /// Returns `true` if `T` is registered, on this registry or any of its
+    /// parents.
+    ///
+    /// The type isn't constructed, and its dependencies aren't checked. Use
+    /// [`Registry::validate`] for that.
+    #[must_use]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.contains_id(TypeId::of::<T>())
+    }
+
+    /// Returns `true` if a type with the [`TypeId`] `id` is registered, on
+    /// this registry or any of its parents.
+    ///
+    /// See [`Registry::contains`].
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn contains_id(&self, id: TypeId) -> bool {
+        if self.contains_id_local(id) {
+            return true;
+        }
+
+        for parent in self.ancestors() {
+            if parent.contains_id_local(id) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Returns `true` if `T` was registered directly on this registry.
+    #[must_use]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn contains_local<T: 'static>(&self) -> bool {
+        self.contains_id_local(TypeId::of::<T>())
+    }
+
+    /// Returns which registry `T` is resolved from, without constructing it.
+    ///
+    /// Returns `None` if `T` isn't registered, neither on this registry, nor
+    /// any of its parents.
+    #[must_use]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn resolve_source<T: 'static>(&self) -> Option<ResolveSource> {
+        if self.contains_local::<T>() {
+            return Some(ResolveSource::Local);
+        }
+
+        for (depth, parent) in (1..).zip(self.ancestors()) {
+            if parent.contains_local::<T>() {
+                return Some(ResolveSource::Parent(depth));
+            }
+        }
+
+        None
+    }
+
+    /// Remove the registration of `T` from this registry.
+    ///
+    /// Returns `true` if `T` was registered, `false` otherwise. Objects that
+    /// have already been resolved (e.g., the instance of a singleton) are not
+    /// affected and stay alive as long as they're referenced.
+    ///
+    /// Types that depend on `T` are still registered, but will fail
+    /// validation until `T` is registered again.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn remove<T: 'static>(&self) -> bool {
+        self.assert_not_frozen::<T>();
+        let removed = {
+            let mut lock = self.objects.write();
+            lock.remove(&(TypeId::of::<T>(), None)).is_some()
+        };
+
+        if removed {
+            self.validator.remove::<T>(None);
+        }
+
+        removed
+    }
+
+    /// Move all registrations of `other` into this registry.
+    ///
+    /// The dependency graph of `other` is merged as well, so that
+    /// [`Registry::validate_all`] validates the combined graph.
+    ///
+    /// Constructors registered with [`Registry::register_named_any`] are
+    /// merged as well. The default provider (see
+    /// [`Registry::set_default_provider`]) and the metrics sink of `other`
+    /// are only kept, if this registry has none.
+    ///
+    /// # Errors
+    /// Returns [`MergeError::Conflict`] if a type, or a key of
+    /// [`Registry::register_named_any`], is registered in both registries, or
+    /// [`MergeError::Frozen`] if this registry is frozen. Nothing is merged in
+    /// that case.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn merge(&self, other: Self) -> Result<(), MergeError> {
+        self.merge_inner(other, false)
+    }
+
+    /// Move all registrations of `other` into this registry, replacing the
+    /// registrations of types that are registered in both registries.
+    ///
+    /// See [`Registry::merge`].
+    ///
+    /// # Errors
+    /// Returns [`MergeError::Frozen`] if this registry is frozen. Nothing is
+    /// merged in that case.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn merge_override(&self, other: Self) -> Result<(), MergeError> {
+        self.merge_inner(other, true)
+    }
+
+    /// Create a new registry with the same registrations as this registry.
+    ///
+    /// Singletons aren't constructed in the new registry, even if they've
+    /// been constructed in this registry already, they're constructed again
+    /// on first use. Only singletons registered with
+    /// [`Registry::register_instance`] share their instance.
+    ///
+    /// This is useful to isolate tests from each other, without registering
+    /// all types again.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use ferrunix_core::Registry;
+    /// let registry = Registry::empty();
+    /// registry.singleton(|| String::from("Hello, World"));
+    /// let _ = registry.get_singleton::<String>();
+    ///
+    /// let duplicate = registry.duplicate();
+    /// assert!(duplicate.peek_singleton::<String>().is_none());
+    /// ```
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn duplicate(&self) -> Self {
+        let objects = {
+            let lock = self.objects.read();
+            lock.iter()
+                .map(|(key, entry)| (*key, entry.duplicate()))
+                .collect()
+        };
+        let multi_objects = {
+            let lock = self.multi_objects.read();
+            lock.iter()
+                .map(|(type_id, entries)| {
+                    (*type_id, entries.iter().map(Object::duplicate).collect())
+                })
+                .collect()
+        };
+
+        Self {
+            objects: RwLock::new(objects),
+            multi_objects: RwLock::new(multi_objects),
+            validator: self.validator.duplicate(),
+            parent: self.parent.clone(),
+            this: None,
+            frozen: AtomicBool::new(self.is_frozen()),
+            named_any: NonAsyncRwLock::new(self.named_any.read().clone()),
+            default_provider: NonAsyncRwLock::new(
+                self.default_provider.read().clone(),
+            ),
+            #[cfg(feature = "metrics")]
+            metrics_sink: NonAsyncRwLock::new(self.metrics_sink.read().clone()),
+        }
+    }
+
+    /// Reset the global registry, removing all previously registered types, and
+    /// re-running the auto-registration routines.
+    ///
+    /// Prefer [`Registry::fresh_autoregistered`] to get a separate registry,
+    /// without modifying the global registry.
+    ///
+    /// # Safety
+    /// Ensure that no other thread is currently using [`Registry::global()`].
+    #[allow(unsafe_code)]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub unsafe fn reset_global() {
+        let registry = Self::global();
+        {
+            let mut lock = registry.objects.write();
+            lock.clear();
+        }
+
+        for register in inventory::iter::<RegistrationFunc>
+            .into_iter()
+            .filter(|register| register.1.is_none())
+        {
+            #[cfg(not(feature = "multithread"))]
+            (register.0)(&registry);
+
+            #[cfg(feature = "multithread")]
+            (register.0)(registry);
+        }
+    }
+
+    /// Create an empty registry, and add all autoregistered types into it.
+    ///
+    /// This is the constructor for the global registry that can be acquired
+    /// with [`Registry::global`].
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn autoregistered() -> Self {
+        let registry = Self::empty();
+        for register in inventory::iter::<RegistrationFunc>
+            .into_iter()
+            .filter(|register| register.1.is_none())
+        {
+            (register.0)(&registry);
+        }
+
+        registry
+    }
+
+    /// Create an empty registry, and add all autoregistered types into it,
+    /// without aborting on panicking registration functions.
+    ///
+    /// Same as [`Registry::autoregistered`], except that a panic of a
+    /// registration function is caught, and returned as
+    /// [`RegistrationError::Panicked`]. The types of all other registration
+    /// functions are still registered. Types registered by the panicking
+    /// function, before it panicked, are kept.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn autoregistered_lenient() -> (Self, Vec<RegistrationError>) {
+        let registry = Self::empty();
+        let mut errors = Vec::new();
+        for register in inventory::iter::<RegistrationFunc>
+            .into_iter()
+            .filter(|register| register.1.is_none())
+        {
+            // The registry doesn't use poisoning locks, it stays usable after
+            // a panic.
+            let registered =
+                std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    (register.0)(&registry);
+                }));
+            if let Err(payload) = registered {
+                errors.push(RegistrationError::panicked(payload));
+            }
+        }
+
+        (registry, errors)
+    }
+
+    /// Create a new, ref-counted, registry, and add all autoregistered types
+    /// into it.
+    ///
+    /// In contrast to [`Registry::reset_global`], this doesn't touch the
+    /// global registry. Every call returns a separate registry, e.g., to
+    /// isolate tests running in parallel from each other.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn fresh_autoregistered() -> Ref<Self> {
+        Self::autoregistered().into_ref()
+    }
+
+    /// Create an empty registry, and add the types of all `funcs` into it.
+    ///
+    /// Same as [`Registry::autoregistered`], except that the registration
+    /// functions are supplied by the caller, instead of collecting all
+    /// autoregistered types. Grouped registration functions are called, too.
+    /// This doesn't touch the global registry.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(funcs)))]
+    pub fn from_registrations<I>(funcs: I) -> Self
+    where
+        I: IntoIterator<Item = RegistrationFunc>,
+    {
+        let registry = Self::empty();
+        for register in funcs {
+            (register.0)(&registry);
+        }
+
+        registry
+    }
+
+    /// Add all types that are autoregistered in the group `group` into this
+    /// registry, e.g., with `#[provides(transient, group = "plugins")]`.
+    ///
+    /// Grouped types aren't part of the global registry.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn populate_group(&self, group: &str) {
+        for register in inventory::iter::<RegistrationFunc>
+            .into_iter()
+            .filter(|register| register.1 == Some(group))
+        {
+            (register.0)(self);
+        }
+    }
+
+    /// Retrieves a newly constructed `T`, registered with `name`.
+    ///
+    /// If `T` isn't registered on this registry, the parent registries are
+    /// searched, starting with the closest one.
+    pub(crate) fn resolve_transient<T>(
+        &self,
+        name: Option<&'static str>,
+    ) -> Result<T, ResolveError>
+    where
+        T: Registerable,
+    {
+        if let Some(resolved) = self.resolve_transient_local::<T>(name) {
+            return resolved;
+        }
+
+        for parent in self.ancestors() {
+            if let Some(resolved) = parent.resolve_transient_local::<T>(name) {
+                return resolved;
+            }
+        }
+
+        if name.is_none() {
+            if let Some(resolved) = self.resolve_default(TypeId::of::<T>()) {
+                return resolved
+                    .downcast::<T>()
+                    .map(|obj| *obj)
+                    .map_err(|_| ResolveError::type_mismatch::<T>());
+            }
+        }
+
+        Err(self.missing_error().with_type(std::any::type_name::<T>()))
+    }
+
+    /// Retrieves a newly constructed `T`, registered with `name`, on this registry only.
+    ///
+    /// Returns `None`, if `T` isn't registered on this registry.
+    ///
+    /// This acquires a shared lock on `self.objects`.
+    fn resolve_transient_local<T>(
+        &self,
+        name: Option<&'static str>,
+    ) -> Option<Result<T, ResolveError>>
+    where
+        T: Registerable,
+    {
+        let transient = {
+            let lock = self.objects.read();
+            let Some(Object::Transient(transient)) = lock
+                .get(&(TypeId::of::<T>(), name))
+                .map(|entry| &entry.object)
+            else {
+                return None;
+            };
+            Ref::clone(transient)
+        };
+
+        let resolved = transient.make_transient(self);
+        Some(resolved.and_then(|resolved| {
+            resolved
+                .downcast::<T>()
+                .ok()
+                .map(|obj| *obj)
+                .ok_or_else(ResolveError::type_mismatch::<T>)
+        }))
+    }
+
+    /// Constructs a new object of the transient type with the [`TypeId`]
+    /// `id`, on this registry only.
+    ///
+    /// Returns `None`, if no transient with `id` is registered on this
+    /// registry.
+    ///
+    /// This acquires a shared lock on `self.objects`.
+    fn transient_dyn_local(
+        &self,
+        id: TypeId,
+    ) -> Option<Result<Box<dyn std::any::Any>, ResolveError>> {
+        let transient = {
+            let lock = self.objects.read();
+            let Some(Object::Transient(transient)) =
+                lock.get(&(id, None)).map(|entry| &entry.object)
+            else {
+                return None;
+            };
+            Ref::clone(transient)
+        };
+
+        Some(transient.make_transient(self))
+    }
+
+    /// Constructs a new `T` with the factory registered on this registry
+    /// only.
+    ///
+    /// This acquires a shared lock on `self.objects`.
+    fn make_local<T, Args>(&self, args: Args) -> Result<T, ResolveError>
+    where
+        T: Registerable,
+        Args: 'static,
+    {
+        let factory = {
+            let lock = self.objects.read();
+            let Some(Object::Factory(factory)) = lock
+                .get(&(TypeId::of::<T>(), None))
+                .map(|entry| &entry.object)
+            else {
+                return Err(ResolveError::TypeMissing
+                    .with_type(std::any::type_name::<T>()));
+            };
+            Ref::clone(factory)
+        };
+
+        let factory = factory
+            .downcast_ref::<Box<dyn Factory<Args, T>>>()
+            .ok_or_else(|| {
+                ResolveError::TypeMissing.with_type(std::any::type_name::<T>())
+            })?;
+        Ok(factory(self, args))
+    }
+
+    /// Retrieves the singleton `T`, registered with `name`.
+    ///
+    /// If `T` isn't registered on this registry, the parent registries are
+    /// searched, starting with the closest one.
+    pub(crate) fn resolve_singleton<T>(
+        &self,
+        name: Option<&'static str>,
+    ) -> Result<Ref<T>, ResolveError>
+    where
+        T: RegisterableSingleton,
+    {
+        if let Some(resolved) = self.resolve_singleton_local::<T>(name) {
+            return resolved;
+        }
+
+        for parent in self.ancestors() {
+            if let Some(resolved) = parent.resolve_singleton_local::<T>(name) {
+                return resolved;
+            }
+        }
+
+        Err(self.missing_error().with_type(std::any::type_name::<T>()))
+    }
+
+    /// Retrieves the singleton `T`, registered with `name`, on this registry only.
+    ///
+    /// Returns `None`, if `T` isn't registered on this registry.
+    ///
+    /// This acquires a shared lock on `self.objects`.
+    fn resolve_singleton_local<T>(
+        &self,
+        name: Option<&'static str>,
+    ) -> Option<Result<Ref<T>, ResolveError>>
+    where
+        T: RegisterableSingleton,
+    {
+        let singleton = {
+            let lock = self.objects.read();
+            let Some(Object::Singleton(singleton)) = lock
+                .get(&(TypeId::of::<T>(), name))
+                .map(|entry| &entry.object)
+            else {
+                return None;
+            };
+            Ref::clone(singleton)
+        };
+
+        let resolved = singleton.get_singleton(self);
+        Some(resolved.and_then(|resolved| {
+            resolved
+                .downcast::<T>()
+                .ok()
+                .ok_or_else(ResolveError::type_mismatch::<T>)
+        }))
+    }
+
+    /// Retrieves a weak handle to the singleton `T`, registered with `name`,
+    /// without constructing it.
+    ///
+    /// If `T` isn't registered on this registry, the parent registries are
+    /// searched, starting with the closest one.
+    pub(crate) fn resolve_weak_singleton<T>(
+        &self,
+        name: Option<&'static str>,
+    ) -> Result<WeakAny, ResolveError>
+    where
+        T: RegisterableSingleton,
+    {
+        if let Some(weak) = self.resolve_weak_singleton_local::<T>(name) {
+            return Ok(weak);
+        }
+
+        for parent in self.ancestors() {
+            if let Some(weak) = parent.resolve_weak_singleton_local::<T>(name) {
+                return Ok(weak);
+            }
+        }
+
+        Err(self.missing_error().with_type(std::any::type_name::<T>()))
+    }
+
+    /// Retrieves a weak handle to the singleton `T`, registered with `name`
+    /// on this registry only, without constructing it.
+    ///
+    /// This acquires a shared lock on `self.objects`.
+    fn resolve_weak_singleton_local<T>(
+        &self,
+        name: Option<&'static str>,
+    ) -> Option<WeakAny>
+    where
+        T: RegisterableSingleton,
+    {
+        let lock = self.objects.read();
+        match lock
+            .get(&(TypeId::of::<T>(), name))
+            .map(|entry| &entry.object)
+        {
+            Some(Object::Singleton(singleton)) => Some(singleton.weak_handle()),
+            _ => None,
+        }
+    }
+
+    /// Constructs a new scoped `T` on this registry only.
+    ///
+    /// Returns `None`, if `T` isn't registered as scoped type on this
+    /// registry.
+    ///
+    /// This acquires a shared lock on `self.objects`.
+    fn make_scoped_local<T>(&self) -> Option<Result<T, ResolveError>>
+    where
+        T: Registerable,
+    {
+        let scoped = {
+            let lock = self.objects.read();
+            let Some(Object::Scoped(scoped)) = lock
+                .get(&(TypeId::of::<T>(), None))
+                .map(|entry| &entry.object)
+            else {
+                return None;
+            };
+            Ref::clone(scoped)
+        };
+
+        let resolved = scoped.make_transient(self);
+        Some(resolved.and_then(|resolved| {
+            resolved
+                .downcast::<T>()
+                .ok()
+                .map(|obj| *obj)
+                .ok_or_else(ResolveError::type_mismatch::<T>)
+        }))
+    }
+
+    /// Inserts a new object into the objecs hashtable, replacing the
+    /// previous object registered for `T` (with the name `name`), if any.
+    ///
+    /// This acquires an exclusive lock on `self.objects`.
+    #[inline]
+    fn replace<T: 'static>(&self, name: Option<&'static str>, value: Object) {
+        self.assert_not_frozen::<T>();
+        let mut lock = self.objects.write();
+        lock.insert(
+            (TypeId::of::<T>(), name),
+            RegisteredObject {
+                type_name: std::any::type_name::<T>(),
+                object: value,
+            },
+        );
+    }
+
+    /// Appends a new object to the objects registered for `T` with
+    /// [`Registry::register_all`].
+    ///
+    /// This acquires an exclusive lock on `self.multi_objects`.
+    #[inline]
+    fn push_multi<T: 'static>(&self, value: Object) {
+        self.assert_not_frozen::<T>();
+        let mut lock = self.multi_objects.write();
+        lock.entry(TypeId::of::<T>()).or_default().push(value);
+    }
+
+    /// Move all registrations of `other` into this registry. If `overwrite`
+    /// is `false`, nothing is merged if any type, or key of
+    /// [`Registry::register_named_any`], is registered in both.
+    ///
+    /// This acquires an exclusive lock on `self.objects`,
+    /// `self.multi_objects`, `self.named_any`, `self.default_provider`, and
+    /// `self.metrics_sink`.
+    fn merge_inner(
+        &self,
+        other: Self,
+        overwrite: bool,
+    ) -> Result<(), MergeError> {
+        if self.is_frozen() {
+            return Err(MergeError::Frozen);
+        }
+
+        let Self {
+            objects,
+            multi_objects,
+            validator,
+            named_any,
+            default_provider,
+            #[cfg(feature = "metrics")]
+            metrics_sink,
+            ..
+        } = other;
+
+        {
+            let objects = objects.into_inner();
+            let named_any = named_any.into_inner();
+            let mut lock = self.objects.write();
+            let mut named_lock = self.named_any.write();
+            if !overwrite {
+                if let Some(conflict) = objects
+                    .iter()
+                    .find(|(key, _)| lock.contains_key(*key))
+                    .map(|(_, entry)| entry.type_name)
+                {
+                    return Err(MergeError::Conflict {
+                        type_name: conflict,
+                    });
+                }
+                if named_any.keys().any(|key| named_lock.contains_key(key)) {
+                    // The constructors return a `Box<dyn Any>`, see
+                    // `Registry::register_named_any`.
+                    let type_name =
+                        std::any::type_name::<Box<dyn std::any::Any>>();
+                    return Err(MergeError::Conflict { type_name });
+                }
+            }
+            lock.extend(objects);
+            named_lock.extend(named_any);
+        }
+
+        // The fallbacks of `other` are only used, if this registry has none.
+        if let Some(provider) = default_provider.into_inner() {
+            self.default_provider.write().get_or_insert(provider);
+        }
+        #[cfg(feature = "metrics")]
+        if let Some(sink) = metrics_sink.into_inner() {
+            self.metrics_sink.write().get_or_insert(sink);
+        }
+
+        {
+            let mut lock = self.multi_objects.write();
+            for (type_id, mut entries) in multi_objects.into_inner() {
+                lock.entry(type_id).or_default().append(&mut entries);
+            }
+        }
+
+        self.validator.merge(validator);
+        Ok(())
+    }
+
+    /// Returns `true` if `id` is a key of the objects hashtable.
+    ///
+    /// This acquires a shared lock on `self.objects`.
+    #[inline]
+    fn contains_id_local(&self, id: TypeId) -> bool {
+        let lock = self.objects.read();
+        lock.contains_key(&(id, None))
+    }
+
+    /// Inserts a new object into the objecs hashtable.
+    ///
+    /// This acquires an exclusive lock on `self.objects`.
+    ///
+    /// # Panics
+    /// If the key already exists (=> the type was previously registered), or
+    /// the registry is frozen.
+    #[inline]
+    fn insert_or_panic<T: 'static>(
+        &self,
+        name: Option<&'static str>,
+        value: Object,
+    ) {
+        self.assert_not_frozen::<T>();
+        let inserted = self.try_insert::<T>(name, value).is_ok();
+        assert!(
+            inserted,
+            "Type '{}' ({:?}) is already registered{}",
+            std::any::type_name::<T>(),
+            TypeId::of::<T>(),
+            name.map(|name| format!(" with name '{name}'"))
+                .unwrap_or_default()
+        );
+    }
+
+    /// Inserts a new object into the objecs hashtable.
+    ///
+    /// This acquires an exclusive lock on `self.objects`.
+    ///
+    /// # Errors
+    /// If the key already exists (=> the type was previously registered), or
+    /// the registry is frozen.
+    #[inline]
+    fn try_insert<T: 'static>(
+        &self,
+        name: Option<&'static str>,
+        value: Object,
+    ) -> Result<(), RegistryError> {
+        self.ensure_not_frozen::<T>()?;
+        let mut lock = self.objects.write();
+        let entry = lock.entry((TypeId::of::<T>(), name));
+        match entry {
+            hashbrown::hash_map::Entry::Occupied(_) => {
+                Err(RegistryError::AlreadyRegistered {
+                    type_name: std::any::type_name::<T>(),
+                })
+            }
+            hashbrown::hash_map::Entry::Vacant(view) => {
+                view.insert(RegisteredObject {
+                    type_name: std::any::type_name::<T>(),
+                    object: value,
+                });
+                Ok(())
+            }
+        }
+    }
+
+    /// Inserts the objects for the type `C`, and its alias `A`, into the
+    /// objects hashtable. Either both are inserted, or none.
+    ///
+    /// This acquires an exclusive lock on `self.objects`.
+    ///
+    /// # Errors
+    /// If any of the keys already exists (=> the type was previously
+    /// registered), or the registry is frozen.
+    fn try_insert_aliased<C: 'static, A: 'static>(
+        &self,
+        ctor: Object,
+        alias: Object,
+    ) -> Result<(), RegistryError> {
+        self.ensure_not_frozen::<C>()?;
+        let objects = [
+            (TypeId::of::<C>(), std::any::type_name::<C>(), ctor),
+            (TypeId::of::<A>(), std::any::type_name::<A>(), alias),
+        ];
+
+        let mut lock = self.objects.write();
+        if let Some((_, type_name, _)) = objects
+            .iter()
+            .find(|(type_id, _, _)| lock.contains_key(&(*type_id, None)))
+        {
+            return Err(RegistryError::AlreadyRegistered {
+                type_name: *type_name,
+            });
+        }
+
+        for (type_id, type_name, object) in objects {
+            lock.insert(
+                (type_id, None),
+                RegisteredObject { type_name, object },
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl Registry {
+    /// Create an empty registry, and add all autoregistered types into it.
+    ///
+    /// This is the constructor for the global registry that can be acquired
+    /// with [`Registry::global`].
+    ///
+    /// # Panics
+    /// If any of the constructors panic.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn autoregistered() -> Self {
+        use std::sync::Arc;
+
+        let registry = Arc::new(Self::empty());
+
+        #[cfg(feature = "tokio")]
+        {
+            let mut set = tokio::task::JoinSet::new();
+            for register in inventory::iter::<RegistrationFunc>
+                .into_iter()
+                .filter(|register| register.1.is_none())
+            {
+                let registry = Arc::clone(&registry);
+                set.spawn(async move {
+                    let inner_registry = registry;
+                    (register.0)(&inner_registry).await;
+                });
+            }
+
+            #[allow(clippy::panic)]
+            while let Some(res) = set.join_next().await {
+                match res {
+                    Ok(_) => continue,
+                    Err(err) if err.is_panic() => {
+                        std::panic::resume_unwind(err.into_panic())
+                    }
+                    Err(err) => panic!("{err}"),
+                }
+            }
+        }
+
+        #[cfg(all(feature = "async-std", not(feature = "tokio")))]
+        {
+            use futures_util::StreamExt;
+
+            let mut handles = inventory::iter::<RegistrationFunc>
+                .into_iter()
+                .filter(|register| register.1.is_none())
+                .map(|register| {
+                    let registry = Arc::clone(&registry);
+                    async_std::task::spawn(async move {
+                        let inner_registry = registry;
+                        (register.0)(&inner_registry).await;
+                    })
+                })
+                .collect::<FuturesUnordered<_>>();
+
+            // Joined in the order the tasks complete.
+            while handles.next().await.is_some() {}
+        }
+
+        assert_eq!(
+            Arc::strong_count(&registry),
+            1,
+            "all of the spawned tasks should've joined, dropping their \
+            Arc's. some task is still holding an Arc"
+        );
+        Arc::try_unwrap(registry).expect("all tasks above are joined")
+    }
+
+    /// Create an empty registry, and add all autoregistered types into it,
+    /// without aborting on panicking registration functions.
+    ///
+    /// Same as [`Registry::autoregistered`], except that a panic of a
+    /// registration function is caught, and returned as
+    /// [`RegistrationError::Panicked`]. The types of all other registration
+    /// functions are still registered. Types registered by the panicking
+    /// function, before it panicked, are kept.
+    ///
+    /// In contrast to [`Registry::autoregistered`], the registration functions
+    /// are called one after another.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn autoregistered_lenient() -> (Self, Vec<RegistrationError>) {
+        use std::future::Future;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::task::Poll;
+
+        let registry = Self::empty();
+        let mut errors = Vec::new();
+        for register in inventory::iter::<RegistrationFunc>
+            .into_iter()
+            .filter(|register| register.1.is_none())
+        {
+            // The registry doesn't use poisoning locks, it stays usable after
+            // a panic.
+            let mut registration = (register.0)(&registry);
+            let registered = std::future::poll_fn(|cx| {
+                match catch_unwind(AssertUnwindSafe(|| {
+                    registration.as_mut().poll(cx)
+                })) {
+                    Ok(Poll::Ready(())) => Poll::Ready(Ok(())),
+                    Ok(Poll::Pending) => Poll::Pending,
+                    Err(payload) => Poll::Ready(Err(payload)),
+                }
+            })
+            .await;
+            if let Err(payload) = registered {
+                errors.push(RegistrationError::panicked(payload));
+            }
+        }
+
+        (registry, errors)
+    }
+
+    /// Create a new, ref-counted, registry, and add all autoregistered types
+    /// into it.
+    ///
+    /// In contrast to [`Registry::reset_global`], this doesn't touch the
+    /// global registry. Every call returns a separate registry, e.g., to
+    /// isolate tests running in parallel from each other.
+    ///
+    /// # Panics
+    /// If any of the constructors panic.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn fresh_autoregistered() -> Ref<Self> {
+        Self::autoregistered().await.into_ref()
+    }
+
+    /// Create an empty registry, and add the types of all `funcs` into it.
+    ///
+    /// Same as [`Registry::autoregistered`], except that the registration
+    /// functions are supplied by the caller, instead of collecting all
+    /// autoregistered types. Grouped registration functions are called, too.
+    /// This doesn't touch the global registry.
+    ///
+    /// The registration functions are called one after another.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(funcs)))]
+    pub async fn from_registrations<I>(funcs: I) -> Self
+    where
+        I: IntoIterator<Item = RegistrationFunc>,
+    {
+        let registry = Self::empty();
+        for register in funcs {
+            (register.0)(&registry).await;
+        }
+
+        registry
+    }
+
+    /// Register a new singleton object, without dependencies.
+    ///
+    /// To register a type with dependencies, use the builder returned from
+    /// [`Registry::with_deps`].
+    ///
+    /// # Parameters
+    ///   * `ctor`: A constructor function returning the newly constructed `T`.
+    ///     This constructor will be called once, lazily, when the first
+    ///     instance of `T` is requested.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn singleton<T, F>(&self, ctor: F)
+    where
+        T: RegisterableSingleton,
+        F: SingletonCtor<T>,
+    {
+        use crate::object_builder::AsyncSingletonNoDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering singleton ({})",
+            std::any::type_name::<T>()
+        );
+
+        let singleton =
+            Object::AsyncSingleton(Ref::new(AsyncSingletonNoDeps::new(ctor)));
+
+        self.insert_or_panic::<T>(None, singleton).await;
+        self.validator.add_singleton_no_deps::<T>(None);
+    }
+
+    /// Register a new singleton object, without dependencies, whose
+    /// construction fails if it takes longer than `timeout`.
+    ///
+    /// If the construction times out, [`ResolveError::ConstructionTimeout`] is
+    /// returned, and the next request of `T` calls `ctor` again.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    ///
+    /// With the `tokio` feature, resolving `T` panics if the time driver of
+    /// the Tokio runtime isn't enabled, see
+    /// `tokio::runtime::Builder::enable_time`. It's enabled by
+    /// `#[tokio::main]` and `#[tokio::test]`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn register_singleton_with_timeout<T, F>(
+        &self,
+        timeout: std::time::Duration,
+        ctor: F,
+    ) where
+        T: RegisterableSingleton,
+        F: SingletonCtor<T>,
+    {
+        use crate::object_builder::AsyncSingletonNoDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering singleton ({}) with timeout {:?}",
+            std::any::type_name::<T>(),
+            timeout
+        );
+
+        let singleton = Object::AsyncSingleton(Ref::new(
+            AsyncSingletonNoDeps::with_timeout(ctor, timeout),
+        ));
+
+        self.insert_or_panic::<T>(None, singleton).await;
+        self.validator.add_singleton_no_deps::<T>(None);
+    }
+
+    /// Register a new transient object, without dependencies.
+    ///
+    /// To register a type with dependencies, use the builder returned from
+    /// [`Registry::with_deps`].
+    ///
+    /// # Parameters
+    ///   * `ctor`: A constructor function returning the newly constructed `T`.
+    ///     This constructor will be called for every `T` that is requested.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn transient<T>(
+        &self,
+        ctor: fn() -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = T> + Send>,
+        >,
+    ) where
+        T: Registerable,
+    {
+        use crate::object_builder::AsyncTransientBuilderImplNoDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering transient ({})",
+            std::any::type_name::<T>()
+        );
+
+        let transient = Object::AsyncTransient(Ref::new(
+            AsyncTransientBuilderImplNoDeps::new(ctor),
+        ));
+
+        self.insert_or_panic::<T>(None, transient).await;
+        self.validator.add_transient_no_deps::<T>(None);
+    }
+
+    /// Register a new transient object `C`, without dependencies, and
+    /// additionally as its alias `A`, e.g., a trait object.
+    ///
+    /// `ctor` is registered once, for `C`. Every request for `A` constructs
+    /// a new `C`, and converts it into `A` using `alias`.
+    ///
+    /// # Panics
+    /// When `C` or `A` has been registered already, or the registry is
+    /// frozen. Neither is registered in that case.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip(ctor, alias),
+            fields(
+                type_name = std::any::type_name::<C>(),
+                alias = std::any::type_name::<A>(),
+            )
+        )
+    )]
+    pub async fn register_transient_aliased<C, A>(
+        &self,
+        ctor: fn() -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = C> + Send>,
+        >,
+        alias: fn(C) -> A,
+    ) where
+        C: Registerable,
+        A: Registerable,
+    {
+        #[allow(clippy::panic)]
+        if let Err(err) =
+            self.checked_register_transient_aliased(ctor, alias).await
+        {
+            panic!("{err}");
+        }
+    }
+
+    /// Register a new transient object `C`, without dependencies, and
+    /// additionally as its alias `A`.
+    ///
+    /// This is the non-panicking version of
+    /// [`Registry::register_transient_aliased`].
+    ///
+    /// # Errors
+    /// Returns [`RegistryError::AlreadyRegistered`] when `C` or `A` has been
+    /// registered already, or [`RegistryError::Frozen`] if the registry is
+    /// frozen. Neither is registered in that case.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip(ctor, alias),
+            fields(
+                type_name = std::any::type_name::<C>(),
+                alias = std::any::type_name::<A>(),
+            )
+        )
+    )]
+    pub async fn checked_register_transient_aliased<C, A>(
+        &self,
+        ctor: fn() -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = C> + Send>,
+        >,
+        alias: fn(C) -> A,
+    ) -> Result<(), RegistryError>
+    where
+        C: Registerable,
+        A: Registerable,
+    {
+        use crate::object_builder::{
+            AsyncAliasTransientBuilder, AsyncTransientBuilderImplNoDeps,
+        };
+
+        let transient = Object::AsyncTransient(Ref::new(
+            AsyncTransientBuilderImplNoDeps::new(ctor),
+        ));
+        let aliased = Object::AsyncTransient(Ref::new(
+            AsyncAliasTransientBuilder::new(alias),
+        ));
+
+        self.try_insert_aliased::<C, A>(transient, aliased).await?;
+        self.validator.add_transient_no_deps::<C>(None);
+        self.validator
+            .add_transient_deps::<A, (crate::Transient<C>,)>(None);
+        Ok(())
+    }
+
+    /// Register the transient `Base` as an upcast of the already registered,
+    /// or later registered, transient `Sub`, e.g., to resolve a
+    /// `Box<dyn BaseService>` from the `Box<dyn SpecificService>`
+    /// registration, where `SpecificService: BaseService`.
+    ///
+    /// Every request for `Base` constructs a new `Sub`, and converts it into
+    /// `Base` using `upcast`. Trait objects can't be upcast implicitly, the
+    /// sub-trait usually provides the conversion itself.
+    ///
+    /// # Panics
+    /// When `Base` has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip(upcast),
+            fields(
+                type_name = std::any::type_name::<Sub>(),
+                base = std::any::type_name::<Base>(),
+            )
+        )
+    )]
+    pub async fn register_upcast<Sub, Base>(&self, upcast: fn(Sub) -> Base)
+    where
+        Sub: Registerable,
+        Base: Registerable,
+    {
+        use crate::object_builder::AsyncAliasTransientBuilder;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering upcast ({} -> {})",
+            std::any::type_name::<Sub>(),
+            std::any::type_name::<Base>()
+        );
+
+        let transient = Object::AsyncTransient(Ref::new(
+            AsyncAliasTransientBuilder::new(upcast),
+        ));
+
+        self.insert_or_panic::<Base>(None, transient).await;
+        self.validator
+            .add_transient_deps::<Base, (crate::Transient<Sub>,)>(None);
+    }
+
+    /// Register a new transient object, without dependencies, constructed by
+    /// the stateful constructor `ctor`, e.g., to hand out sequential ids.
+    ///
+    /// In contrast to [`Registry::transient`], `ctor` may mutate its captured
+    /// state. Therefore, it's called while holding an exclusive lock, and
+    /// concurrent constructions of `T` are serialized. The lock is released
+    /// before the returned future is awaited.
+    ///
+    /// Registries duplicated from this registry share the state of `ctor`.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn register_transient_stateful<T, C>(&self, ctor: C)
+    where
+        T: Registerable,
+        C: StatefulTransientCtor<T>,
+    {
+        use crate::object_builder::AsyncStatefulTransientBuilder;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering stateful transient ({})",
+            std::any::type_name::<T>()
+        );
+
+        let builder = AsyncStatefulTransientBuilder::<T, C>::new(ctor);
+        let transient = Object::AsyncTransient(Ref::new(builder));
+
+        self.insert_or_panic::<T>(None, transient).await;
+        self.validator.add_transient_no_deps::<T>(None);
+    }
+
+    /// Register an already constructed `value` as singleton.
+    ///
+    /// The first request for the singleton `T` returns `value`, no
+    /// constructor is ever run. This is useful for values that are loaded or
+    /// constructed before the registry is populated, e.g. the configuration
+    /// of the program.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(value), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn register_instance<T>(&self, value: T)
+    where
+        T: RegisterableSingleton,
+    {
+        use crate::object_builder::AsyncSingletonInstance;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering singleton instance ({})",
+            std::any::type_name::<T>()
+        );
+
+        let singleton = Object::AsyncSingleton(Ref::new(
+            AsyncSingletonInstance::new(value),
+        ));
+
+        self.insert_or_panic::<T>(None, singleton).await;
+        self.validator.add_singleton_no_deps::<T>(None);
+    }
+
+    /// Register a new transient object, constructed with
+    /// [`Default::default`].
+    ///
+    /// Shorthand for `registry.transient(|| Box::pin(async { T::default() }))`.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn register_transient_default<T>(&self)
+    where
+        T: Registerable + Default,
+    {
+        self.transient::<T>(|| Box::pin(std::future::ready(T::default())))
+            .await;
+    }
+
+    /// Register a new singleton object, constructed with
+    /// [`Default::default`].
+    ///
+    /// Shorthand for `registry.singleton(|| Box::pin(async { T::default() }))`.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn register_singleton_default<T>(&self)
+    where
+        T: RegisterableSingleton + Default,
+    {
+        self.singleton::<T, _>(|| Box::pin(std::future::ready(T::default())))
+            .await;
+    }
+
+    /// Register a new transient object, without dependencies, using a
+    /// fallible constructor.
+    ///
+    /// If `ctor` returns an error, resolving `T` fails, with
+    /// [`ResolveError::Ctor`] at the end of the dependency chain.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn try_register_transient<T>(
+        &self,
+        ctor: fn() -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = Result<T, BoxErr>> + Send>,
+        >,
+    ) where
+        T: Registerable,
+    {
+        use crate::object_builder::AsyncTryTransientBuilderNoDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering fallible transient ({})",
+            std::any::type_name::<T>()
+        );
+
+        let transient = Object::AsyncTransient(Ref::new(
+            AsyncTryTransientBuilderNoDeps::new(ctor),
+        ));
+
+        self.insert_or_panic::<T>(None, transient).await;
+        self.validator.add_transient_no_deps::<T>(None);
+    }
+
+    /// Register a new singleton object, without dependencies, using a
+    /// fallible constructor.
+    ///
+    /// If `ctor` returns an error, resolving `T` fails, with
+    /// [`ResolveError::Ctor`] at the end of the dependency chain. The error
+    /// isn't cached, the next request of `T` calls `ctor` again.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn try_register_singleton<T, F>(&self, ctor: F)
+    where
+        T: RegisterableSingleton,
+        F: SingletonCtor<Result<T, BoxErr>>,
+    {
+        use crate::object_builder::AsyncTrySingleton;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering fallible singleton ({})",
+            std::any::type_name::<T>()
+        );
+
+        let singleton =
+            Object::AsyncSingleton(Ref::new(AsyncTrySingleton::<T, ()>::new(
+                move |()| ctor(),
+            )));
+
+        self.insert_or_panic::<T>(None, singleton).await;
+        self.validator.add_singleton_no_deps::<T>(None);
+    }
+
+    /// Register a new singleton object, without dependencies.
+    ///
+    /// This is the non-panicking version of [`Registry::singleton`].
+    ///
+    /// # Errors
+    /// Returns [`RegistryError::AlreadyRegistered`] when the type has been
+    /// registered already, or [`RegistryError::Frozen`] if the registry is
+    /// frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn checked_singleton<T, F>(
+        &self,
+        ctor: F,
+    ) -> Result<(), RegistryError>
+    where
+        T: RegisterableSingleton,
+        F: SingletonCtor<T>,
+    {
+        use crate::object_builder::AsyncSingletonNoDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering singleton ({})",
+            std::any::type_name::<T>()
+        );
+
+        let singleton =
+            Object::AsyncSingleton(Ref::new(AsyncSingletonNoDeps::new(ctor)));
+
+        self.try_insert::<T>(None, singleton).await?;
+        self.validator.add_singleton_no_deps::<T>(None);
+        Ok(())
+    }
+
+    /// Register a new transient object, without dependencies.
+    ///
+    /// This is the non-panicking version of [`Registry::transient`].
+    ///
+    /// # Errors
+    /// Returns [`RegistryError::AlreadyRegistered`] when the type has been
+    /// registered already, or [`RegistryError::Frozen`] if the registry is
+    /// frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn checked_transient<T>(
+        &self,
+        ctor: fn() -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = T> + Send>,
+        >,
+    ) -> Result<(), RegistryError>
+    where
+        T: Registerable,
+    {
+        use crate::object_builder::AsyncTransientBuilderImplNoDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering transient ({})",
+            std::any::type_name::<T>()
+        );
+
+        let transient = Object::AsyncTransient(Ref::new(
+            AsyncTransientBuilderImplNoDeps::new(ctor),
+        ));
+
+        self.try_insert::<T>(None, transient).await?;
+        self.validator.add_transient_no_deps::<T>(None);
+        Ok(())
+    }
+
+    /// Register a new singleton object, without dependencies, replacing any
+    /// previous registration of `T`.
+    ///
+    /// A previously constructed instance of `T` is discarded by the registry;
+    /// the next request for `T` constructs a new instance using `ctor`.
+    /// Instances that are still referenced elsewhere are not affected.
+    ///
+    /// This is useful for replacing dependencies in test fixtures, without
+    /// having to rebuild the whole registry. If `T` isn't registered yet, it's
+    /// registered as with [`Registry::singleton`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn override_singleton<T, F>(&self, ctor: F)
+    where
+        T: RegisterableSingleton,
+        F: SingletonCtor<T>,
+    {
+        use crate::object_builder::AsyncSingletonNoDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!("overriding singleton ({})", std::any::type_name::<T>());
+
+        let singleton =
+            Object::AsyncSingleton(Ref::new(AsyncSingletonNoDeps::new(ctor)));
+
+        self.replace::<T>(None, singleton).await;
+        self.validator.add_singleton_no_deps::<T>(None);
+    }
+
+    /// Register a new transient object, without dependencies, replacing any
+    /// previous registration of `T`.
+    ///
+    /// This is useful for replacing dependencies in test fixtures, without
+    /// having to rebuild the whole registry. If `T` isn't registered yet, it's
+    /// registered as with [`Registry::transient`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn override_transient<T>(
+        &self,
+        ctor: fn() -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = T> + Send>,
+        >,
+    ) where
+        T: Registerable,
+    {
+        use crate::object_builder::AsyncTransientBuilderImplNoDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!("overriding transient ({})", std::any::type_name::<T>());
+
+        let transient = Object::AsyncTransient(Ref::new(
+            AsyncTransientBuilderImplNoDeps::new(ctor),
+        ));
+
+        self.replace::<T>(None, transient).await;
+        self.validator.add_transient_no_deps::<T>(None);
+    }
+
+    /// Register a new transient object, without dependencies, and return a
+    /// handle to the registration.
+    ///
+    /// The handle allows overriding or removing the registration later,
+    /// without naming `T` again. See [`Registry::transient`].
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn transient_handle<T>(
+        &self,
+        ctor: fn() -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = T> + Send>,
+        >,
+    ) -> Registration<'_, T>
+    where
+        T: Registerable,
+    {
+        self.transient(ctor).await;
+        Registration::new(self)
+    }
+
+    /// Register a new singleton object, without dependencies, and return a
+    /// handle to the registration.
+    ///
+    /// The handle allows overriding or removing the registration later,
+    /// without naming `T` again. See [`Registry::singleton`].
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn singleton_handle<T, F>(&self, ctor: F) -> Registration<'_, T>
+    where
+        T: RegisterableSingleton,
+        F: SingletonCtor<T>,
+    {
+        self.singleton(ctor).await;
+        Registration::new(self)
+    }
+
+    /// Register a new singleton object, without dependencies, under the name
+    /// `name`.
+    ///
+    /// Named registrations allow registering multiple constructors for the
+    /// same type `T`. They're resolved with [`Registry::get_singleton_named`],
+    /// or with [`NamedSingleton`] as a dependency.
+    ///
+    /// # Panics
+    /// When the type has been registered already with the same name, or the
+    /// registry is frozen.
+    ///
+    /// [`NamedSingleton`]: crate::dependencies::NamedSingleton
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn singleton_named<T, F>(&self, name: &'static str, ctor: F)
+    where
+        T: RegisterableSingleton,
+        F: SingletonCtor<T>,
+    {
+        use crate::object_builder::AsyncSingletonNoDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering named singleton ({}, {name})",
+            std::any::type_name::<T>()
+        );
+
+        let singleton =
+            Object::AsyncSingleton(Ref::new(AsyncSingletonNoDeps::new(ctor)));
+
+        self.insert_or_panic::<T>(Some(name), singleton).await;
+        self.validator.add_singleton_no_deps::<T>(Some(name));
+    }
+
+    /// Register a new transient object, without dependencies, under the name
+    /// `name`.
+    ///
+    /// Named registrations allow registering multiple constructors for the
+    /// same type `T`. They're resolved with [`Registry::get_transient_named`],
+    /// or with [`NamedTransient`] as a dependency.
+    ///
+    /// # Panics
+    /// When the type has been registered already with the same name, or the
+    /// registry is frozen.
+    ///
+    /// [`NamedTransient`]: crate::dependencies::NamedTransient
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn transient_named<T>(
+        &self,
+        name: &'static str,
+        ctor: fn() -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = T> + Send>,
+        >,
+    ) where
+        T: Registerable,
+    {
+        use crate::object_builder::AsyncTransientBuilderImplNoDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering named transient ({}, {name})",
+            std::any::type_name::<T>()
+        );
+
+        let transient = Object::AsyncTransient(Ref::new(
+            AsyncTransientBuilderImplNoDeps::new(ctor),
+        ));
+
+        self.insert_or_panic::<T>(Some(name), transient).await;
+        self.validator.add_transient_no_deps::<T>(Some(name));
+    }
+
+    /// Register one more transient constructor of `T`, without dependencies.
+    ///
+    /// Any number of constructors can be registered for the same `T`, all of
+    /// them are constructed with [`Registry::transient_all`]. These are
+    /// independent of the registrations done with [`Registry::transient`],
+    /// and can't be injected as dependencies.
+    ///
+    /// The `ctor` must return a boxed `dyn Future`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn register_all<T>(
+        &self,
+        ctor: fn() -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = T> + Send>,
+        >,
+    ) where
+        T: Registerable,
+    {
+        use crate::object_builder::AsyncTransientBuilderImplNoDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering transient into collection ({})",
+            std::any::type_name::<T>()
+        );
+
+        let transient = Object::AsyncTransient(Ref::new(
+            AsyncTransientBuilderImplNoDeps::new(ctor),
+        ));
+
+        self.push_multi::<T>(transient).await;
+    }
+
+    /// Register a new scoped object, without dependencies.
+    ///
+    /// A scoped object is constructed once per scope, and shared within it.
+    /// See [`Registry::enter_scope`].
+    ///
+    /// # Parameters
+    ///   * `ctor`: A constructor function returning the newly constructed `T`.
+    ///     This constructor will be called once per scope, lazily, when the
+    ///     first instance of `T` is requested within the scope.
+    ///
+    /// The `ctor` must return a boxed `dyn Future`.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn register_scoped<T>(
+        &self,
+        ctor: fn() -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = T> + Send>,
+        >,
+    ) where
+        T: RegisterableSingleton,
+    {
+        use crate::object_builder::AsyncTransientBuilderImplNoDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!("registering scoped ({})", std::any::type_name::<T>());
+
+        let scoped = Object::AsyncScoped(Ref::new(
+            AsyncTransientBuilderImplNoDeps::new(ctor),
+        ));
+
+        self.insert_or_panic::<T>(None, scoped).await;
+        self.validator.add_scoped_no_deps::<T>();
+    }
+
+    /// Retrieves the singleton `T`, registering it with `ctor` first if `T`
+    /// hasn't been registered yet.
+    ///
+    /// Checking whether `T` is registered, and registering it, happens in a
+    /// single critical section. It's safe to call this concurrently from
+    /// multiple tasks, only one `ctor` is ever registered. If `T` has been
+    /// registered already, on this registry or any of its parents, `ctor` is
+    /// dropped without being called.
+    ///
+    /// # Panics
+    /// When `T` has been registered, but not as singleton, when `T` failed to
+    /// construct, or when `T` hasn't been registered and the registry is
+    /// frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn get_or_register_singleton<T, F>(&self, ctor: F) -> Ref<T>
+    where
+        T: RegisterableSingleton,
+        F: SingletonCtor<T>,
+    {
+        use crate::object_builder::AsyncSingletonNoDeps;
+
+        if !self.contains::<T>().await {
+            self.assert_not_frozen::<T>();
+
+            let singleton = Object::AsyncSingleton(Ref::new(
+                AsyncSingletonNoDeps::new(ctor),
+            ));
+            if self.try_insert::<T>(None, singleton).await.is_ok() {
+                #[cfg(feature = "tracing")]
+                tracing::info!(
+                    "registered singleton ({})",
+                    std::any::type_name::<T>()
+                );
+
+                self.validator.add_singleton_no_deps::<T>(None);
+            }
+        }
+
+        self.resolve_singleton::<T>(None)
+            .await
+            .expect("to be registered as constructible singleton")
+    }
+
+    /// Register a new transient object, without dependencies, if `cond` is
+    /// `true`.
+    ///
+    /// Returns whether `T` has been registered.
+    ///
+    /// # Panics
+    /// When `cond` is `true`, and the type has been registered already, or the
+    /// registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn register_transient_if<T>(
+        &self,
+        cond: bool,
+        ctor: fn() -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = T> + Send>,
+        >,
+    ) -> bool
+    where
+        T: Registerable,
+    {
+        if cond {
+            self.transient(ctor).await;
+        }
+        cond
+    }
+
+    /// Register a new singleton object, without dependencies, if `cond` is
+    /// `true`.
+    ///
+    /// Returns whether `T` has been registered. If `cond` is `false`, `ctor`
+    /// is dropped without being called.
+    ///
+    /// # Panics
+    /// When `cond` is `true`, and the type has been registered already, or the
+    /// registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn register_singleton_if<T, F>(&self, cond: bool, ctor: F) -> bool
+    where
+        T: RegisterableSingleton,
+        F: SingletonCtor<T>,
+    {
+        if cond {
+            self.singleton(ctor).await;
+        }
+        cond
+    }
+
+    /// Register the constructor of the first of `candidates`, whose condition
+    /// is `true`, as transient object without dependencies.
+    ///
+    /// This allows to express fallbacks, e.g., "prefer `a`, else `b`, else
+    /// `default`".
+    ///
+    /// Returns whether `T` has been registered, i.e., `false` if none of the
+    /// conditions are `true`.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(candidates), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn register_first_available<T>(
+        &self,
+        candidates: &[(bool, TransientCtor<T>)],
+    ) -> bool
+    where
+        T: Registerable,
+    {
+        let Some((_, ctor)) = candidates.iter().find(|(cond, _)| *cond) else {
+            return false;
+        };
+
+        self.transient(*ctor).await;
+        true
+    }
+
+    /// Register a new factory, constructing a new `T` from runtime arguments
+    /// `Args` on every call to [`Registry::make`].
+    ///
+    /// The factory receives this registry, to resolve further dependencies,
+    /// and the arguments passed to [`Registry::make`]. The constructed `T` is
+    /// never cached.
+    /// The factory returns a pinned, boxed future constructing `T`.
+    ///
+    /// Factories can't be used as dependencies of other types, since their
+    /// arguments are only known at the call site.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(factory), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn register_factory<Args, T, F>(&self, factory: F)
+    where
+        Args: 'static,
+        T: Registerable,
+        F: Factory<Args, T>,
+    {
+        #[cfg(feature = "tracing")]
+        tracing::info!("registering factory ({})", std::any::type_name::<T>());
+
+        let factory: Box<dyn Factory<Args, T>> = Box::new(factory);
+        self.insert_or_panic::<T>(
+            None,
+            Object::AsyncFactory(Ref::new(factory)),
+        )
+        .await;
+    }
+
+    /// Calls `callback` with every singleton registered on this registry, that has
+    /// been constructed already. Singletons that haven't been constructed
+    /// yet are skipped, nothing is constructed.
+    ///
+    /// The order of the singletons is unspecified, use
+    /// [`Registry::shutdown_order`] to get the singletons in dependency
+    /// order.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(callback)))]
+    pub async fn for_each_singleton<F>(&self, mut callback: F)
+    where
+        F: FnMut(&RefAny),
+    {
+        let singletons = {
+            let lock = self.objects.read().await;
+            lock.values()
+                .filter_map(|entry| match &entry.object {
+                    Object::AsyncSingleton(singleton) => {
+                        singleton.get_if_init()
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        for singleton in &singletons {
+            callback(singleton);
+        }
+    }
+
+    /// Returns all singletons registered on this registry, that have been
+    /// constructed already, in the order they should be shut down: every
+    /// singleton comes before the singletons it depends on.
+    ///
+    /// # Errors
+    /// Returns [`ValidationError::Cycle`] if the dependency graph has cycles.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn shutdown_order(&self) -> Result<Vec<RefAny>, ValidationError> {
+        let order = self.validator.topological_order()?;
+
+        let lock = self.objects.read().await;
+        Ok(order
+            .iter()
+            .filter_map(|key| match lock.get(key).map(|entry| &entry.object) {
+                Some(Object::AsyncSingleton(singleton)) => {
+                    singleton.get_if_init()
+                }
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Constructs all singletons registered on this registry, that haven't
+    /// been constructed yet, in dependency order: every singleton is
+    /// constructed after the singletons it depends on.
+    ///
+    /// Singletons registered on a parent registry aren't constructed.
+    ///
+    /// # Errors
+    /// Returns [`ResolveError::Cycle`] if the dependency graph has cycles, no
+    /// singleton is constructed in that case. Otherwise, returns the error of
+    /// the first singleton that fails to construct. The singletons that were
+    /// constructed before it stay constructed.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn initialize_all_singletons(&self) -> Result<(), ResolveError> {
+        let order = self
+            .validator
+            .topological_order()
+            .map_err(|_| ResolveError::Cycle)?;
+
+        for key in order.iter().rev() {
+            let singleton = {
+                let lock = self.objects.read().await;
+                let Some(Object::AsyncSingleton(singleton)) =
+                    lock.get(key).map(|entry| &entry.object)
+                else {
+                    continue;
+                };
+                Ref::clone(singleton)
+            };
+
+            singleton.get_singleton(self).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Constructs the singletons with the [`TypeId`]s `ids` concurrently,
+    /// and yields every singleton as soon as it has been constructed.
+    ///
+    /// Independent singletons are constructed concurrently, a dependency
+    /// shared by multiple singletons is still only constructed once. Every id
+    /// is yielded exactly once, in the order of completion.
+    ///
+    /// If no singleton with the id is registered on this registry, the parent
+    /// registries are searched, starting with the closest one.
+    ///
+    /// [`ResolveError::TypeMissing`] is yielded for every id that's not
+    /// registered as a singleton, and the same errors as
+    /// [`Registry::try_get_singleton`] for singletons that failed to
+    /// construct.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn construct_many<'a>(
+        &'a self,
+        ids: &[TypeId],
+    ) -> impl Stream<Item = (TypeId, Result<RefAny, ResolveError>)> + 'a {
+        ids.iter()
+            .map(|&id| async move { (id, self.singleton_dyn(id).await) })
+            .collect::<FuturesUnordered<_>>()
+    }
+
+    /// Resolves the dependency `D`, the same way it's resolved for the
+    /// constructor of a registered type, e.g., [`Transient<T>`] constructs a
+    /// new `T`, and [`Singleton<T>`] returns the shared `T`.
+    ///
+    /// [`Transient<T>`]: crate::Transient
+    /// [`Singleton<T>`]: crate::Singleton
+    ///
+    /// # Errors
+    /// Returns an error if `D` couldn't be resolved, e.g., because the type
+    /// isn't registered, or failed to construct.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn resolve_dep<D>(&self) -> Result<D, ResolveError>
+    where
+        D: Dep,
+    {
+        D::new(self).await
+    }
+
+    /// Retrieves a newly constructed `T` from this registry.
+    ///
+    /// Returns `None` if `T` wasn't registered or failed to construct.
+    #[must_use]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn get_transient<T>(&self) -> Option<T>
+    where
+        T: Registerable,
+    {
+        self.resolve_transient::<T>(None).await.ok()
+    }
+
+    /// Retrieves a newly constructed `T` from this registry, that was
+    /// registered with the name `name`.
+    ///
+    /// Returns `None` if `T` wasn't registered with `name` or failed to
+    /// construct.
+    #[must_use]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn get_transient_named<T>(&self, name: &'static str) -> Option<T>
+    where
+        T: Registerable,
+    {
+        self.resolve_transient::<T>(Some(name)).await.ok()
+    }
+
+    /// Constructs a new `T` with the factory registered with
+    /// [`Registry::register_factory`], passing `args` to it.
+    ///
+    /// If no factory for `T` is registered on this registry, the parent
+    /// registries are searched, starting with the closest one.
+    ///
+    /// # Errors
+    /// Returns [`ResolveError::TypeMissing`] if no factory for `T`, taking
+    /// `Args`, has been registered.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(args), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn make<T, Args>(&self, args: Args) -> Result<T, ResolveError>
+    where
+        T: Registerable,
+        Args: Send + 'static,
+    {
+        if !self.contains_local::<T>().await {
+            for parent in self.ancestors() {
+                if parent.contains_local::<T>().await {
+                    return parent.make_local(args).await;
+                }
+            }
+        }
+
+        self.make_local(args).await
+    }
+
+    /// Retrieves the singleton `T` from this registry.
+    ///
+    /// Returns `None` if `T` wasn't registered or failed to construct. The
+    /// singleton is a ref-counted pointer object (either `Arc` or `Rc`).
+    #[must_use]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn get_singleton<T>(&self) -> Option<Ref<T>>
+    where
+        T: RegisterableSingleton,
+    {
+        self.resolve_singleton::<T>(None).await.ok()
+    }
+
+    /// Retrieves a newly constructed `T` from this registry.
+    ///
+    /// # Errors
+    /// Returns [`ResolveError::DependencyChain`] if `T`, or any of its
+    /// dependencies, weren't registered or failed to construct. The
+    /// [`ResolveError::chain`] contains the names of all types along the path
+    /// that failed.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn try_get_transient<T>(&self) -> Result<T, ResolveError>
+    where
+        T: Registerable,
+    {
+        self.resolve_transient::<T>(None).await
+    }
+
+    /// Retrieves a newly constructed object of the transient type with the
+    /// [`TypeId`] `id` from this registry, without downcasting it.
+    ///
+    /// This is useful for dynamic dispatch, where the concrete type isn't
+    /// known at the call site, e.g., deserialization keyed by [`TypeId`].
+    ///
+    /// # Errors
+    /// Returns [`ResolveError::TypeMissing`] if no transient with `id` is
+    /// registered, and the same errors as [`Registry::try_get_transient`] if
+    /// any of its dependencies weren't registered or failed to construct.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn transient_dyn(
+        &self,
+        id: TypeId,
+    ) -> Result<Box<dyn std::any::Any + Send>, ResolveError> {
+        if let Some(resolved) = self.transient_dyn_local(id).await {
+            return resolved;
+        }
+
+        for parent in self.ancestors() {
+            if let Some(resolved) = parent.transient_dyn_local(id).await {
+                return resolved;
+            }
+        }
+
+        if let Some(resolved) = self.resolve_default(id) {
+            return Ok(resolved);
+        }
+
+        Err(self.missing_error())
+    }
+
+    /// Retrieves the singleton `T` from this registry.
+    ///
+    /// # Errors
+    /// Returns [`ResolveError::DependencyChain`] if `T`, or any of its
+    /// dependencies, weren't registered or failed to construct. The
+    /// [`ResolveError::chain`] contains the names of all types along the path
+    /// that failed.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn try_get_singleton<T>(&self) -> Result<Ref<T>, ResolveError>
+    where
+        T: RegisterableSingleton,
+    {
+        self.resolve_singleton::<T>(None).await
+    }
+
+    /// Retrieves a clone of the singleton `T` from this registry, instead of
+    /// the shared `Ref<T>`.
+    ///
+    /// This is useful for small singletons, e.g., configuration structs, that
+    /// are cheap to clone.
+    ///
+    /// # Errors
+    /// Same as [`Registry::try_get_singleton`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn singleton_owned<T>(&self) -> Result<T, ResolveError>
+    where
+        T: RegisterableSingleton + Clone,
+    {
+        self.resolve_singleton::<T>(None)
+            .await
+            .map(|rc| (*rc).clone())
+    }
+
+    /// Retrieves the singleton `T` from this registry, only if it has already
+    /// been constructed.
+    ///
+    /// Returns `None` if `T` wasn't registered, or hasn't been constructed
+    /// yet. In contrast to [`Registry::get_singleton`], this never runs the
+    /// constructor of `T`.
+    #[must_use]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn peek_singleton<T>(&self) -> Option<Ref<T>>
+    where
+        T: RegisterableSingleton,
+    {
+        let lock = self.objects.read().await;
+        let Some(Object::AsyncSingleton(singleton)) = lock
+            .get(&(TypeId::of::<T>(), None))
+            .map(|entry| &entry.object)
+        else {
+            return None;
+        };
+
+        singleton.get_if_init()?.downcast::<T>().ok()
+    }
+
+    /// Drops the constructed singleton `T`, while keeping its registration.
+    /// The next time `T` is requested, it's constructed again with the same
+    /// constructor.
+    ///
+    /// Objects that already hold a reference to `T` keep the old instance.
+    ///
+    /// Returns `true` if a constructed instance was dropped, `false` if `T`
+    /// wasn't registered as singleton, hasn't been constructed yet, or was
+    /// registered with [`Registry::register_instance`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn invalidate_singleton<T>(&self) -> bool
+    where
+        T: RegisterableSingleton,
+    {
+        let invalidated = {
+            let mut lock = self.objects.write().await;
+            match lock
+                .get_mut(&(TypeId::of::<T>(), None))
+                .map(|entry| &mut entry.object)
+            {
+                Some(Object::AsyncSingleton(singleton)) => {
+                    invalidate_shared(singleton)
+                }
+                _ => None,
+            }
+        };
+
+        // The instance is dropped after the lock is released, in case it
+        // accesses the registry on drop.
+        invalidated.is_some()
+    }
+
+    /// Retrieves the singleton `T` from this registry, that was registered
+    /// with the name `name`.
+    ///
+    /// Returns `None` if `T` wasn't registered with `name` or failed to
+    /// construct. The singleton is a ref-counted pointer object (either `Arc`
+    /// or `Rc`).
+    #[must_use]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn get_singleton_named<T>(
+        &self,
+        name: &'static str,
+    ) -> Option<Ref<T>>
+    where
+        T: RegisterableSingleton,
+    {
+        self.resolve_singleton::<T>(Some(name)).await.ok()
+    }
+
+    /// Constructs a new scoped `T`, for the scope that's resolving it, see
+    /// [`ScopeGuard::get`].
+    ///
+    /// If `T` isn't registered on this registry, the parent registries are
+    /// searched, starting with the closest one.
+    pub(crate) async fn make_scoped<T>(&self) -> Result<T, ResolveError>
+    where
+        T: Registerable,
+    {
+        if let Some(made) = self.make_scoped_local::<T>().await {
+            return made;
+        }
+
+        for parent in self.ancestors() {
+            if let Some(made) = parent.make_scoped_local::<T>().await {
+                return made;
+            }
+        }
+
+        Err(self.missing_error().with_type(std::any::type_name::<T>()))
+    }
+
+    /// Constructs every `T` registered with [`Registry::register_all`], in
+    /// the order they were registered.
+    ///
+    /// Returns an empty `Vec` if no constructor was registered for `T`.
+    ///
+    /// # Errors
+    /// Returns the error of the dependency that failed to construct any of
+    /// the `T`s, or [`ResolveError::TypeMismatch`] if any of the constructed
+    /// objects isn't a `T`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn transient_all<T>(&self) -> Result<Vec<T>, ResolveError>
+    where
+        T: Registerable,
+    {
+        let ctors = {
+            let lock = self.multi_objects.read().await;
+            let Some(objects) = lock.get(&TypeId::of::<T>()) else {
+                return Ok(Vec::new());
+            };
+            objects
+                .iter()
+                .filter_map(|object| match object {
+                    Object::AsyncTransient(ctor) => Some(Ref::clone(ctor)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut all = Vec::with_capacity(ctors.len());
+        for ctor in ctors {
+            let boxed = ctor.make_transient(self).await?;
+            let obj = boxed
+                .downcast::<T>()
+                .ok()
+                .ok_or_else(ResolveError::type_mismatch::<T>)?;
+            all.push(*obj);
+        }
+
+        Ok(all)
+    }
+
+    /// Returns the number of types registered in this registry.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn len(&self) -> usize {
+        let lock = self.objects.read().await;
+        lock.len()
+    }
+
+    /// Returns `true` if no types are registered in this registry.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn is_empty(&self) -> bool {
+        let lock = self.objects.read().await;
+        lock.is_empty()
+    }
+
+    /// Returns the [`TypeId`] and type name of all types registered in this
+    /// registry, in no particular order.
+    ///
+    /// The type name is captured at registration time with
+    /// [`std::any::type_name`], therefore, it's "best effort", and might not
+    /// be correct or reproducible.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn registered_types(&self) -> Vec<(TypeId, &'static str)> {
+        let lock = self.objects.read().await;
+        lock.iter()
+            .map(|((type_id, _), entry)| (*type_id, entry.type_name))
+            .collect()
+    }
+
+    /// Returns the [`TypeId`] and type name of all types registered in this
+    /// registry, or any of its parents, in no particular order.
+    ///
+    /// Registrations of this registry shadow the registrations of its
+    /// parents, the same way they do for resolution. See
+    /// [`Registry::registered_types`] for the caveats of the type name.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn registered_types_recursive(
+        &self,
+    ) -> Vec<(TypeId, &'static str)> {
+        let mut types = HashMap::new();
+        self.insert_registered_types(&mut types).await;
+        for parent in self.ancestors() {
+            parent.insert_registered_types(&mut types).await;
+        }
+
+        types
+            .into_iter()
+            .map(|((type_id, _), type_name)| (type_id, type_name))
+            .collect()
+    }
+
+    /// Inserts the type names of all types registered in this registry into
+    /// `types`, unless their key is in `types` already.
+    ///
+    /// This acquires a shared lock on `self.objects`.
+    async fn insert_registered_types(
+        &self,
+        types: &mut HashMap<RegistrationKey, &'static str>,
+    ) {
+        let lock = self.objects.read().await;
+        for (key, entry) in lock.iter() {
+            types.entry(*key).or_insert(entry.type_name);
+        }
+    }
+
+    /// Returns `true` if `T` is registered, on this registry or any of its
+    /// parents.
+    ///
+    /// The type isn't constructed, and its dependencies aren't checked. Use
+    /// [`Registry::validate`] for that.
+    #[must_use]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn contains<T: 'static>(&self) -> bool {
+        self.contains_id(TypeId::of::<T>()).await
+    }
+
+    /// Returns `true` if a type with the [`TypeId`] `id` is registered, on
+    /// this registry or any of its parents.
+    ///
+    /// See [`Registry::contains`].
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn contains_id(&self, id: TypeId) -> bool {
+        if self.contains_id_local(id).await {
+            return true;
+        }
+
+        for parent in self.ancestors() {
+            if parent.contains_id_local(id).await {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Returns `true` if `T` was registered directly on this registry.
+    #[must_use]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn contains_local<T: 'static>(&self) -> bool {
+        self.contains_id_local(TypeId::of::<T>()).await
+    }
+
+    /// Returns which registry `T` is resolved from, without constructing it.
+    ///
+    /// Returns `None` if `T` isn't registered, neither on this registry, nor
+    /// any of its parents.
+    #[must_use]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn resolve_source<T: 'static>(&self) -> Option<ResolveSource> {
+        if self.contains_local::<T>().await {
+            return Some(ResolveSource::Local);
+        }
+
+        for (depth, parent) in (1..).zip(self.ancestors()) {
+            if parent.contains_local::<T>().await {
+                return Some(ResolveSource::Parent(depth));
+            }
+        }
+
+        None
+    }
+
+    /// Remove the registration of `T` from this registry.
+    ///
+    /// Returns `true` if `T` was registered, `false` otherwise. Objects that
+    /// have already been resolved (e.g., the instance of a singleton) are not
+    /// affected and stay alive as long as they're referenced.
+    ///
+    /// Types that depend on `T` are still registered, but will fail
+    /// validation until `T` is registered again.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn remove<T: 'static>(&self) -> bool {
+        self.assert_not_frozen::<T>();
+        let removed = {
+            let mut lock = self.objects.write().await;
+            lock.remove(&(TypeId::of::<T>(), None)).is_some()
+        };
+
+        if removed {
+            self.validator.remove::<T>(None);
+        }
+
+        removed
+    }
+
+    /// Move all registrations of `other` into this registry.
+    ///
+    /// The dependency graph of `other` is merged as well, so that
+    /// [`Registry::validate_all`] validates the combined graph.
+    ///
+    /// Constructors registered with [`Registry::register_named_any`] are
+    /// merged as well. The default provider (see
+    /// [`Registry::set_default_provider`]) and the metrics sink of `other`
+    /// are only kept, if this registry has none.
+    ///
+    /// # Errors
+    /// Returns [`MergeError::Conflict`] if a type, or a key of
+    /// [`Registry::register_named_any`], is registered in both registries, or
+    /// [`MergeError::Frozen`] if this registry is frozen. Nothing is merged in
+    /// that case.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn merge(&self, other: Self) -> Result<(), MergeError> {
+        self.merge_inner(other, false).await
+    }
+
+    /// Move all registrations of `other` into this registry, replacing the
+    /// registrations of types that are registered in both registries.
+    ///
+    /// See [`Registry::merge`].
+    ///
+    /// # Errors
+    /// Returns [`MergeError::Frozen`] if this registry is frozen. Nothing is
+    /// merged in that case.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn merge_override(&self, other: Self) -> Result<(), MergeError> {
+        self.merge_inner(other, true).await
+    }
+
+    /// Create a new registry with the same registrations as this registry.
+    ///
+    /// Singletons aren't constructed in the new registry, even if they've
+    /// been constructed in this registry already, they're constructed again
+    /// on first use. Only singletons registered with
+    /// [`Registry::register_instance`] share their instance.
+    ///
+    /// This is useful to isolate tests from each other, without registering
+    /// all types again.
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn duplicate(&self) -> Self {
+        let objects = {
+            let lock = self.objects.read().await;
+            lock.iter()
+                .map(|(key, entry)| (*key, entry.duplicate()))
+                .collect()
+        };
+        let multi_objects = {
+            let lock = self.multi_objects.read().await;
+            lock.iter()
+                .map(|(type_id, entries)| {
+                    (*type_id, entries.iter().map(Object::duplicate).collect())
+                })
+                .collect()
+        };
+
+        Self {
+            objects: RwLock::new(objects),
+            multi_objects: RwLock::new(multi_objects),
+            validator: self.validator.duplicate(),
+            parent: self.parent.clone(),
+            this: None,
+            frozen: AtomicBool::new(self.is_frozen()),
+            named_any: NonAsyncRwLock::new(self.named_any.read().clone()),
+            default_provider: NonAsyncRwLock::new(
+                self.default_provider.read().clone(),
+            ),
+            #[cfg(feature = "metrics")]
+            metrics_sink: NonAsyncRwLock::new(self.metrics_sink.read().clone()),
+        }
+    }
+
+    /// Access the global registry.
+    ///
+    /// This registry contains the types that are marked for auto-registration
+    /// via the derive macro.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn global() -> &'static Self {
+        DEFAULT_REGISTRY.get_or_init(Self::autoregistered).await
+    }
+
+    /// Reset the global registry, removing all previously registered types, and
+    /// re-running the auto-registration routines.
+    ///
+    /// Prefer [`Registry::fresh_autoregistered`] to get a separate registry,
+    /// without modifying the global registry.
+    ///
+    /// # Safety
+    /// Ensure that no other thread is currently using [`Registry::global()`].
+    #[allow(unsafe_code)]
+    pub async unsafe fn reset_global() {
+        // Purposefully not annotated with `tracing::instrument` because it mangles the order of
+        // `async` and `unsafe`, resulting in a compiler error.
+        let registry = Self::global().await;
+        {
+            let mut lock = registry.objects.write().await;
+            lock.clear();
+        }
+
+        for register in inventory::iter::<RegistrationFunc>
+            .into_iter()
+            .filter(|register| register.1.is_none())
+        {
+            (register.0)(registry).await;
+        }
+    }
+
+    /// Add all types that are autoregistered in the group `group` into this
+    /// registry, e.g., with `#[provides(transient, group = "plugins")]`.
+    ///
+    /// Grouped types aren't part of the global registry.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub async fn populate_group(&self, group: &str) {
+        for register in inventory::iter::<RegistrationFunc>
+            .into_iter()
+            .filter(|register| register.1 == Some(group))
+        {
+            (register.0)(self).await;
+        }
+    }
+
+    /// Retrieves a newly constructed `T`, registered with `name`.
+    ///
+    /// If `T` isn't registered on this registry, the parent registries are
+    /// searched, starting with the closest one.
+    pub(crate) async fn resolve_transient<T>(
+        &self,
+        name: Option<&'static str>,
+    ) -> Result<T, ResolveError>
+    where
+        T: Registerable,
+    {
+        if let Some(resolved) = self.resolve_transient_local::<T>(name).await {
+            return resolved;
+        }
+
+        for parent in self.ancestors() {
+            if let Some(resolved) =
+                parent.resolve_transient_local::<T>(name).await
+            {
+                return resolved;
+            }
+        }
+
+        if name.is_none() {
+            if let Some(resolved) = self.resolve_default(TypeId::of::<T>()) {
+                return resolved
+                    .downcast::<T>()
+                    .map(|obj| *obj)
+                    .map_err(|_| ResolveError::type_mismatch::<T>());
+            }
+        }
+
+        Err(self.missing_error().with_type(std::any::type_name::<T>()))
+    }
+
+    /// Retrieves a newly constructed `T`, registered with `name`, on this registry only.
+    ///
+    /// Returns `None`, if `T` isn't registered on this registry.
+    ///
+    /// This acquires a shared lock on `self.objects`.
+    async fn resolve_transient_local<T>(
+        &self,
+        name: Option<&'static str>,
+    ) -> Option<Result<T, ResolveError>>
+    where
+        T: Registerable,
+    {
+        let ctor = {
+            let lock = self.objects.read().await;
+            let Some(Object::AsyncTransient(ctor)) = lock
+                .get(&(TypeId::of::<T>(), name))
+                .map(|entry| &entry.object)
+            else {
+                return None;
+            };
+            Ref::clone(ctor)
+        };
+
+        let resolved = ctor.make_transient(self).await;
+        Some(resolved.and_then(|resolved| {
+            resolved
+                .downcast::<T>()
+                .ok()
+                .map(|obj| *obj)
+                .ok_or_else(ResolveError::type_mismatch::<T>)
+        }))
+    }
+
+    /// Constructs a new object of the transient type with the [`TypeId`]
+    /// `id`, on this registry only.
+    ///
+    /// Returns `None`, if no transient with `id` is registered on this
+    /// registry.
+    ///
+    /// This acquires a shared lock on `self.objects`.
+    async fn transient_dyn_local(
+        &self,
+        id: TypeId,
+    ) -> Option<Result<Box<dyn std::any::Any + Send>, ResolveError>> {
+        let ctor = {
+            let lock = self.objects.read().await;
+            let Some(Object::AsyncTransient(ctor)) =
+                lock.get(&(id, None)).map(|entry| &entry.object)
+            else {
+                return None;
+            };
+            Ref::clone(ctor)
+        };
+
+        Some(ctor.make_transient(self).await)
+    }
+
+    /// Retrieves the singleton with the [`TypeId`] `id`, from this registry,
+    /// or any of its parents.
+    async fn singleton_dyn(&self, id: TypeId) -> Result<RefAny, ResolveError> {
+        if let Some(resolved) = self.singleton_dyn_local(id).await {
+            return resolved;
+        }
+
+        for parent in self.ancestors() {
+            if let Some(resolved) = parent.singleton_dyn_local(id).await {
+                return resolved;
+            }
+        }
+
+        Err(self.missing_error())
+    }
+
+    /// Retrieves the singleton with the [`TypeId`] `id`, on this registry
+    /// only.
+    ///
+    /// Returns `None`, if no singleton with `id` is registered on this
+    /// registry.
+    ///
+    /// This acquires a shared lock on `self.objects`.
+    async fn singleton_dyn_local(
+        &self,
+        id: TypeId,
+    ) -> Option<Result<RefAny, ResolveError>> {
+        let singleton = {
+            let lock = self.objects.read().await;
+            let Some(Object::AsyncSingleton(singleton)) =
+                lock.get(&(id, None)).map(|entry| &entry.object)
+            else {
+                return None;
+            };
+            Ref::clone(singleton)
+        };
+
+        Some(singleton.get_singleton(self).await)
+    }
+
+    /// Constructs a new `T` with the factory registered on this registry
+    /// only.
+    ///
+    /// This acquires a shared lock on `self.objects`.
+    async fn make_local<T, Args>(&self, args: Args) -> Result<T, ResolveError>
+    where
+        T: Registerable,
+        Args: Send + 'static,
+    {
+        let factory = {
+            let lock = self.objects.read().await;
+            let Some(Object::AsyncFactory(factory)) = lock
+                .get(&(TypeId::of::<T>(), None))
+                .map(|entry| &entry.object)
+            else {
+                return Err(ResolveError::TypeMissing
+                    .with_type(std::any::type_name::<T>()));
+            };
+            Ref::clone(factory)
+        };
+
+        let factory = factory
+            .downcast_ref::<Box<dyn Factory<Args, T>>>()
+            .ok_or_else(|| {
+                ResolveError::TypeMissing.with_type(std::any::type_name::<T>())
+            })?;
+        Ok(factory(self, args).await)
+    }
+
+    /// Retrieves the singleton `T`, registered with `name`.
+    ///
+    /// If `T` isn't registered on this registry, the parent registries are
+    /// searched, starting with the closest one.
+    pub(crate) async fn resolve_singleton<T>(
+        &self,
+        name: Option<&'static str>,
+    ) -> Result<Ref<T>, ResolveError>
+    where
+        T: RegisterableSingleton,
+    {
+        if let Some(resolved) = self.resolve_singleton_local::<T>(name).await {
+            return resolved;
+        }
+
+        for parent in self.ancestors() {
+            if let Some(resolved) =
+                parent.resolve_singleton_local::<T>(name).await
+            {
+                return resolved;
+            }
+        }
+
+        Err(self.missing_error().with_type(std::any::type_name::<T>()))
+    }
+
+    /// Retrieves the singleton `T`, registered with `name`, on this registry only.
+    ///
+    /// Returns `None`, if `T` isn't registered on this registry.
+    ///
+    /// This acquires a shared lock on `self.objects`.
+    async fn resolve_singleton_local<T>(
+        &self,
+        name: Option<&'static str>,
+    ) -> Option<Result<Ref<T>, ResolveError>>
+    where
+        T: RegisterableSingleton,
+    {
+        let singleton = {
+            let lock = self.objects.read().await;
+            let Some(Object::AsyncSingleton(singleton)) = lock
+                .get(&(TypeId::of::<T>(), name))
+                .map(|entry| &entry.object)
+            else {
+                return None;
+            };
+            Ref::clone(singleton)
+        };
+
+        let resolved = singleton.get_singleton(self).await;
+        Some(resolved.and_then(|resolved| {
+            resolved
+                .downcast::<T>()
+                .ok()
+                .ok_or_else(ResolveError::type_mismatch::<T>)
+        }))
+    }
+
+    /// Retrieves a weak handle to the singleton `T`, registered with `name`,
+    /// without constructing it.
+    ///
+    /// If `T` isn't registered on this registry, the parent registries are
+    /// searched, starting with the closest one.
+    pub(crate) async fn resolve_weak_singleton<T>(
+        &self,
+        name: Option<&'static str>,
+    ) -> Result<WeakAny, ResolveError>
+    where
+        T: RegisterableSingleton,
+    {
+        if let Some(weak) = self.resolve_weak_singleton_local::<T>(name).await {
+            return Ok(weak);
+        }
+
+        for parent in self.ancestors() {
+            if let Some(weak) =
+                parent.resolve_weak_singleton_local::<T>(name).await
+            {
+                return Ok(weak);
+            }
+        }
+
+        Err(self.missing_error().with_type(std::any::type_name::<T>()))
+    }
+
+    /// Retrieves a weak handle to the singleton `T`, registered with `name`
+    /// on this registry only, without constructing it.
+    ///
+    /// This acquires a shared lock on `self.objects`.
+    async fn resolve_weak_singleton_local<T>(
+        &self,
+        name: Option<&'static str>,
+    ) -> Option<WeakAny>
+    where
+        T: RegisterableSingleton,
+    {
+        let lock = self.objects.read().await;
+        match lock
+            .get(&(TypeId::of::<T>(), name))
+            .map(|entry| &entry.object)
+        {
+            Some(Object::AsyncSingleton(singleton)) => {
+                Some(singleton.weak_handle())
+            }
+            _ => None,
+        }
+    }
+
+    /// Constructs a new scoped `T` on this registry only.
+    ///
+    /// Returns `None`, if `T` isn't registered as scoped type on this
+    /// registry.
+    ///
+    /// This acquires a shared lock on `self.objects`.
+    async fn make_scoped_local<T>(&self) -> Option<Result<T, ResolveError>>
+    where
+        T: Registerable,
+    {
+        let scoped = {
+            let lock = self.objects.read().await;
+            let Some(Object::AsyncScoped(scoped)) = lock
+                .get(&(TypeId::of::<T>(), None))
+                .map(|entry| &entry.object)
+            else {
+                return None;
+            };
+            Ref::clone(scoped)
+        };
+
+        let resolved = scoped.make_transient(self).await;
+        Some(resolved.and_then(|resolved| {
+            resolved
+                .downcast::<T>()
+                .ok()
+                .map(|obj| *obj)
+                .ok_or_else(ResolveError::type_mismatch::<T>)
+        }))
+    }
+
+    /// Inserts a new object into the objecs hashtable, replacing the
+    /// previous object registered for `T` (with the name `name`), if any.
+    ///
+    /// This acquires an exclusive lock on `self.objects`.
+    #[inline]
+    async fn replace<T: 'static>(
+        &self,
+        name: Option<&'static str>,
+        value: Object,
+    ) {
+        self.assert_not_frozen::<T>();
+        let mut lock = self.objects.write().await;
+        lock.insert(
+            (TypeId::of::<T>(), name),
+            RegisteredObject {
+                type_name: std::any::type_name::<T>(),
+                object: value,
+            },
+        );
+    }
+
+    /// Appends a new object to the objects registered for `T` with
+    /// [`Registry::register_all`].
+    ///
+    /// This acquires an exclusive lock on `self.multi_objects`.
+    #[inline]
+    async fn push_multi<T: 'static>(&self, value: Object) {
+        self.assert_not_frozen::<T>();
+        let mut lock = self.multi_objects.write().await;
+        lock.entry(TypeId::of::<T>()).or_default().push(value);
+    }
+
+    /// Move all registrations of `other` into this registry. If `overwrite`
+    /// is `false`, nothing is merged if any type, or key of
+    /// [`Registry::register_named_any`], is registered in both.
+    ///
+    /// This acquires an exclusive lock on `self.objects`,
+    /// `self.multi_objects`, `self.named_any`, `self.default_provider`, and
+    /// `self.metrics_sink`.
+    async fn merge_inner(
+        &self,
+        other: Self,
+        overwrite: bool,
+    ) -> Result<(), MergeError> {
+        if self.is_frozen() {
+            return Err(MergeError::Frozen);
+        }
+
+        let Self {
+            objects,
+            multi_objects,
+            validator,
+            named_any,
+            default_provider,
+            #[cfg(feature = "metrics")]
+            metrics_sink,
+            ..
+        } = other;
+
+        {
+            let objects = objects.into_inner();
+            let named_any = named_any.into_inner();
+            let mut lock = self.objects.write().await;
+            let mut named_lock = self.named_any.write();
+            if !overwrite {
+                if let Some(conflict) = objects
+                    .iter()
+                    .find(|(key, _)| lock.contains_key(*key))
+                    .map(|(_, entry)| entry.type_name)
+                {
+                    return Err(MergeError::Conflict {
+                        type_name: conflict,
+                    });
+                }
+                if named_any.keys().any(|key| named_lock.contains_key(key)) {
+                    // The constructors return a `Box<dyn Any>`, see
+                    // `Registry::register_named_any`.
+                    let type_name =
+                        std::any::type_name::<Box<dyn std::any::Any>>();
+                    return Err(MergeError::Conflict { type_name });
+                }
+            }
+            lock.extend(objects);
+            named_lock.extend(named_any);
+        }
+
+        // The fallbacks of `other` are only used, if this registry has none.
+        if let Some(provider) = default_provider.into_inner() {
+            self.default_provider.write().get_or_insert(provider);
+        }
+        #[cfg(feature = "metrics")]
+        if let Some(sink) = metrics_sink.into_inner() {
+            self.metrics_sink.write().get_or_insert(sink);
+        }
+
+        {
+            let mut lock = self.multi_objects.write().await;
+            for (type_id, mut entries) in multi_objects.into_inner() {
+                lock.entry(type_id).or_default().append(&mut entries);
+            }
+        }
+
+        self.validator.merge(validator);
+        Ok(())
+    }
+
+    /// Returns `true` if `id` is a key of the objects hashtable.
+    ///
+    /// This acquires a shared lock on `self.objects`.
+    #[inline]
+    async fn contains_id_local(&self, id: TypeId) -> bool {
+        let lock = self.objects.read().await;
+        lock.contains_key(&(id, None))
+    }
+
+    /// Inserts a new object into the objecs hashtable.
+    ///
+    /// This acquires an exclusive lock on `self.objects`.
+    ///
+    /// # Panics
+    /// If the key already exists (=> the type was previously registered), or
+    /// the registry is frozen.
+    #[inline]
+    async fn insert_or_panic<T: 'static>(
+        &self,
+        name: Option<&'static str>,
+        value: Object,
+    ) {
+        self.assert_not_frozen::<T>();
+        let inserted = self.try_insert::<T>(name, value).await.is_ok();
+        assert!(
+            inserted,
+            "Type '{}' ({:?}) is already registered{}",
+            std::any::type_name::<T>(),
+            TypeId::of::<T>(),
+            name.map(|name| format!(" with name '{name}'"))
+                .unwrap_or_default()
+        );
+    }
+
+    /// Inserts a new object into the objecs hashtable.
+    ///
+    /// This acquires an exclusive lock on `self.objects`.
+    ///
+    /// # Errors
+    /// If the key already exists (=> the type was previously registered), or
+    /// the registry is frozen.
+    #[inline]
+    async fn try_insert<T: 'static>(
+        &self,
+        name: Option<&'static str>,
+        value: Object,
+    ) -> Result<(), RegistryError> {
+        self.ensure_not_frozen::<T>()?;
+        let mut lock = self.objects.write().await;
+        let entry = lock.entry((TypeId::of::<T>(), name));
+        match entry {
+            hashbrown::hash_map::Entry::Occupied(_) => {
+                Err(RegistryError::AlreadyRegistered {
+                    type_name: std::any::type_name::<T>(),
+                })
+            }
+            hashbrown::hash_map::Entry::Vacant(view) => {
+                view.insert(RegisteredObject {
+                    type_name: std::any::type_name::<T>(),
+                    object: value,
+                });
+                Ok(())
+            }
+        }
+    }
+
+    /// Inserts the objects for the type `C`, and its alias `A`, into the
+    /// objects hashtable. Either both are inserted, or none.
+    ///
+    /// This acquires an exclusive lock on `self.objects`.
+    ///
+    /// # Errors
+    /// If any of the keys already exists (=> the type was previously
+    /// registered), or the registry is frozen.
+    async fn try_insert_aliased<C: 'static, A: 'static>(
+        &self,
+        ctor: Object,
+        alias: Object,
+    ) -> Result<(), RegistryError> {
+        self.ensure_not_frozen::<C>()?;
+        let objects = [
+            (TypeId::of::<C>(), std::any::type_name::<C>(), ctor),
+            (TypeId::of::<A>(), std::any::type_name::<A>(), alias),
+        ];
+
+        let mut lock = self.objects.write().await;
+        if let Some((_, type_name, _)) = objects
+            .iter()
+            .find(|(type_id, _, _)| lock.contains_key(&(*type_id, None)))
+        {
+            return Err(RegistryError::AlreadyRegistered {
+                type_name: *type_name,
+            });
+        }
+
+        for (type_id, type_name, object) in objects {
+            lock.insert(
+                (type_id, None),
+                RegisteredObject { type_name, object },
+            );
+        }
+        Ok(())
+    }
+}
+
+/// The registry a type is resolved from, as returned by
+/// [`Registry::resolve_source`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResolveSource {
+    /// The type is registered on the registry itself.
+    Local,
+    /// The type is registered on a parent registry, `depth` levels up. The
+    /// direct parent has a depth of `1`.
+    Parent(usize),
+}
+
+/// A handle to the registration of `T`, as returned by
+/// [`Registry::transient_handle`] and [`Registry::singleton_handle`].
+///
+/// It allows overriding or removing the registration of `T`, without naming
+/// `T` again.
+pub struct Registration<'reg, T> {
+    /// Reference to the registry `T` is registered on.
+    registry: &'reg Registry,
+    /// Marker for `T`.
+    _marker: PhantomData<T>,
+}
+
+impl<'reg, T> Registration<'reg, T>
+where
+    T: 'static,
+{
+    /// Create a new handle to the registration of `T` on `registry`.
+    fn new(registry: &'reg Registry) -> Self {
+        Self {
+            registry,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the [`TypeId`] of the registered type.
+    #[must_use]
+    pub fn type_id(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    /// Returns the registry `T` is registered on.
+    #[must_use]
+    pub fn registry(&self) -> &'reg Registry {
+        self.registry
+    }
+
+    /// Replace the registration of `T` with a transient object, without
+    /// dependencies.
+    ///
+    /// See [`Registry::override_transient`].
+    #[cfg(not(feature = "async"))]
+    pub fn override_transient(&self, ctor: fn() -> T)
+    where
+        T: Registerable,
+    {
+        self.registry.override_transient(ctor);
+    }
+
+    /// Replace the registration of `T` with a singleton object, without
+    /// dependencies.
+    ///
+    /// See [`Registry::override_singleton`].
+    #[cfg(not(feature = "async"))]
+    pub fn override_singleton<F>(&self, ctor: F)
+    where
+        T: RegisterableSingleton,
+        F: SingletonCtor<T>,
+    {
+        self.registry.override_singleton(ctor);
+    }
+
+    /// Remove the registration of `T`.
+    ///
+    /// Returns `true` if `T` was still registered. See [`Registry::remove`].
+    #[cfg(not(feature = "async"))]
+    pub fn remove(self) -> bool {
+        self.registry.remove::<T>()
+    }
+
+    /// Replace the registration of `T` with a transient object, without
+    /// dependencies.
+    ///
+    /// See [`Registry::override_transient`].
+    #[cfg(feature = "async")]
+    pub async fn override_transient(
+        &self,
+        ctor: fn() -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = T> + Send>,
+        >,
+    ) where
+        T: Registerable,
+    {
+        self.registry.override_transient(ctor).await;
+    }
+
+    /// Replace the registration of `T` with a singleton object, without
+    /// dependencies.
+    ///
+    /// See [`Registry::override_singleton`].
+    #[cfg(feature = "async")]
+    pub async fn override_singleton<F>(&self, ctor: F)
+    where
+        T: RegisterableSingleton,
+        F: SingletonCtor<T>,
+    {
+        self.registry.override_singleton(ctor).await;
+    }
+
+    /// Remove the registration of `T`.
+    ///
+    /// Returns `true` if `T` was still registered. See [`Registry::remove`].
+    #[cfg(feature = "async")]
+    pub async fn remove(self) -> bool {
+        self.registry.remove::<T>().await
+    }
+}
+
+impl<T> std::fmt::Debug for Registration<'_, T> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("Registration")
+            .field("type_name", &std::any::type_name::<T>())
+            .finish()
+    }
+}
+
+/// Invalidates `singleton`, see [`Registry::invalidate_singleton`].
+///
+/// If the singleton is shared with threads or tasks that are currently
+/// resolving it, it's replaced with a duplicate, whose object hasn't been
+/// constructed yet. The resolvers finish the construction of the old object.
+fn invalidate_shared(singleton: &mut RefSingletonGetter) -> Option<RefAny> {
+    if let Some(singleton) = Ref::get_mut(singleton) {
+        return singleton.invalidate();
+    }
+
+    let duplicate = singleton.duplicate();
+    if duplicate.get_if_init().is_some() {
+        // Pre-constructed instances are never invalidated.
+        return None;
+    }
+
+    let previous = singleton.get_if_init();
+    *singleton = Ref::from(duplicate);
+    previous
+}
+
+impl std::fmt::Debug for Registry {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         fmt.debug_struct("Registry").finish()
     }
 }
 
+/// A builder for a [`Registry`] that's validated and frozen once built.
+///
+/// All registration functions of [`Registry`] are available on the builder.
+/// [`RegistryBuilder::build`] validates all registered types, and returns a
+/// frozen registry, which rejects any further registrations.
+pub struct RegistryBuilder {
+    /// The registry that's being built.
+    registry: Registry,
+}
+
+impl RegistryBuilder {
+    /// Create a new builder for an empty registry, see [`Registry::empty`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            registry: Registry::empty(),
+        }
+    }
+
+    /// Call `f` with the registry that's being built, to register types
+    /// fluently.
+    ///
+    /// With the `async` feature, the registration functions are `async`, use
+    /// [`RegistryBuilder::with_async`] to register types instead.
+    #[must_use]
+    pub fn with<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&Registry),
+    {
+        f(&self.registry);
+        self
+    }
+
+    /// Call `f` with the registry that's being built, and await the returned
+    /// future, to register types fluently.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// # use ferrunix_core::registry::RegistryBuilder;
+    /// let registry = RegistryBuilder::new()
+    ///     .with_async(|registry| {
+    ///         Box::pin(async move {
+    ///             registry.transient(|| Box::pin(async move { 1_u8 })).await;
+    ///         })
+    ///     })
+    ///     .await
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "async")]
+    #[must_use]
+    pub async fn with_async<F>(self, f: F) -> Self
+    where
+        F: for<'reg> FnOnce(
+            &'reg Registry,
+        ) -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = ()> + Send + 'reg>,
+        >,
+    {
+        f(&self.registry).await;
+        self
+    }
+
+    /// Validate all registered types, and freeze the registry.
+    ///
+    /// Registering a type on the returned registry panics, or returns
+    /// [`RegistryError::Frozen`] for the fallible registration functions.
+    /// Merging another registry into it returns [`MergeError::Frozen`].
+    ///
+    /// # Errors
+    /// Returns all validation errors, see [`Registry::validate_all_full`].
+    pub fn build(self) -> Result<Registry, FullValidationError> {
+        self.registry.validate_all_full()?;
+        self.registry.freeze();
+        Ok(self.registry)
+    }
+}
+
+impl Default for RegistryBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::ops::Deref for RegistryBuilder {
+    type Target = Registry;
+
+    fn deref(&self) -> &Self::Target {
+        &self.registry
+    }
+}
+
+impl std::fmt::Debug for RegistryBuilder {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("RegistryBuilder").finish()
+    }
+}
+
 /// A builder for objects with dependencies. This can be created by using
 /// [`Registry::with_deps`].
 #[allow(clippy::single_char_lifetime_names)]
@@ -538,8 +5397,8 @@ pub struct Builder<'reg, T, Deps> {
 
 impl<
         T,
-        #[cfg(not(feature = "tokio"))] Deps: DepBuilder<T> + 'static,
-        #[cfg(feature = "tokio")] Deps: DepBuilder<T> + Sync + 'static,
+        #[cfg(not(feature = "async"))] Deps: DepBuilder<T> + 'static,
+        #[cfg(feature = "async")] Deps: DepBuilder<T> + Sync + 'static,
     > Builder<'_, T, Deps>
 where
     T: Registerable,
@@ -568,46 +5427,291 @@ where
     ///     });
     /// ```
     ///
-    /// For single dependencies, the destructured tuple needs to end with a
-    /// comma: `(dep,)`.
+    /// For single dependencies, the destructured tuple needs to end with a
+    /// comma: `(dep,)`.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg(not(feature = "async"))]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn transient(&self, ctor: fn(Deps) -> T) {
+        use crate::object_builder::TransientBuilderImplWithDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering transient (with dependencies) ({})",
+            std::any::type_name::<T>()
+        );
+
+        let transient = Object::Transient(Ref::new(
+            TransientBuilderImplWithDeps::new(ctor),
+        ));
+
+        self.registry.insert_or_panic::<T>(None, transient);
+        self.registry.validator.add_transient_deps::<T, Deps>(None);
+    }
+
+    /// Register a new transient object, with dependencies specified in
+    /// `.with_deps`.
+    ///
+    /// This is the non-panicking version of [`Builder::transient`].
+    ///
+    /// # Errors
+    /// Returns [`RegistryError::AlreadyRegistered`] when the type has been
+    /// registered already, or [`RegistryError::Frozen`] if the registry is
+    /// frozen.
+    #[cfg(not(feature = "async"))]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn checked_transient(
+        &self,
+        ctor: fn(Deps) -> T,
+    ) -> Result<(), RegistryError> {
+        use crate::object_builder::TransientBuilderImplWithDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering transient (with dependencies) ({})",
+            std::any::type_name::<T>()
+        );
+
+        let transient = Object::Transient(Ref::new(
+            TransientBuilderImplWithDeps::new(ctor),
+        ));
+
+        self.registry.try_insert::<T>(None, transient)?;
+        self.registry.validator.add_transient_deps::<T, Deps>(None);
+        Ok(())
+    }
+
+    /// Register a new transient object, with dependencies specified in
+    /// `.with_deps`, under the name `name`.
+    ///
+    /// See [`Builder::transient`] and [`Registry::transient_named`].
+    ///
+    /// # Panics
+    /// When the type has been registered already with the same name, or the
+    /// registry is frozen.
+    #[cfg(not(feature = "async"))]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn transient_named(&self, name: &'static str, ctor: fn(Deps) -> T) {
+        use crate::object_builder::TransientBuilderImplWithDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering named transient (with dependencies) ({}, {name})",
+            std::any::type_name::<T>()
+        );
+
+        let transient = Object::Transient(Ref::new(
+            TransientBuilderImplWithDeps::new(ctor),
+        ));
+
+        self.registry.insert_or_panic::<T>(Some(name), transient);
+        self.registry
+            .validator
+            .add_transient_deps::<T, Deps>(Some(name));
+    }
+
+    /// Register one more transient constructor of `T`, with dependencies
+    /// specified in `.with_deps`.
+    ///
+    /// See [`Builder::transient`] and [`Registry::register_all`].
+    #[cfg(not(feature = "async"))]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn register_all(&self, ctor: fn(Deps) -> T) {
+        use crate::object_builder::TransientBuilderImplWithDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering transient into collection (with dependencies) ({})",
+            std::any::type_name::<T>()
+        );
+
+        let transient = Object::Transient(Ref::new(
+            TransientBuilderImplWithDeps::new(ctor),
+        ));
+
+        self.registry.push_multi::<T>(transient);
+        self.registry
+            .validator
+            .add_multi_transient_deps::<T, Deps>();
+    }
+
+    /// Register a new transient object, with dependencies specified in
+    /// `.with_deps`, using a fallible constructor.
+    ///
+    /// See [`Builder::transient`] and [`Registry::try_register_transient`].
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg(not(feature = "async"))]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn try_register_transient(&self, ctor: fn(Deps) -> Result<T, BoxErr>) {
+        use crate::object_builder::TryTransientBuilderWithDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering fallible transient (with dependencies) ({})",
+            std::any::type_name::<T>()
+        );
+
+        let transient =
+            Object::Transient(Ref::new(TryTransientBuilderWithDeps::new(ctor)));
+
+        self.registry.insert_or_panic::<T>(None, transient);
+        self.registry.validator.add_transient_deps::<T, Deps>(None);
+    }
+
+    /// Register a new transient object, with dependencies specified in
+    /// `.with_deps`.
+    ///
+    /// The `ctor` parameter is a constructor function returning the newly
+    /// constructed `T`. The constructor accepts a single argument `Deps` (a
+    /// tuple implementing [`crate::dependency_builder::DepBuilder`]). It's
+    /// best to destructure the tuple to accept each dependency separately.
+    /// This constructor will be called for every `T` that is requested.
+    ///
+    /// The `ctor` must return a boxed `dyn Future`.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg(feature = "async")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn transient(
+        &self,
+        ctor: fn(
+            Deps,
+        ) -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = T> + Send>,
+        >,
+    ) {
+        use crate::object_builder::AsyncTransientBuilderImplWithDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering transient (with dependencies) ({})",
+            std::any::type_name::<T>()
+        );
+
+        let transient = Object::AsyncTransient(Ref::new(
+            AsyncTransientBuilderImplWithDeps::new(ctor),
+        ));
+
+        self.registry.insert_or_panic::<T>(None, transient).await;
+        self.registry.validator.add_transient_deps::<T, Deps>(None);
+    }
+
+    /// Register a new transient object, with dependencies specified in
+    /// `.with_deps`.
+    ///
+    /// This is the non-panicking version of [`Builder::transient`].
+    ///
+    /// # Errors
+    /// Returns [`RegistryError::AlreadyRegistered`] when the type has been
+    /// registered already, or [`RegistryError::Frozen`] if the registry is
+    /// frozen.
+    #[cfg(feature = "async")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn checked_transient(
+        &self,
+        ctor: fn(
+            Deps,
+        ) -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = T> + Send>,
+        >,
+    ) -> Result<(), RegistryError> {
+        use crate::object_builder::AsyncTransientBuilderImplWithDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering transient (with dependencies) ({})",
+            std::any::type_name::<T>()
+        );
+
+        let transient = Object::AsyncTransient(Ref::new(
+            AsyncTransientBuilderImplWithDeps::new(ctor),
+        ));
+
+        self.registry.try_insert::<T>(None, transient).await?;
+        self.registry.validator.add_transient_deps::<T, Deps>(None);
+        Ok(())
+    }
+
+    /// Register a new transient object, with dependencies specified in
+    /// `.with_deps`, under the name `name`.
+    ///
+    /// See [`Builder::transient`] and [`Registry::transient_named`].
     ///
     /// # Panics
-    /// When the type has been registered already.
-    #[cfg(not(feature = "tokio"))]
-    #[cfg_attr(feature = "tracing", tracing::instrument(skip(ctor)))]
-    pub fn transient(&self, ctor: fn(Deps) -> T) {
-        use crate::object_builder::TransientBuilderImplWithDeps;
+    /// When the type has been registered already with the same name, or the
+    /// registry is frozen.
+    #[cfg(feature = "async")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn transient_named(
+        &self,
+        name: &'static str,
+        ctor: fn(
+            Deps,
+        ) -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = T> + Send>,
+        >,
+    ) {
+        use crate::object_builder::AsyncTransientBuilderImplWithDeps;
 
         #[cfg(feature = "tracing")]
         tracing::info!(
-            "registering transient (with dependencies) ({})",
+            "registering named transient (with dependencies) ({}, {name})",
             std::any::type_name::<T>()
         );
 
-        let transient = Object::Transient(Box::new(
-            TransientBuilderImplWithDeps::new(ctor),
+        let transient = Object::AsyncTransient(Ref::new(
+            AsyncTransientBuilderImplWithDeps::new(ctor),
         ));
 
-        self.registry.insert_or_panic::<T>(transient);
-        self.registry.validator.add_transient_deps::<T, Deps>();
+        self.registry
+            .insert_or_panic::<T>(Some(name), transient)
+            .await;
+        self.registry
+            .validator
+            .add_transient_deps::<T, Deps>(Some(name));
     }
 
-    /// Register a new transient object, with dependencies specified in
-    /// `.with_deps`.
+    /// Register one more transient constructor of `T`, with dependencies
+    /// specified in `.with_deps`.
     ///
-    /// The `ctor` parameter is a constructor function returning the newly
-    /// constructed `T`. The constructor accepts a single argument `Deps` (a
-    /// tuple implementing [`crate::dependency_builder::DepBuilder`]). It's
-    /// best to destructure the tuple to accept each dependency separately.
-    /// This constructor will be called for every `T` that is requested.
+    /// See [`Builder::transient`] and [`Registry::register_all`].
     ///
     /// The `ctor` must return a boxed `dyn Future`.
-    ///
-    /// # Panics
-    /// When the type has been registered already.
-    #[cfg(feature = "tokio")]
-    #[cfg_attr(feature = "tracing", tracing::instrument(skip(ctor)))]
-    pub async fn transient(
+    #[cfg(feature = "async")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn register_all(
         &self,
         ctor: fn(
             Deps,
@@ -619,23 +5723,61 @@ where
 
         #[cfg(feature = "tracing")]
         tracing::info!(
-            "registering transient (with dependencies) ({})",
+            "registering transient into collection (with dependencies) ({})",
             std::any::type_name::<T>()
         );
 
-        let transient = Object::AsyncTransient(Box::new(
+        let transient = Object::AsyncTransient(Ref::new(
             AsyncTransientBuilderImplWithDeps::new(ctor),
         ));
 
-        self.registry.insert_or_panic::<T>(transient).await;
-        self.registry.validator.add_transient_deps::<T, Deps>();
+        self.registry.push_multi::<T>(transient).await;
+        self.registry
+            .validator
+            .add_multi_transient_deps::<T, Deps>();
+    }
+
+    /// Register a new transient object, with dependencies specified in
+    /// `.with_deps`, using a fallible constructor.
+    ///
+    /// See [`Builder::transient`] and [`Registry::try_register_transient`].
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg(feature = "async")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn try_register_transient(
+        &self,
+        ctor: fn(
+            Deps,
+        ) -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = Result<T, BoxErr>> + Send>,
+        >,
+    ) {
+        use crate::object_builder::AsyncTryTransientBuilderWithDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering fallible transient (with dependencies) ({})",
+            std::any::type_name::<T>()
+        );
+
+        let transient = Object::AsyncTransient(Ref::new(
+            AsyncTryTransientBuilderWithDeps::new(ctor),
+        ));
+
+        self.registry.insert_or_panic::<T>(None, transient).await;
+        self.registry.validator.add_transient_deps::<T, Deps>(None);
     }
 }
 
 impl<
         T,
-        #[cfg(not(feature = "tokio"))] Deps: DepBuilder<T> + 'static,
-        #[cfg(feature = "tokio")] Deps: DepBuilder<T> + Sync + 'static,
+        #[cfg(not(feature = "async"))] Deps: DepBuilder<T> + 'static,
+        #[cfg(feature = "async")] Deps: DepBuilder<T> + Sync + 'static,
     > Builder<'_, T, Deps>
 where
     T: RegisterableSingleton,
@@ -667,8 +5809,11 @@ where
     ///
     /// For single dependencies, the destructured tuple needs to end with a
     /// comma: `(dep,)`.
-    #[cfg(not(feature = "tokio"))]
-    #[cfg_attr(feature = "tracing", tracing::instrument(skip(ctor)))]
+    #[cfg(not(feature = "async"))]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
     pub fn singleton<F>(&self, ctor: F)
     where
         F: SingletonCtorDeps<T, Deps>,
@@ -682,10 +5827,216 @@ where
         );
 
         let singleton =
-            Object::Singleton(Box::new(SingletonGetterWithDeps::new(ctor)));
+            Object::Singleton(Ref::new(SingletonGetterWithDeps::new(ctor)));
+
+        self.registry.insert_or_panic::<T>(None, singleton);
+        self.registry.validator.add_singleton_deps::<T, Deps>(None);
+    }
+
+    /// Register a new singleton object, with dependencies specified in
+    /// `.with_deps`.
+    ///
+    /// This is the non-panicking version of [`Builder::singleton`].
+    ///
+    /// # Errors
+    /// Returns [`RegistryError::AlreadyRegistered`] when the type has been
+    /// registered already, or [`RegistryError::Frozen`] if the registry is
+    /// frozen.
+    #[cfg(not(feature = "async"))]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn checked_singleton<F>(&self, ctor: F) -> Result<(), RegistryError>
+    where
+        F: SingletonCtorDeps<T, Deps>,
+    {
+        use crate::object_builder::SingletonGetterWithDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering singleton (with dependencies) ({})",
+            std::any::type_name::<T>()
+        );
+
+        let singleton =
+            Object::Singleton(Ref::new(SingletonGetterWithDeps::new(ctor)));
+
+        self.registry.try_insert::<T>(None, singleton)?;
+        self.registry.validator.add_singleton_deps::<T, Deps>(None);
+        Ok(())
+    }
+
+    /// Register a new singleton object, with dependencies specified in
+    /// `.with_deps`, under the name `name`.
+    ///
+    /// See [`Builder::singleton`] and [`Registry::singleton_named`].
+    ///
+    /// # Panics
+    /// When the type has been registered already with the same name, or the
+    /// registry is frozen.
+    #[cfg(not(feature = "async"))]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn singleton_named<F>(&self, name: &'static str, ctor: F)
+    where
+        F: SingletonCtorDeps<T, Deps>,
+    {
+        use crate::object_builder::SingletonGetterWithDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering named singleton (with dependencies) ({}, {name})",
+            std::any::type_name::<T>()
+        );
+
+        let singleton =
+            Object::Singleton(Ref::new(SingletonGetterWithDeps::new(ctor)));
+
+        self.registry.insert_or_panic::<T>(Some(name), singleton);
+        self.registry
+            .validator
+            .add_singleton_deps::<T, Deps>(Some(name));
+    }
+
+    /// Register a new scoped object, with dependencies specified in
+    /// `.with_deps`.
+    ///
+    /// See [`Builder::transient`] and [`Registry::register_scoped`].
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg(not(feature = "async"))]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn register_scoped(&self, ctor: fn(Deps) -> T) {
+        use crate::object_builder::TransientBuilderImplWithDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering scoped (with dependencies) ({})",
+            std::any::type_name::<T>()
+        );
+
+        let scoped =
+            Object::Scoped(Ref::new(TransientBuilderImplWithDeps::new(ctor)));
+
+        self.registry.insert_or_panic::<T>(None, scoped);
+        self.registry.validator.add_scoped_deps::<T, Deps>();
+    }
+
+    /// Register a new singleton object, with dependencies specified in
+    /// `.with_deps`, using a fallible constructor.
+    ///
+    /// See [`Builder::singleton`] and [`Registry::try_register_singleton`].
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg(not(feature = "async"))]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn try_register_singleton<F>(&self, ctor: F)
+    where
+        F: SingletonCtorDeps<Result<T, BoxErr>, Deps>,
+    {
+        use crate::object_builder::TrySingletonGetter;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering fallible singleton (with dependencies) ({})",
+            std::any::type_name::<T>()
+        );
+
+        let singleton =
+            Object::Singleton(Ref::new(TrySingletonGetter::new(ctor)));
+
+        self.registry.insert_or_panic::<T>(None, singleton);
+        self.registry.validator.add_singleton_deps::<T, Deps>(None);
+    }
+
+    /// Register a new singleton object, with dependencies specified in
+    /// `.with_deps`, constructed eagerly.
+    ///
+    /// Unlike [`Builder::singleton`], the dependencies are resolved and `ctor`
+    /// is called immediately, and the constructed `T` is stored as a
+    /// pre-built instance (see [`Registry::register_instance`]). This is
+    /// useful for values which must exist before the rest of the registration
+    /// proceeds.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use ferrunix_core::{Registry, Singleton};
+    /// # let registry = Registry::empty();
+    /// struct Config {
+    ///     port: u16,
+    /// }
+    ///
+    /// registry.register_instance(Config { port: 8080 });
+    /// registry
+    ///     .with_deps::<_, (Singleton<Config>,)>()
+    ///     .register_singleton_eager(|(config,)| u32::from(config.port))
+    ///     .unwrap();
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if any of the dependencies couldn't be resolved. `T`
+    /// isn't registered in that case.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg(not(feature = "async"))]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn register_singleton_eager<F>(
+        &self,
+        ctor: F,
+    ) -> Result<(), ResolveError>
+    where
+        F: FnOnce(Deps) -> T,
+    {
+        use crate::object_builder::SingletonGetterInstance;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering eager singleton (with dependencies) ({})",
+            std::any::type_name::<T>()
+        );
+
+        self.registry.assert_not_frozen::<T>();
+        assert!(
+            !self.registry.contains_local::<T>(),
+            "Type '{}' ({:?}) is already registered",
+            std::any::type_name::<T>(),
+            TypeId::of::<T>(),
+        );
+
+        // The dependencies are validated against `T`, which requires `T` to
+        // be known to the validator before it's resolved.
+        self.registry.validator.add_singleton_deps::<T, Deps>(None);
+        let deps = match Deps::resolve(
+            self.registry,
+            crate::dependency_builder::private::SealToken,
+        ) {
+            Ok(deps) => deps,
+            Err(err) => {
+                self.registry.validator.remove::<T>(None);
+                return Err(err);
+            }
+        };
 
-        self.registry.insert_or_panic::<T>(singleton);
-        self.registry.validator.add_singleton_deps::<T, Deps>();
+        let singleton = Object::Singleton(Ref::new(
+            SingletonGetterInstance::new(ctor(deps)),
+        ));
+        self.registry.insert_or_panic::<T>(None, singleton);
+        Ok(())
     }
 
     /// Register a new singleton object, with dependencies specified in
@@ -699,8 +6050,11 @@ where
     /// instance of `T` is requested.
     ///
     /// The `ctor` must return a boxed `dyn Future`.
-    #[cfg(feature = "tokio")]
-    #[cfg_attr(feature = "tracing", tracing::instrument(skip(ctor)))]
+    #[cfg(feature = "async")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
     pub async fn singleton<F>(&self, ctor: F)
     where
         F: SingletonCtorDeps<T, Deps>,
@@ -714,10 +6068,224 @@ where
         );
 
         let singleton =
-            Object::AsyncSingleton(Box::new(AsyncSingletonWithDeps::new(ctor)));
+            Object::AsyncSingleton(Ref::new(AsyncSingletonWithDeps::new(ctor)));
+
+        self.registry.insert_or_panic::<T>(None, singleton).await;
+        self.registry.validator.add_singleton_deps::<T, Deps>(None);
+    }
+
+    /// Register a new singleton object, with dependencies specified in
+    /// `.with_deps`.
+    ///
+    /// This is the non-panicking version of [`Builder::singleton`].
+    ///
+    /// # Errors
+    /// Returns [`RegistryError::AlreadyRegistered`] when the type has been
+    /// registered already, or [`RegistryError::Frozen`] if the registry is
+    /// frozen.
+    #[cfg(feature = "async")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn checked_singleton<F>(
+        &self,
+        ctor: F,
+    ) -> Result<(), RegistryError>
+    where
+        F: SingletonCtorDeps<T, Deps>,
+    {
+        use crate::object_builder::AsyncSingletonWithDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering singleton (with dependencies) ({})",
+            std::any::type_name::<T>()
+        );
+
+        let singleton =
+            Object::AsyncSingleton(Ref::new(AsyncSingletonWithDeps::new(ctor)));
+
+        self.registry.try_insert::<T>(None, singleton).await?;
+        self.registry.validator.add_singleton_deps::<T, Deps>(None);
+        Ok(())
+    }
+
+    /// Register a new singleton object, with dependencies specified in
+    /// `.with_deps`, under the name `name`.
+    ///
+    /// See [`Builder::singleton`] and [`Registry::singleton_named`].
+    ///
+    /// # Panics
+    /// When the type has been registered already with the same name, or the
+    /// registry is frozen.
+    #[cfg(feature = "async")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn singleton_named<F>(&self, name: &'static str, ctor: F)
+    where
+        F: SingletonCtorDeps<T, Deps>,
+    {
+        use crate::object_builder::AsyncSingletonWithDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering named singleton (with dependencies) ({}, {name})",
+            std::any::type_name::<T>()
+        );
+
+        let singleton =
+            Object::AsyncSingleton(Ref::new(AsyncSingletonWithDeps::new(ctor)));
+
+        self.registry
+            .insert_or_panic::<T>(Some(name), singleton)
+            .await;
+        self.registry
+            .validator
+            .add_singleton_deps::<T, Deps>(Some(name));
+    }
+
+    /// Register a new scoped object, with dependencies specified in
+    /// `.with_deps`.
+    ///
+    /// See [`Builder::transient`] and [`Registry::register_scoped`].
+    ///
+    /// The `ctor` must return a boxed `dyn Future`.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg(feature = "async")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn register_scoped(
+        &self,
+        ctor: fn(
+            Deps,
+        ) -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = T> + Send>,
+        >,
+    ) {
+        use crate::object_builder::AsyncTransientBuilderImplWithDeps;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering scoped (with dependencies) ({})",
+            std::any::type_name::<T>()
+        );
+
+        let scoped = Object::AsyncScoped(Ref::new(
+            AsyncTransientBuilderImplWithDeps::new(ctor),
+        ));
+
+        self.registry.insert_or_panic::<T>(None, scoped).await;
+        self.registry.validator.add_scoped_deps::<T, Deps>();
+    }
+
+    /// Register a new singleton object, with dependencies specified in
+    /// `.with_deps`, using a fallible constructor.
+    ///
+    /// See [`Builder::singleton`] and [`Registry::try_register_singleton`].
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg(feature = "async")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn try_register_singleton<F>(&self, ctor: F)
+    where
+        F: SingletonCtorDeps<Result<T, BoxErr>, Deps>,
+    {
+        use crate::object_builder::AsyncTrySingleton;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering fallible singleton (with dependencies) ({})",
+            std::any::type_name::<T>()
+        );
+
+        let singleton =
+            Object::AsyncSingleton(Ref::new(AsyncTrySingleton::new(ctor)));
+
+        self.registry.insert_or_panic::<T>(None, singleton).await;
+        self.registry.validator.add_singleton_deps::<T, Deps>(None);
+    }
+
+    /// Register a new singleton object, with dependencies specified in
+    /// `.with_deps`, constructed eagerly.
+    ///
+    /// Unlike [`Builder::singleton`], the dependencies are resolved and `ctor`
+    /// is awaited immediately, and the constructed `T` is stored as a
+    /// pre-built instance (see [`Registry::register_instance`]). This is
+    /// useful for values which must exist before the rest of the registration
+    /// proceeds.
+    ///
+    /// The `ctor` must return a boxed `dyn Future`.
+    ///
+    /// # Errors
+    /// Returns an error if any of the dependencies couldn't be resolved. `T`
+    /// isn't registered in that case.
+    ///
+    /// # Panics
+    /// When the type has been registered already, or the registry is frozen.
+    #[cfg(feature = "async")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(ctor), fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn register_singleton_eager<F>(
+        &self,
+        ctor: F,
+    ) -> Result<(), ResolveError>
+    where
+        F: FnOnce(
+            Deps,
+        ) -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = T> + Send>,
+        >,
+    {
+        use crate::object_builder::AsyncSingletonInstance;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            "registering eager singleton (with dependencies) ({})",
+            std::any::type_name::<T>()
+        );
+
+        self.registry.assert_not_frozen::<T>();
+        assert!(
+            !self.registry.contains_local::<T>().await,
+            "Type '{}' ({:?}) is already registered",
+            std::any::type_name::<T>(),
+            TypeId::of::<T>(),
+        );
 
-        self.registry.insert_or_panic::<T>(singleton).await;
-        self.registry.validator.add_singleton_deps::<T, Deps>();
+        // The dependencies are validated against `T`, which requires `T` to
+        // be known to the validator before it's resolved.
+        self.registry.validator.add_singleton_deps::<T, Deps>(None);
+        let deps = match Deps::resolve(
+            self.registry,
+            crate::dependency_builder::private::SealToken,
+        )
+        .await
+        {
+            Ok(deps) => deps,
+            Err(err) => {
+                self.registry.validator.remove::<T>(None);
+                return Err(err);
+            }
+        };
+
+        let singleton = Object::AsyncSingleton(Ref::new(
+            AsyncSingletonInstance::new(ctor(deps).await),
+        ));
+        self.registry.insert_or_panic::<T>(None, singleton).await;
+        Ok(())
     }
 }
 
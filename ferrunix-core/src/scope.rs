@@ -0,0 +1,125 @@
+//! Scopes for types that are constructed once per scope.
+//!
+//! A scope is entered with [`Registry::enter_scope`], and left when the
+//! returned [`ScopeGuard`] is dropped.
+
+use std::any::TypeId;
+
+use crate::error::ResolveError;
+use crate::types::{
+    HashMap, NonAsyncRwLock, Ref, RefAny, RegisterableSingleton, SingletonCell,
+};
+use crate::Registry;
+
+/// Guard of an active scope, returned by [`Registry::enter_scope`].
+///
+/// Types registered with [`Registry::register_scoped`] are constructed once
+/// per scope, and shared within it. They're resolved through the guard, see
+/// [`ScopeGuard::get`]. All objects constructed within the scope are dropped
+/// when the guard is dropped.
+#[must_use = "scoped types are resolved through the guard"]
+pub struct ScopeGuard<'reg> {
+    /// The registry the scope was entered on.
+    registry: &'reg Registry,
+    /// The objects constructed within this scope, keyed by their type.
+    cache: NonAsyncRwLock<HashMap<TypeId, Ref<SingletonCell>>>,
+}
+
+impl<'reg> ScopeGuard<'reg> {
+    /// Create a new guard for a scope on `registry`.
+    pub(crate) fn new(registry: &'reg Registry) -> Self {
+        Self {
+            registry,
+            cache: NonAsyncRwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the cell caching `T` in this scope.
+    ///
+    /// This acquires an exclusive lock on `self.cache`.
+    fn cell<T: 'static>(&self) -> Ref<SingletonCell> {
+        let mut cache = self.cache.write();
+        let cell = cache
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Ref::new(SingletonCell::new()));
+        Ref::clone(cell)
+    }
+}
+
+#[cfg(not(feature = "async"))]
+impl ScopeGuard<'_> {
+    /// Retrieves the scoped `T` of this scope, constructing it if it hasn't
+    /// been constructed within the scope yet.
+    ///
+    /// If `T` isn't registered on the registry the scope was entered on, the
+    /// parent registries are searched, starting with the closest one.
+    ///
+    /// # Errors
+    /// Returns [`ResolveError::TypeMissing`] if `T` wasn't registered with
+    /// [`Registry::register_scoped`], or the error of the dependency that
+    /// failed to construct `T`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub fn get<T>(&self) -> Result<Ref<T>, ResolveError>
+    where
+        T: RegisterableSingleton,
+    {
+        let cell = self.cell::<T>();
+        let resolved = cell.get_or_try_init(|| {
+            let obj = self.registry.make_scoped::<T>()?;
+            let rc: RefAny = Ref::<T>::new(obj);
+            Ok::<_, ResolveError>(rc)
+        })?;
+
+        Ref::clone(resolved)
+            .downcast::<T>()
+            .ok()
+            .ok_or_else(ResolveError::type_mismatch::<T>)
+    }
+}
+
+#[cfg(feature = "async")]
+impl ScopeGuard<'_> {
+    /// Retrieves the scoped `T` of this scope, constructing it if it hasn't
+    /// been constructed within the scope yet.
+    ///
+    /// If `T` isn't registered on the registry the scope was entered on, the
+    /// parent registries are searched, starting with the closest one.
+    ///
+    /// # Errors
+    /// Returns [`ResolveError::TypeMissing`] if `T` wasn't registered with
+    /// [`Registry::register_scoped`], or the error of the dependency that
+    /// failed to construct `T`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(fields(type_name = std::any::type_name::<T>()))
+    )]
+    pub async fn get<T>(&self) -> Result<Ref<T>, ResolveError>
+    where
+        T: RegisterableSingleton,
+    {
+        let cell = self.cell::<T>();
+        let resolved = cell
+            .get_or_try_init(|| async {
+                let obj = self.registry.make_scoped::<T>().await?;
+                let rc: RefAny = Ref::<T>::new(obj);
+                Ok::<_, ResolveError>(rc)
+            })
+            .await?;
+
+        Ref::clone(resolved)
+            .downcast::<T>()
+            .ok()
+            .ok_or_else(ResolveError::type_mismatch::<T>)
+    }
+}
+
+impl std::fmt::Debug for ScopeGuard<'_> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("ScopeGuard")
+            .field("len", &self.cache.read().len())
+            .finish()
+    }
+}
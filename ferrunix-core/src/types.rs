@@ -6,26 +6,64 @@
 )]
 
 mod private {
-    /// This is used for sealing the traits [`SingletonCtor`] and [`SingletonCtorDeps`].
+    /// This is used for sealing the traits [`SingletonCtor`],
+    /// [`SingletonCtorDeps`], and [`Factory`].
     #[derive(Debug, Clone, Copy)]
     pub struct SealToken;
 }
 
 use std::any::TypeId;
 
-use crate::cycle_detection::{DependencyValidator, VisitorContext};
+use crate::cycle_detection::{DependencyValidator, Lifetime, VisitorContext};
+use crate::dependency_builder::DepInfo;
+
+/// Key of a registered type in the [`Registry`](crate::Registry): the
+/// [`TypeId`] of the type, and the name for named registrations.
+pub(crate) type RegistrationKey = (TypeId, Option<&'static str>);
+
+/// Hashmap of the registered objects in the [`Registry`](crate::Registry).
+///
+/// With the `fxhash` feature, the keys are hashed with the cheaper
+/// `FxHasher`, a [`TypeId`] is already a good hash.
+#[cfg(feature = "fxhash")]
+pub(crate) type ObjectMap<K, V> = hashbrown::HashMap<
+    K,
+    V,
+    std::hash::BuildHasherDefault<rustc_hash::FxHasher>,
+>;
+/// Hashmap of the registered objects in the [`Registry`](crate::Registry).
+#[cfg(not(feature = "fxhash"))]
+pub(crate) type ObjectMap<K, V> = hashbrown::HashMap<K, V>;
+
+/// Fallback for transients that aren't registered, see
+/// [`Registry::set_default_provider`](crate::Registry::set_default_provider).
+pub(crate) type DefaultProvider =
+    Ref<dyn Fn(TypeId, &crate::Registry) -> Option<BoxedAny> + Send + Sync>;
 
 // Alias types used in [`DependencyValidator`].
+pub(crate) type VisitFn = fn(
+    &DependencyValidator,
+    &HashMap<RegistrationKey, Visitor>,
+    &mut VisitorContext,
+    RegistrationKey,
+    Lifetime,
+    &[DepInfo],
+) -> petgraph::graph::NodeIndex;
+
+/// Callback to visit a registered type, the dependencies of the type, and
+/// the lifetime the type is registered with.
+///
+/// The dependencies are computed once, when the type is registered, and
+/// passed into the callback on every visit, along with the lifetime.
+#[derive(Clone)]
 pub(crate) struct Visitor(
-    pub(crate)  fn(
-        &DependencyValidator,
-        &HashMap<TypeId, Visitor>,
-        &mut VisitorContext,
-    ) -> petgraph::graph::NodeIndex,
+    pub(crate) VisitFn,
+    pub(crate) Box<[DepInfo]>,
+    pub(crate) Lifetime,
 );
 
 /// Types that are enabled when the `multithread` feature is set.
-#[cfg(all(feature = "multithread", not(feature = "tokio")))]
+#[cfg(all(feature = "multithread", not(feature = "async")))]
 mod sync {
     use std::any::Any;
 
@@ -47,32 +85,39 @@ mod sync {
 
     // Hashmap types.
     pub(crate) type HashMap<K, V> = hashbrown::HashMap<K, V>;
+    pub(crate) type HashSet<T> = hashbrown::HashSet<T>;
 
     // Alias types used in [`Registry`].
     pub(crate) type BoxedAny = Box<dyn Any>;
     pub(crate) type RefAny = Ref<dyn Any + Send + Sync + 'static>;
+    pub(crate) type WeakAny = WeakRef<dyn Any + Send + Sync + 'static>;
     pub(crate) type SingletonCell = OnceCell<RefAny>;
     pub(crate) type BoxedTransientBuilder =
         Box<dyn TransientBuilder + Send + Sync + 'static>;
     pub(crate) type BoxedSingletonGetter =
         Box<dyn SingletonGetter + Send + Sync + 'static>;
+    pub(crate) type RefTransientBuilder =
+        Ref<dyn TransientBuilder + Send + Sync + 'static>;
+    pub(crate) type RefSingletonGetter =
+        Ref<dyn SingletonGetter + Send + Sync + 'static>;
+    pub(crate) type RefFactory = Ref<dyn Any + Send + Sync>;
 
     /// A generic constructor for singletons.
     ///
     /// This is a marker trait to identify all valid constructors usable by singletons.
     /// It's not implementable by other crates.
     ///
-    /// A blanket implementation for `FnOnce() -> T` is provided.
-    pub trait SingletonCtor<T>: FnOnce() -> T + Send + Sync + 'static {
+    /// A blanket implementation for `Fn() -> T` is provided.
+    pub trait SingletonCtor<T>: Fn() -> T + Send + Sync + 'static {
         /// Calls the construcor.
-        fn call(self, _: super::private::SealToken) -> T;
+        fn call(&self, _: super::private::SealToken) -> T;
     }
 
     impl<T, F> SingletonCtor<T> for F
     where
-        F: FnOnce() -> T + Send + Sync + 'static,
+        F: Fn() -> T + Send + Sync + 'static,
     {
-        fn call(self, _: super::private::SealToken) -> T {
+        fn call(&self, _: super::private::SealToken) -> T {
             (self)()
         }
     }
@@ -82,25 +127,61 @@ mod sync {
     /// This is a marker trait to identify all valid constructors usable by singletons.
     /// It's not implementable by other crates.
     ///
-    /// A blanket implementation for `FnOnce(Deps) -> T` is provided.
+    /// A blanket implementation for `Fn(Deps) -> T` is provided.
     pub trait SingletonCtorDeps<T, Deps>:
-        FnOnce(Deps) -> T + Send + Sync + 'static
+        Fn(Deps) -> T + Send + Sync + 'static
     {
         /// Calls the construcor.
-        fn call(self, deps: Deps, _: super::private::SealToken) -> T;
+        fn call(&self, deps: Deps, _: super::private::SealToken) -> T;
     }
 
-    #[cfg(not(feature = "tokio"))]
+    #[cfg(not(feature = "async"))]
     impl<T, F, Deps> SingletonCtorDeps<T, Deps> for F
     where
-        F: FnOnce(Deps) -> T + Send + Sync + 'static,
+        F: Fn(Deps) -> T + Send + Sync + 'static,
         Deps: crate::dependency_builder::DepBuilder<T> + 'static,
     {
-        fn call(self, deps: Deps, _: super::private::SealToken) -> T {
+        fn call(&self, deps: Deps, _: super::private::SealToken) -> T {
             (self)(deps)
         }
     }
 
+    /// Constructor of transient objects without dependencies, as accepted by
+    /// `Registry::transient`.
+    pub type TransientCtor<T> = fn() -> T;
+
+    /// A factory constructing `T` from runtime arguments `Args`.
+    ///
+    /// This is a marker trait to identify all valid factories usable by
+    /// `Registry::register_factory`. It's not implementable by other crates.
+    ///
+    /// A blanket implementation for `Fn(&Registry, Args) -> T` is provided.
+    pub trait Factory<Args, T>:
+        Fn(&crate::Registry, Args) -> T + Send + Sync + 'static
+    {
+        /// Calls the factory.
+        fn call(
+            &self,
+            registry: &crate::Registry,
+            args: Args,
+            _: super::private::SealToken,
+        ) -> T;
+    }
+
+    impl<Args, T, F> Factory<Args, T> for F
+    where
+        F: Fn(&crate::Registry, Args) -> T + Send + Sync + 'static,
+    {
+        fn call(
+            &self,
+            registry: &crate::Registry,
+            args: Args,
+            _: super::private::SealToken,
+        ) -> T {
+            (self)(registry, args)
+        }
+    }
+
     /// A generic reference type that's used as the default type for types with
     /// the singleton lifetime.
     ///
@@ -112,6 +193,9 @@ mod sync {
     /// simplifies enabling `multithread` when required.
     pub type Ref<T> = std::sync::Arc<T>;
 
+    /// The weak counterpart to [`Ref`], which is [`std::sync::Weak`].
+    pub type WeakRef<T> = std::sync::Weak<T>;
+
     /// A marker trait for all types that can be registered with `Registry::transient`.
     ///
     /// It's automatically implemented for all types that are valid. Generally,
@@ -128,10 +212,40 @@ mod sync {
     pub trait RegisterableSingleton: Send + Sync + 'static {}
 
     impl<T> RegisterableSingleton for T where T: Send + Sync + 'static {}
+
+    /// A marker trait for all constructors that can be registered with
+    /// `Registry::register_named_any`.
+    ///
+    /// It's automatically implemented for all `Fn() -> Box<dyn Any>`, that
+    /// are also `Send` and `Sync`.
+    pub trait AnyCtor:
+        Fn() -> Box<dyn std::any::Any> + Send + Sync + 'static
+    {
+    }
+
+    impl<F> AnyCtor for F where
+        F: Fn() -> Box<dyn std::any::Any> + Send + Sync + 'static
+    {
+    }
+
+    /// A marker trait for all stateful constructors that can be registered
+    /// with `Registry::register_transient_stateful`.
+    ///
+    /// It's automatically implemented for all `FnMut() -> T`, that are also
+    /// `Send` and `Sync`.
+    pub trait StatefulTransientCtor<T>:
+        FnMut() -> T + Send + Sync + 'static
+    {
+    }
+
+    impl<T, F> StatefulTransientCtor<T> for F where
+        F: FnMut() -> T + Send + Sync + 'static
+    {
+    }
 }
 
 /// Types that are enabled when the `multithread` feature is **NOT** set.
-#[cfg(all(not(feature = "multithread"), not(feature = "tokio")))]
+#[cfg(all(not(feature = "multithread"), not(feature = "async")))]
 mod unsync {
     use std::any::Any;
 
@@ -169,33 +283,48 @@ mod unsync {
         pub(crate) fn write(&self) -> RwLockWriteGuard<'_, T> {
             self.inner.borrow_mut()
         }
+
+        pub(crate) fn into_inner(self) -> T {
+            self.inner.into_inner()
+        }
     }
 
     // Hashmap types.
     pub(crate) type HashMap<K, V> = hashbrown::HashMap<K, V>;
+    pub(crate) type HashSet<T> = hashbrown::HashSet<T>;
 
     // Alias types used in [`Registry`].
     pub(crate) type BoxedAny = Box<dyn Any>;
+    #[cfg(not(feature = "ref-arc"))]
     pub(crate) type RefAny = Ref<dyn Any>;
+    #[cfg(not(feature = "ref-arc"))]
+    pub(crate) type WeakAny = WeakRef<dyn Any>;
+    #[cfg(feature = "ref-arc")]
+    pub(crate) type RefAny = Ref<dyn Any + Send + Sync + 'static>;
+    #[cfg(feature = "ref-arc")]
+    pub(crate) type WeakAny = WeakRef<dyn Any + Send + Sync + 'static>;
     pub(crate) type SingletonCell = OnceCell<RefAny>;
     pub(crate) type BoxedTransientBuilder = Box<dyn TransientBuilder>;
     pub(crate) type BoxedSingletonGetter = Box<dyn SingletonGetter>;
+    pub(crate) type RefTransientBuilder = Ref<dyn TransientBuilder>;
+    pub(crate) type RefSingletonGetter = Ref<dyn SingletonGetter>;
+    pub(crate) type RefFactory = Ref<dyn Any>;
 
     /// A generic constructor for singletons.
     ///
     /// This is a marker trait to identify all valid constructors usable by singletons.
     /// It's not implementable by other crates.
     ///
-    /// A blanket implementation for `FnOnce() -> T` is provided.
-    pub trait SingletonCtor<T>: FnOnce() -> T + 'static {
+    /// A blanket implementation for `Fn() -> T` is provided.
+    pub trait SingletonCtor<T>: Fn() -> T + 'static {
         /// Calls the construcor.
-        fn call(self, _: super::private::SealToken) -> T;
+        fn call(&self, _: super::private::SealToken) -> T;
     }
     impl<T, F> SingletonCtor<T> for F
     where
-        F: FnOnce() -> T + 'static,
+        F: Fn() -> T + 'static,
     {
-        fn call(self, _: super::private::SealToken) -> T {
+        fn call(&self, _: super::private::SealToken) -> T {
             (self)()
         }
     }
@@ -205,33 +334,87 @@ mod unsync {
     /// This is a marker trait to identify all valid constructors usable by singletons.
     /// It's not implementable by other crates.
     ///
-    /// A blanket implementation for `FnOnce(Deps) -> T` is provided.
-    pub trait SingletonCtorDeps<T, Deps>: FnOnce(Deps) -> T + 'static {
+    /// A blanket implementation for `Fn(Deps) -> T` is provided.
+    pub trait SingletonCtorDeps<T, Deps>: Fn(Deps) -> T + 'static {
         /// Calls the construcor.
-        fn call(self, deps: Deps, _: super::private::SealToken) -> T;
+        fn call(&self, deps: Deps, _: super::private::SealToken) -> T;
     }
 
     impl<T, F, Deps> SingletonCtorDeps<T, Deps> for F
     where
-        F: FnOnce(Deps) -> T + 'static,
+        F: Fn(Deps) -> T + 'static,
         Deps: crate::dependency_builder::DepBuilder<T> + 'static,
     {
-        fn call(self, deps: Deps, _: super::private::SealToken) -> T {
+        fn call(&self, deps: Deps, _: super::private::SealToken) -> T {
             (self)(deps)
         }
     }
 
+    /// Constructor of transient objects without dependencies, as accepted by
+    /// `Registry::transient`.
+    pub type TransientCtor<T> = fn() -> T;
+
+    /// A factory constructing `T` from runtime arguments `Args`.
+    ///
+    /// This is a marker trait to identify all valid factories usable by
+    /// `Registry::register_factory`. It's not implementable by other crates.
+    ///
+    /// A blanket implementation for `Fn(&Registry, Args) -> T` is provided.
+    pub trait Factory<Args, T>:
+        Fn(&crate::Registry, Args) -> T + 'static
+    {
+        /// Calls the factory.
+        fn call(
+            &self,
+            registry: &crate::Registry,
+            args: Args,
+            _: super::private::SealToken,
+        ) -> T;
+    }
+
+    impl<Args, T, F> Factory<Args, T> for F
+    where
+        F: Fn(&crate::Registry, Args) -> T + 'static,
+    {
+        fn call(
+            &self,
+            registry: &crate::Registry,
+            args: Args,
+            _: super::private::SealToken,
+        ) -> T {
+            (self)(registry, args)
+        }
+    }
+
     /// A generic reference type that's used as the default type for types with
     /// the singleton lifetime.
     ///
     /// When the `multithread` feature is **NOT** set, this defaults to
-    /// [`std::rc::Rc`]. When the `multithread` feature is set, this
-    /// defaults to [`std::sync::Arc`].
+    /// [`std::rc::Rc`]. When the `multithread` feature, or the `ref-arc`
+    /// feature is set, this defaults to [`std::sync::Arc`].
     ///
     /// It's advised to use [`Ref`] instead of the concrete type because it
     /// simplifies enabling `multithread` when required.
+    #[cfg(not(feature = "ref-arc"))]
     pub type Ref<T> = std::rc::Rc<T>;
 
+    /// The weak counterpart to [`Ref`], which is [`std::rc::Weak`].
+    #[cfg(not(feature = "ref-arc"))]
+    pub type WeakRef<T> = std::rc::Weak<T>;
+
+    /// A generic reference type that's used as the default type for types with
+    /// the singleton lifetime.
+    ///
+    /// With the `ref-arc` feature, this is [`std::sync::Arc`], so singletons
+    /// can be sent to other threads, while the registry itself stays
+    /// single-threaded.
+    #[cfg(feature = "ref-arc")]
+    pub type Ref<T> = std::sync::Arc<T>;
+
+    /// The weak counterpart to [`Ref`], which is [`std::sync::Weak`].
+    #[cfg(feature = "ref-arc")]
+    pub type WeakRef<T> = std::sync::Weak<T>;
+
     /// A marker trait for all types that can be registered with `Registry::transient`.
     ///
     /// It's automatically implemented for all types that are valid. Generally,
@@ -244,60 +427,103 @@ mod unsync {
     ///
     /// It's automatically implemented for all types that are valid. Generally,
     /// those are all types with a `'static` lifetime.
+    #[cfg(not(feature = "ref-arc"))]
     pub trait RegisterableSingleton: 'static {}
 
+    #[cfg(not(feature = "ref-arc"))]
     impl<T> RegisterableSingleton for T where T: 'static {}
+
+    /// A marker trait for all types that can be registered with `Registry::singleton`.
+    ///
+    /// It's automatically implemented for all types that are valid. With the
+    /// `ref-arc` feature, those are all types with a `'static` lifetime, that
+    /// are also `Send` and `Sync`, so a [`Ref`] to them can be sent to other
+    /// threads.
+    #[cfg(feature = "ref-arc")]
+    pub trait RegisterableSingleton: Send + Sync + 'static {}
+
+    #[cfg(feature = "ref-arc")]
+    impl<T> RegisterableSingleton for T where T: Send + Sync + 'static {}
+
+    /// A marker trait for all constructors that can be registered with
+    /// `Registry::register_named_any`.
+    ///
+    /// It's automatically implemented for all `Fn() -> Box<dyn Any>`.
+    pub trait AnyCtor: Fn() -> Box<dyn std::any::Any> + 'static {}
+
+    impl<F> AnyCtor for F where F: Fn() -> Box<dyn std::any::Any> + 'static {}
+
+    /// A marker trait for all stateful constructors that can be registered
+    /// with `Registry::register_transient_stateful`.
+    ///
+    /// It's automatically implemented for all `FnMut() -> T`.
+    pub trait StatefulTransientCtor<T>: FnMut() -> T + 'static {}
+
+    impl<T, F> StatefulTransientCtor<T> for F where F: FnMut() -> T + 'static {}
 }
 
-#[cfg(feature = "tokio")]
-mod tokio_ext {
+#[cfg(feature = "async")]
+mod async_ext {
     use std::any::Any;
 
+    use crate::object_builder::{AsyncSingleton, AsyncTransientBuilder};
+
     // Alias types used in [`Registry`].
     pub(crate) type BoxedAny = Box<dyn Any + Send>;
     pub(crate) type RefAny = Ref<dyn Any + Send + Sync + 'static>;
+    pub(crate) type WeakAny = WeakRef<dyn Any + Send + Sync + 'static>;
+    pub(crate) type RefFactory = Ref<dyn Any + Send + Sync>;
+    pub(crate) type RefTransientBuilder =
+        Ref<dyn AsyncTransientBuilder + Send + Sync>;
+    pub(crate) type RefSingletonGetter = Ref<dyn AsyncSingleton + Send + Sync>;
 
     // `RwLock` types.
     pub(crate) type NonAsyncRwLock<T> = parking_lot::RwLock<T>;
+    #[cfg(feature = "tokio")]
     pub(crate) type RwLock<T> = ::tokio::sync::RwLock<T>;
+    #[cfg(all(feature = "async-std", not(feature = "tokio")))]
+    pub(crate) type RwLock<T> = ::async_std::sync::RwLock<T>;
 
     // Hashmap types.
     pub(crate) type HashMap<K, V> = hashbrown::HashMap<K, V>;
+    pub(crate) type HashSet<T> = hashbrown::HashSet<T>;
 
     // Cell types.
+    #[cfg(feature = "tokio")]
     pub(crate) type OnceCell<T> = ::tokio::sync::OnceCell<T>;
-    pub(crate) type SingletonCell = ::tokio::sync::OnceCell<RefAny>;
+    #[cfg(all(feature = "async-std", not(feature = "tokio")))]
+    pub(crate) type OnceCell<T> = ::async_lock::OnceCell<T>;
+    pub(crate) type SingletonCell = OnceCell<RefAny>;
 
     /// A generic constructor for singletons.
     ///
     /// This is a marker trait to identify all valid constructors usable by singletons.
     /// It's not implementable by other crates.
     ///
-    /// A blanket implementation for `FnOnce() -> T` is provided.
+    /// A blanket implementation for `Fn() -> T` is provided.
     pub trait SingletonCtor<T>:
-        FnOnce()
-            -> std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send>>
+        Fn() -> std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send>>
         + Send
         + Sync
         + 'static
     {
         /// Calls the construcor.
         fn call(
-            self,
+            &self,
             _: super::private::SealToken,
         ) -> std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send>>;
     }
 
     impl<T, F> SingletonCtor<T> for F
     where
-        F: FnOnce() -> std::pin::Pin<
-                Box<dyn std::future::Future<Output = T> + Send>,
-            > + Send
+        F: Fn()
+                -> std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send>>
+            + Send
             + Sync
             + 'static,
     {
         fn call(
-            self,
+            &self,
             _: super::private::SealToken,
         ) -> std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send>>
         {
@@ -310,9 +536,9 @@ mod tokio_ext {
     /// This is a marker trait to identify all valid constructors usable by singletons.
     /// It's not implementable by other crates.
     ///
-    /// A blanket implementation for `FnOnce(Deps) -> T` is provided.
+    /// A blanket implementation for `Fn(Deps) -> T` is provided.
     pub trait SingletonCtorDeps<T, Deps>:
-        FnOnce(
+        Fn(
             Deps,
         )
             -> std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send>>
@@ -322,7 +548,7 @@ mod tokio_ext {
     {
         /// Calls the construcor.
         fn call(
-            self,
+            &self,
             deps: Deps,
             _: super::private::SealToken,
         ) -> std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send>>;
@@ -330,17 +556,17 @@ mod tokio_ext {
 
     impl<T, F, Deps> SingletonCtorDeps<T, Deps> for F
     where
-        F: FnOnce(
+        F: Fn(
                 Deps,
-            ) -> std::pin::Pin<
-                Box<dyn std::future::Future<Output = T> + Send>,
-            > + Send
+            )
+                -> std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send>>
+            + Send
             + Sync
             + 'static,
         Deps: crate::dependency_builder::DepBuilder<T> + Sync + 'static,
     {
         fn call(
-            self,
+            &self,
             deps: Deps,
             _: super::private::SealToken,
         ) -> std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send>>
@@ -349,6 +575,59 @@ mod tokio_ext {
         }
     }
 
+    /// Constructor of transient objects without dependencies, as accepted by
+    /// `Registry::transient`.
+    pub type TransientCtor<T> =
+        fn() -> std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send>>;
+
+    /// A factory constructing `T` from runtime arguments `Args`.
+    ///
+    /// This is a marker trait to identify all valid factories usable by
+    /// `Registry::register_factory`. It's not implementable by other crates.
+    ///
+    /// A blanket implementation for `Fn(&Registry, Args) -> Pin<Box<dyn
+    /// Future<Output = T>>>` is provided.
+    pub trait Factory<Args, T>:
+        for<'reg> Fn(
+            &'reg crate::Registry,
+            Args,
+        ) -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = T> + Send + 'reg>,
+        > + Send
+        + Sync
+        + 'static
+    {
+        /// Calls the factory.
+        fn call<'reg>(
+            &self,
+            registry: &'reg crate::Registry,
+            args: Args,
+            _: super::private::SealToken,
+        ) -> std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send + 'reg>>;
+    }
+
+    impl<Args, T, F> Factory<Args, T> for F
+    where
+        F: for<'reg> Fn(
+                &'reg crate::Registry,
+                Args,
+            ) -> std::pin::Pin<
+                Box<dyn std::future::Future<Output = T> + Send + 'reg>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        fn call<'reg>(
+            &self,
+            registry: &'reg crate::Registry,
+            args: Args,
+            _: super::private::SealToken,
+        ) -> std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send + 'reg>>
+        {
+            (self)(registry, args)
+        }
+    }
+
     /// A generic reference type that's used as the default type for types with
     /// the singleton lifetime.
     ///
@@ -360,6 +639,9 @@ mod tokio_ext {
     /// simplifies enabling `multithread` when required.
     pub type Ref<T> = std::sync::Arc<T>;
 
+    /// The weak counterpart to [`Ref`], which is [`std::sync::Weak`].
+    pub type WeakRef<T> = std::sync::Weak<T>;
+
     /// A marker trait for all types that can be registered with `Registry::transient`.
     ///
     /// It's automatically implemented for all types that are valid. Generally,
@@ -376,13 +658,51 @@ mod tokio_ext {
     pub trait RegisterableSingleton: Send + Sync + 'static {}
 
     impl<T> RegisterableSingleton for T where T: Send + Sync + 'static {}
+
+    /// A marker trait for all constructors that can be registered with
+    /// `Registry::register_named_any`.
+    ///
+    /// It's automatically implemented for all `Fn() -> Box<dyn Any>`, that
+    /// are also `Send` and `Sync`.
+    pub trait AnyCtor:
+        Fn() -> Box<dyn std::any::Any> + Send + Sync + 'static
+    {
+    }
+
+    impl<F> AnyCtor for F where
+        F: Fn() -> Box<dyn std::any::Any> + Send + Sync + 'static
+    {
+    }
+
+    /// A marker trait for all stateful constructors that can be registered
+    /// with `Registry::register_transient_stateful`.
+    ///
+    /// It's automatically implemented for all `FnMut() -> Future<Output = T>`,
+    /// that are also `Send` and `Sync`.
+    pub trait StatefulTransientCtor<T>:
+        FnMut()
+            -> std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send>>
+        + Send
+        + Sync
+        + 'static
+    {
+    }
+
+    impl<T, F> StatefulTransientCtor<T> for F where
+        F: FnMut() -> std::pin::Pin<
+                Box<dyn std::future::Future<Output = T> + Send>,
+            > + Send
+            + Sync
+            + 'static
+    {
+    }
 }
 
-#[cfg(all(feature = "multithread", not(feature = "tokio")))]
+#[cfg(all(feature = "multithread", not(feature = "async")))]
 pub use sync::*;
 
-#[cfg(all(not(feature = "multithread"), not(feature = "tokio")))]
+#[cfg(all(not(feature = "multithread"), not(feature = "async")))]
 pub use unsync::*;
 
-#[cfg(feature = "tokio")]
-pub use tokio_ext::*;
+#[cfg(feature = "async")]
+pub use async_ext::*;
@@ -6,9 +6,9 @@
 )]
 use std::borrow::Cow;
 
-use darling::ast::Fields;
+use darling::ast::{Data, Fields, Style};
 use darling::util::{IdentString, Override, SpannedValue};
-use darling::{util, FromDeriveInput, FromField};
+use darling::{FromDeriveInput, FromField, FromVariant};
 use quote::quote;
 use syn::Type;
 
@@ -50,6 +50,41 @@ pub(crate) struct DeriveField {
     /// used as a constructor.
     ctor: Option<SpannedValue<String>>,
 
+    /// Same as `ctor`, except that the expression may `.await`. Requires an
+    /// async runtime feature (`tokio` or `async-std`).
+    ctor_async: Option<SpannedValue<String>>,
+
+    /// Same as `ctor`, except that the expression returns a `Result`, whose
+    /// error is returned from the constructor. Requires `#[provides(fallible)]`.
+    ctor_try: Option<SpannedValue<String>>,
+
+    /// The environment variable the member is parsed from, with `FromStr`.
+    /// Requires `#[provides(fallible)]`.
+    env: Option<SpannedValue<String>>,
+
+    /// Whether the injected transient or singleton is optional, and resolved
+    /// to `None` if it isn't registered. Defaults to `false`.
+    #[darling(default)]
+    optional: bool,
+
+    /// The name of the registration the transient or singleton is resolved
+    /// from, for named registrations.
+    name: Option<SpannedValue<String>>,
+
+    /// Whether the member is injected as a `Lazy<T>`, which constructs the
+    /// transient `T` on first access. Defaults to `false`.
+    #[darling(default)]
+    lazy: bool,
+
+    /// Whether the member is injected as a `Vec<T>` of all transients
+    /// registered with `register_all`. Defaults to `false`.
+    #[darling(default)]
+    all: bool,
+
+    /// Whether the member is injected as an owned clone of a singleton.
+    /// Defaults to `false`.
+    #[darling(default)]
+    clone_singleton: bool,
     // Make sure to update `not_injected` when adding any new attributes.
 }
 
@@ -79,11 +114,45 @@ impl DeriveField {
         self.singleton
     }
 
+    /// Whether the injected transient or singleton is optional, and resolved
+    /// to `None` if it isn't registered. Defaults to `false`.
+    pub(crate) fn is_optional(&self) -> bool {
+        self.optional
+    }
+
+    /// Whether the member is injected as a `Lazy<T>`, which constructs the
+    /// transient `T` on first access. Defaults to `false`.
+    pub(crate) fn is_lazy(&self) -> bool {
+        self.lazy
+    }
+
+    /// Whether the member is injected as a `Vec<T>` of all transients
+    /// registered with `register_all`. Defaults to `false`.
+    pub(crate) fn is_all(&self) -> bool {
+        self.all
+    }
+
+    /// Whether the member is injected as an owned clone of a singleton.
+    /// Defaults to `false`.
+    pub(crate) fn is_clone_singleton(&self) -> bool {
+        self.clone_singleton
+    }
+
+    /// The name of the registration the transient or singleton is resolved
+    /// from, for named registrations.
+    pub(crate) fn name(&self) -> Option<&SpannedValue<String>> {
+        self.name.as_ref()
+    }
+
     /// Whether this member is constructed using `Default::default()`. Defaults
     /// to `false`.
     pub(crate) fn is_using_default_ctor(&self) -> bool {
         // The `ctor` overrides default construction.
-        self.ctor.is_none() && self.default
+        self.ctor.is_none()
+            && self.ctor_async.is_none()
+            && self.ctor_try.is_none()
+            && self.env.is_none()
+            && self.default
     }
 
     /// If it's neither a transient, singleton, or default constructed, this is
@@ -92,59 +161,190 @@ impl DeriveField {
         self.ctor.as_ref()
     }
 
+    /// Same as `ctor`, except that the expression may `.await`. Requires an
+    /// async runtime feature (`tokio` or `async-std`).
+    pub(crate) fn ctor_async(&self) -> Option<&SpannedValue<String>> {
+        self.ctor_async.as_ref()
+    }
+
+    /// Same as `ctor`, except that the expression returns a `Result`, whose
+    /// error is returned from the constructor. Requires `#[provides(fallible)]`.
+    pub(crate) fn ctor_try(&self) -> Option<&SpannedValue<String>> {
+        self.ctor_try.as_ref()
+    }
+
+    /// The environment variable the member is parsed from, with `FromStr`.
+    /// Requires `#[provides(fallible)]`.
+    pub(crate) fn env(&self) -> Option<&SpannedValue<String>> {
+        self.env.as_ref()
+    }
+
+    /// Injects this field as a transient, if it isn't injected otherwise,
+    /// used for the single field of newtypes.
+    pub(crate) fn into_injected(mut self) -> Self {
+        if self.not_injected() {
+            self.transient = true;
+        }
+        self
+    }
+
     /// Whether this field is ignored during custom ctor construction, and not
     /// passed as an injected field to the constructor.
     pub(crate) fn not_injected(&self) -> bool {
         !self.is_transient()
             && !self.is_singleton()
+            && !self.is_lazy()
+            && !self.is_all()
+            && !self.is_clone_singleton()
             && self.ctor.is_none()
+            && self.ctor_async.is_none()
+            && self.ctor_try.is_none()
+            && self.env.is_none()
             && !self.default
     }
 }
 
+#[derive(Debug, Clone, FromVariant)]
+#[darling(attributes(inject))]
+pub(crate) struct DeriveVariant {
+    // Magic types:
+    /// The identifier of the passed-in variant.
+    ident: syn::Ident,
+    /// The fields of the passed-in variant.
+    fields: Fields<DeriveField>,
+
+    //  ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫ Custom: ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+    /// Whether this variant is the one that's constructed. Defaults to `false`.
+    #[darling(default)]
+    default_variant: bool,
+}
+
+impl DeriveVariant {
+    /// Get a reference to the identifier.
+    pub(crate) fn ident(&self) -> &syn::Ident {
+        &self.ident
+    }
+
+    /// Get a reference to the fields of the variant.
+    pub(crate) fn fields(&self) -> &Fields<DeriveField> {
+        &self.fields
+    }
+
+    /// Whether this variant is the one that's constructed. Defaults to `false`.
+    pub(crate) fn is_default_variant(&self) -> bool {
+        self.default_variant
+    }
+}
+
 #[derive(Debug, Clone, FromDeriveInput)]
-#[darling(attributes(inject, provides), supports(struct_any))]
+#[darling(attributes(inject, provides), supports(struct_any, enum_any))]
 pub(crate) struct DeriveAttrInput {
     // Magic types:
     ident: syn::Ident,
     // generics: syn::Generics,
-    data: darling::ast::Data<util::Ignored, DeriveField>,
+    data: Data<DeriveVariant, DeriveField>,
     // attrs: Vec<syn::Attribute>,
 
     //  ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫ Custom: ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
     /// Whether this type is registered as a transient, and, optionally specify what type.
-    transient: Option<Override<Type>>,
+    /// Might be repeated to register the type under multiple types.
+    #[darling(multiple)]
+    transient: Vec<SpannedValue<Override<Type>>>,
 
     /// Whether this type is registered as a singleton, and, optionally specify what type.
-    singleton: Option<Override<Type>>,
+    /// Might be repeated to register the type under multiple types.
+    #[darling(multiple)]
+    singleton: Vec<SpannedValue<Override<Type>>>,
 
     /// Whether a non-default ctor is called, into which all the dependencies are passed as
     /// function arguments.
     ctor: Option<SpannedValue<IdentString>>,
 
+    /// A method that's called after construction, taking `&mut self`, or
+    /// `self` and returning `Self`.
+    post_construct: Option<SpannedValue<IdentString>>,
+
+    /// Whether the construction of this type can fail. The constructor returns
+    /// a `Result`, and the type is registered with `try_register_transient`,
+    /// or `try_register_singleton`.
+    #[darling(default)]
+    fallible: bool,
+
+    /// Whether this type is a newtype wrapper around its single field. The
+    /// field is injected as a transient, unless specified otherwise, and
+    /// `From` and `Deref` are implemented for the inner type.
+    #[darling(default)]
+    newtype: bool,
+
     /// Whether this type isn't registered automatically. With this disabled, the generated
     /// `Register` function needs to be called manually.
     #[darling(default)]
     no_registration: bool,
+
+    /// The group this type is registered in. Grouped types aren't registered
+    /// in the global registry, but with `Registry::populate_group`.
+    group: Option<SpannedValue<String>>,
+
+    /// Path to the `ferrunix` crate, used by the generated code. Defaults to
+    /// `::ferrunix`.
+    #[darling(rename = "crate")]
+    krate: Option<syn::Path>,
 }
 
 impl DeriveAttrInput {
-    /// Iterator over the struct fields.
+    /// Iterator over the struct fields, or the fields of the default variant
+    /// for enums.
+    ///
+    /// For newtypes, the single field is injected as a transient, if it has
+    /// no `inject` attribute.
     pub(crate) fn fields(&self) -> Fields<DeriveField> {
-        self.data.clone().take_struct().expect(
-            "only structs supported. this should be enforced by darling.",
-        )
+        match &self.data {
+            Data::Struct(fields) if self.is_newtype() => {
+                fields.clone().map(DeriveField::into_injected)
+            }
+            Data::Struct(fields) => fields.clone(),
+            Data::Enum(_) => self.default_variant().map_or_else(
+                || Fields::new(Style::Unit, Vec::new()),
+                |variant| variant.fields().clone(),
+            ),
+        }
     }
 
     /// Access to the inner data.
-    pub(crate) fn data(
-        &self,
-    ) -> &darling::ast::Data<util::Ignored, DeriveField> {
+    pub(crate) fn data(&self) -> &Data<DeriveVariant, DeriveField> {
         &self.data
     }
 
+    /// Whether the type is an enum.
+    pub(crate) fn is_enum(&self) -> bool {
+        self.data.is_enum()
+    }
+
+    /// All variants marked with `#[inject(default_variant)]`. Empty for
+    /// structs.
+    pub(crate) fn default_variants(&self) -> Vec<&DeriveVariant> {
+        match &self.data {
+            Data::Struct(_) => Vec::new(),
+            Data::Enum(variants) => variants
+                .iter()
+                .filter(|variant| variant.is_default_variant())
+                .collect(),
+        }
+    }
+
+    /// The variant marked with `#[inject(default_variant)]`, which is
+    /// constructed for enums.
+    pub(crate) fn default_variant(&self) -> Option<&DeriveVariant> {
+        match &self.data {
+            Data::Struct(_) => None,
+            Data::Enum(variants) => {
+                variants.iter().find(|variant| variant.is_default_variant())
+            }
+        }
+    }
+
     /// Whether the `provides` attribute has set a transient value.
-    /// Returns the value, or `Self`, when set, and `None`, when unset.
+    /// Returns the first value, or `Self`, when set, and `None`, when unset.
     ///
     /// Accepted forms are:
     ///   * `#[provides(transient)]`
@@ -152,26 +352,28 @@ impl DeriveAttrInput {
     ///
     /// When the first form is used, the type is set to `Self`.
     pub(crate) fn transient(&self) -> Option<Cow<'_, Type>> {
-        match &self.transient {
-            Some(attr) => match attr {
-                Override::Inherit => {
-                    let tokens = quote!(Self);
-                    let ty = syn::parse2(tokens).expect("Self to be valid");
-                    Some(Cow::Owned(ty))
-                }
-                Override::Explicit(ty) => {
-                    let ret = transform_type(ty, TransformType::Transient)
-                        .expect("a well-formed type");
-                    Some(ret)
-                }
-            },
-
-            None => None,
-        }
+        self.transient.first().map(|attr| {
+            provided_type(attr, TransformType::Transient, &self.crate_path())
+        })
+    }
+
+    /// All values set as transient by the `provides` attribute, see
+    /// [`Self::transient`].
+    pub(crate) fn transients(&self) -> Vec<Cow<'_, Type>> {
+        self.transient
+            .iter()
+            .map(|attr| {
+                provided_type(
+                    attr,
+                    TransformType::Transient,
+                    &self.crate_path(),
+                )
+            })
+            .collect()
     }
 
     /// Whether the `provides` attribute has set a singleton value.
-    /// Returns the value, or `Self`, when set, and `None`, when unset.
+    /// Returns the first value, or `Self`, when set, and `None`, when unset.
     ///
     /// Accepted forms are:
     ///   * `#[provides(singleton)]`
@@ -179,22 +381,57 @@ impl DeriveAttrInput {
     ///
     /// When the first form is used, the type is set to `Self`.
     pub(crate) fn singleton(&self) -> Option<Cow<'_, Type>> {
-        match &self.singleton {
-            Some(attr) => match attr {
-                Override::Inherit => {
-                    let tokens = quote!(Self);
-                    let ty = syn::parse2(tokens).expect("Self to be valid");
-                    Some(Cow::Owned(ty))
-                }
-                Override::Explicit(ty) => {
-                    let ret = transform_type(ty, TransformType::Singleton)
-                        .expect("a well-formed type");
-                    Some(ret)
-                }
-            },
-
-            None => None,
+        self.singleton.first().map(|attr| {
+            provided_type(attr, TransformType::Singleton, &self.crate_path())
+        })
+    }
+
+    /// All values set as singleton by the `provides` attribute, see
+    /// [`Self::singleton`].
+    pub(crate) fn singletons(&self) -> Vec<Cow<'_, Type>> {
+        self.singleton
+            .iter()
+            .map(|attr| {
+                provided_type(
+                    attr,
+                    TransformType::Singleton,
+                    &self.crate_path(),
+                )
+            })
+            .collect()
+    }
+
+    /// The concrete type of a generic type, pinned with
+    /// `#[provides(transient = "MyType<u8>")]`, or `singleton`, respectively.
+    ///
+    /// Returns `None`, if the provided type isn't the type itself.
+    pub(crate) fn pinned_type(&self) -> Option<&Type> {
+        self.transient
+            .iter()
+            .chain(&self.singleton)
+            .find_map(|attr| match &**attr {
+                Override::Explicit(ty) => match ty {
+                    Type::Path(path) => path
+                        .path
+                        .segments
+                        .last()
+                        .filter(|segment| segment.ident == self.ident)
+                        .map(|_| ty),
+                    _ => None,
+                },
+                Override::Inherit => None,
+            })
+    }
+
+    /// The span of the first `singleton` of the `provides` attribute, if the
+    /// type is provided as transient, too. A type can't be provided with both
+    /// lifetimes.
+    pub(crate) fn conflicting_lifetime(&self) -> Option<proc_macro2::Span> {
+        if self.transient.is_empty() {
+            return None;
         }
+
+        self.singleton.first().map(SpannedValue::span)
     }
 
     /// Whether this type isn't registered automatically. With this disabled, the generated
@@ -203,9 +440,61 @@ impl DeriveAttrInput {
         self.no_registration
     }
 
+    /// The group this type is registered in. Grouped types aren't registered
+    /// in the global registry, but with `Registry::populate_group`.
+    pub(crate) fn group(&self) -> Option<&SpannedValue<String>> {
+        self.group.as_ref()
+    }
+
     /// Whether a non-default ctor is called, into which all the dependencies are passed as
     /// function arguments.
     pub(crate) fn custom_ctor(&self) -> Option<&SpannedValue<IdentString>> {
         self.ctor.as_ref()
     }
+
+    /// A method that's called after construction, taking `&mut self`, or
+    /// `self` and returning `Self`.
+    pub(crate) fn post_construct(&self) -> Option<&SpannedValue<IdentString>> {
+        self.post_construct.as_ref()
+    }
+
+    /// Whether the construction of this type can fail. The constructor returns
+    /// a `Result`, and the type is registered with `try_register_transient`,
+    /// or `try_register_singleton`.
+    pub(crate) fn is_fallible(&self) -> bool {
+        self.fallible
+    }
+
+    /// Whether this type is a newtype wrapper around its single field. The
+    /// field is injected as a transient, unless specified otherwise, and
+    /// `From` and `Deref` are implemented for the inner type.
+    pub(crate) fn is_newtype(&self) -> bool {
+        self.newtype
+    }
+
+    /// Path to the `ferrunix` crate, used by the generated code. Defaults to
+    /// `::ferrunix`, set with `#[provides(crate = "my_crate::di")]`.
+    pub(crate) fn crate_path(&self) -> syn::Path {
+        self.krate
+            .clone()
+            .unwrap_or_else(|| syn::parse_quote!(::ferrunix))
+    }
+}
+
+/// The type registered by `attr`, or `Self`, if no type is set.
+fn provided_type<'attr>(
+    attr: &'attr Override<Type>,
+    what: TransformType,
+    krate: &syn::Path,
+) -> Cow<'attr, Type> {
+    match attr {
+        Override::Inherit => {
+            let tokens = quote!(Self);
+            let ty = syn::parse2(tokens).expect("Self to be valid");
+            Cow::Owned(ty)
+        }
+        Override::Explicit(ty) => {
+            transform_type(ty, what, krate).expect("a well-formed type")
+        }
+    }
 }
@@ -118,7 +118,7 @@ pub struct Foo {
     let receiver = DeriveAttrInput::from_derive_input(&parsed);
     let receiver = receiver.unwrap();
     let singleton = receiver.singleton().unwrap();
-    let ty: syn::Type = syn::parse2(quote!(::ferrunix::Ref<Foo>)).unwrap();
+    let ty: syn::Type = syn::parse2(quote!(Foo)).unwrap();
     assert_eq!(singleton.as_ref(), &ty);
     assert_eq!(receiver.transient(), None);
 }
@@ -166,3 +166,261 @@ pub struct Foo {
     let ctor = receiver.custom_ctor().unwrap();
     assert_eq!(*ctor.as_ident(), format_ident!("new"));
 }
+
+#[test]
+fn attr_enum_default_variant() {
+    let input = r#"
+#[derive(Inject)]
+#[provides(transient)]
+pub enum Foo {
+    Bar,
+    #[inject(default_variant)]
+    Baz {
+        #[inject(transient)]
+        counter: u8,
+    },
+}"#;
+    let parsed = syn::parse_str(input).unwrap();
+    let receiver = DeriveAttrInput::from_derive_input(&parsed).unwrap();
+    assert!(receiver.is_enum());
+    assert_eq!(receiver.default_variants().len(), 1);
+
+    let variant = receiver.default_variant().unwrap();
+    assert_eq!(variant.ident(), &format_ident!("Baz"));
+
+    let fields = receiver.fields();
+    assert_eq!(fields.len(), 1);
+    assert!(fields.iter().all(DeriveField::is_transient));
+}
+
+#[test]
+fn attr_pinned_type() {
+    let input = r#"
+#[derive(Inject)]
+#[provides(transient = "Foo<u8>")]
+pub struct Foo<T> {
+    counter: T,
+}"#;
+    let parsed = syn::parse_str(input).unwrap();
+    let receiver = DeriveAttrInput::from_derive_input(&parsed).unwrap();
+    let ty: syn::Type = syn::parse2(quote!(Foo<u8>)).unwrap();
+    assert_eq!(receiver.pinned_type(), Some(&ty));
+
+    let input = r#"
+#[derive(Inject)]
+#[provides(transient = "dyn Bar")]
+pub struct Foo<T> {
+    counter: T,
+}"#;
+    let parsed = syn::parse_str(input).unwrap();
+    let receiver = DeriveAttrInput::from_derive_input(&parsed).unwrap();
+    assert_eq!(receiver.pinned_type(), None);
+}
+
+#[test]
+fn attr_post_construct() {
+    let input = r#"
+#[derive(Inject)]
+#[provides(transient, post_construct = "init")]
+pub struct Foo {
+    counter: u8,
+}"#;
+    let parsed = syn::parse_str(input).unwrap();
+    let receiver = DeriveAttrInput::from_derive_input(&parsed).unwrap();
+    let post_construct = receiver.post_construct().unwrap();
+    assert_eq!(post_construct.as_ident(), &format_ident!("init"));
+    assert!(receiver.custom_ctor().is_none());
+}
+
+#[test]
+fn attr_multiple_provides() {
+    let input = r#"
+#[derive(Inject)]
+#[provides(transient = "dyn Foo")]
+#[provides(transient = "dyn Bar", singleton = "dyn Baz")]
+pub struct Foo {
+    counter: u8,
+}"#;
+    let parsed = syn::parse_str(input).unwrap();
+    let receiver = DeriveAttrInput::from_derive_input(&parsed).unwrap();
+    let transients = receiver.transients();
+    let singletons = receiver.singletons();
+    let foo: syn::Type =
+        syn::parse2(quote!(::std::boxed::Box<dyn Foo>)).unwrap();
+    let bar: syn::Type =
+        syn::parse2(quote!(::std::boxed::Box<dyn Bar>)).unwrap();
+    let baz: syn::Type = syn::parse2(quote!(::ferrunix::Ref<dyn Baz>)).unwrap();
+    assert_eq!(transients.len(), 2);
+    assert!(transients.iter().any(|ty| ty.as_ref() == &foo));
+    assert!(transients.iter().any(|ty| ty.as_ref() == &bar));
+    assert_eq!(singletons.len(), 1);
+    assert!(singletons.iter().all(|ty| ty.as_ref() == &baz));
+    assert_eq!(receiver.transient().as_deref(), Some(&foo));
+}
+
+#[test]
+fn attr_group() {
+    let input = r#"
+#[derive(Inject)]
+#[provides(transient, group = "plugins")]
+pub struct Foo {
+}"#;
+    let parsed = syn::parse_str(input).unwrap();
+    let receiver = DeriveAttrInput::from_derive_input(&parsed).unwrap();
+    assert_eq!(
+        receiver.group().map(|group| group.as_str()),
+        Some("plugins")
+    );
+    assert!(!receiver.no_registration());
+}
+
+#[test]
+fn attr_field_name() {
+    let input = r#"
+#[derive(Inject)]
+#[provides(transient)]
+pub struct Foo {
+    #[inject(transient, name = "replica")]
+    replica: u16,
+    #[inject(transient)]
+    primary: u16,
+}"#;
+    let parsed = syn::parse_str(input).unwrap();
+    let receiver = DeriveAttrInput::from_derive_input(&parsed).unwrap();
+    let fields = receiver.fields();
+    let names = fields
+        .iter()
+        .map(|field| field.name().map(|name| name.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(names, [Some("replica"), None]);
+}
+
+#[test]
+fn attr_field_lazy() {
+    let input = r#"
+#[derive(Inject)]
+#[provides(transient)]
+pub struct Foo {
+    #[inject(lazy)]
+    deferred: Lazy<u16>,
+}"#;
+    let parsed = syn::parse_str(input).unwrap();
+    let receiver = DeriveAttrInput::from_derive_input(&parsed).unwrap();
+    let fields = receiver.fields();
+    let deferred = fields.iter().next().unwrap();
+    assert!(deferred.is_lazy());
+    assert!(!deferred.is_transient());
+    assert!(!deferred.not_injected());
+}
+
+#[test]
+fn attr_field_all() {
+    let input = r#"
+#[derive(Inject)]
+#[provides(transient)]
+pub struct Foo {
+    #[inject(all)]
+    handlers: Vec<Box<dyn Handler>>,
+}"#;
+    let parsed = syn::parse_str(input).unwrap();
+    let receiver = DeriveAttrInput::from_derive_input(&parsed).unwrap();
+    let fields = receiver.fields();
+    let handlers = fields.iter().next().unwrap();
+    assert!(handlers.is_all());
+    assert!(!handlers.is_transient());
+    assert!(!handlers.not_injected());
+}
+
+#[test]
+fn attr_field_clone_singleton() {
+    let input = r#"
+#[derive(Inject)]
+#[provides(transient)]
+pub struct Foo {
+    #[inject(clone_singleton)]
+    config: Config,
+}"#;
+    let parsed = syn::parse_str(input).unwrap();
+    let receiver = DeriveAttrInput::from_derive_input(&parsed).unwrap();
+    let fields = receiver.fields();
+    let config = fields.iter().next().unwrap();
+    assert!(config.is_clone_singleton());
+    assert!(!config.is_singleton());
+    assert!(!config.not_injected());
+}
+
+#[test]
+fn attr_crate_path() {
+    let input = r#"
+#[derive(Inject)]
+#[provides(singleton = "dyn Foo", crate = "my_crate::di")]
+pub struct Foo {
+}"#;
+    let parsed = syn::parse_str(input).unwrap();
+    let receiver = DeriveAttrInput::from_derive_input(&parsed).unwrap();
+    let krate: syn::Path = syn::parse2(quote!(my_crate::di)).unwrap();
+    assert_eq!(receiver.crate_path(), krate);
+    let ty: syn::Type =
+        syn::parse2(quote!(my_crate::di::Ref<dyn Foo>)).unwrap();
+    assert_eq!(receiver.singleton().as_deref(), Some(&ty));
+
+    let input = r#"
+#[derive(Inject)]
+#[provides(transient)]
+pub struct Foo {
+}"#;
+    let parsed = syn::parse_str(input).unwrap();
+    let receiver = DeriveAttrInput::from_derive_input(&parsed).unwrap();
+    let krate: syn::Path = syn::parse2(quote!(::ferrunix)).unwrap();
+    assert_eq!(receiver.crate_path(), krate);
+}
+
+#[test]
+fn attr_field_env() {
+    let input = r#"
+#[derive(Inject)]
+#[provides(transient, fallible)]
+pub struct Foo {
+    #[inject(env = "PORT")]
+    port: u16,
+}"#;
+    let parsed = syn::parse_str(input).unwrap();
+    let receiver = DeriveAttrInput::from_derive_input(&parsed).unwrap();
+    let fields = receiver.fields();
+    let port = fields.iter().next().unwrap();
+    assert_eq!(port.env().map(|env| env.as_str()), Some("PORT"));
+    assert!(!port.not_injected());
+    assert!(!port.is_using_default_ctor());
+}
+
+#[test]
+fn attr_newtype() {
+    let input = r#"
+#[derive(Inject)]
+#[provides(transient, newtype)]
+pub struct Port(u16);"#;
+    let parsed = syn::parse_str(input).unwrap();
+    let receiver = DeriveAttrInput::from_derive_input(&parsed).unwrap();
+    assert!(receiver.is_newtype());
+
+    // The single field is injected as a transient by default.
+    let fields = receiver.fields();
+    let inner = fields.iter().next().unwrap();
+    assert!(inner.is_transient());
+    assert!(!inner.not_injected());
+}
+
+#[test]
+fn attr_newtype_explicit_field() {
+    let input = r#"
+#[derive(Inject)]
+#[provides(transient, newtype)]
+pub struct Port(#[inject(ctor = "8080")] u16);"#;
+    let parsed = syn::parse_str(input).unwrap();
+    let receiver = DeriveAttrInput::from_derive_input(&parsed).unwrap();
+
+    let fields = receiver.fields();
+    let inner = fields.iter().next().unwrap();
+    assert!(!inner.is_transient());
+    assert_eq!(inner.ctor().map(|ctor| ctor.as_str()), Some("8080"));
+}
@@ -2,13 +2,17 @@
 //!
 //! Specifically, not in `lib.rs` to create module encapsulation.
 
-use darling::ast::Fields;
-use quote::{format_ident, quote, ToTokens, TokenStreamExt};
+use darling::ast::{Fields, Style};
+use darling::util::SpannedValue;
+use quote::{format_ident, quote};
 use syn::spanned::Spanned;
 use syn::{Data, DeriveInput};
 
 use crate::attr::{DeriveAttrInput, DeriveField};
-use crate::utils::get_ctor_for;
+use crate::utils::{
+    get_ctor_for, lazy_inner_type, option_inner_type, ref_inner_type,
+    vec_inner_type,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum DependencyType {
@@ -16,11 +20,15 @@ pub(crate) enum DependencyType {
     Transient,
 }
 
-impl ToTokens for DependencyType {
-    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        match self {
-            Self::Singleton => tokens.append(format_ident!("singleton")),
-            Self::Transient => tokens.append(format_ident!("transient")),
+impl DependencyType {
+    /// The method of `Registry`, and `Builder`, registering a type with this
+    /// lifetime, with a fallible constructor if `fallible` is set.
+    fn register_method(self, fallible: bool) -> syn::Ident {
+        match (self, fallible) {
+            (Self::Singleton, false) => format_ident!("singleton"),
+            (Self::Transient, false) => format_ident!("transient"),
+            (Self::Singleton, true) => format_ident!("try_register_singleton"),
+            (Self::Transient, true) => format_ident!("try_register_transient"),
         }
     }
 }
@@ -30,26 +38,95 @@ pub(crate) fn derive_macro_impl(
     attrs: &DeriveAttrInput,
 ) -> syn::Result<proc_macro2::TokenStream> {
     let struct_name = &input.ident;
+    let krate = attrs.crate_path();
 
+    if attrs.is_enum() && attrs.default_variants().len() != 1 {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "enums require exactly one variant marked with \
+             `#[inject(default_variant)]`",
+        ));
+    }
+
+    if let Some(span) = attrs.conflicting_lifetime() {
+        return Err(syn::Error::new(
+            span,
+            "`transient` and `singleton` are mutually exclusive, a type is \
+             provided with one lifetime",
+        ));
+    }
+
+    validate_lifetime_fields(&attrs.fields())?;
+    validate_optional_fields(&attrs.fields())?;
+    validate_named_fields(&attrs.fields())?;
+    validate_singleton_fields(&attrs.fields())?;
+    validate_lazy_fields(&attrs.fields())?;
+    validate_all_fields(&attrs.fields())?;
+    validate_clone_singleton_fields(&attrs.fields())?;
+    validate_ctor_try_fields(&attrs.fields(), attrs.is_fallible())?;
+    validate_env_fields(&attrs.fields(), attrs.is_fallible())?;
+
+    let newtype = newtype_impls(input, attrs)?;
     let registration = registration(input, attrs)?;
-    let sig = register_func_sig();
+    let sig = register_func_sig(&krate);
     let boxed_registration = box_if_required(&registration);
 
+    // Generic types are registered as the concrete type pinned in `provides`,
+    // if there is one.
+    let is_generic = !input.generics.params.is_empty();
+    let pinned_type = attrs.pinned_type().filter(|_| is_generic);
+    let impl_type = pinned_type.map_or_else(
+        || {
+            let (impl_generics, ty_generics, where_clause) =
+                input.generics.split_for_impl();
+            quote! { #impl_generics #struct_name #ty_generics #where_clause }
+        },
+        |ty| quote! { #ty },
+    );
+
     let autoregistration = {
-        if attrs.no_registration() {
+        if let (true, Some(group)) = (attrs.no_registration(), attrs.group()) {
+            return Err(syn::Error::new(
+                group.span(),
+                "`group` can't be combined with `no_registration`",
+            ));
+        } else if attrs.no_registration() {
             None
+        } else if is_generic && pinned_type.is_none() {
+            return Err(syn::Error::new(
+                input.generics.span(),
+                "generic types require a concrete type, e.g., \
+                 `#[provides(transient = \"MyType<u8>\")]`, or \
+                 `no_registration`",
+            ));
         } else {
+            let registered = pinned_type
+                .map_or_else(|| quote! { #struct_name }, |ty| quote! { #ty });
+            let registration_func = attrs.group().map_or_else(
+                || {
+                    quote! {
+                        #krate::RegistrationFunc::new(<#registered>::register)
+                    }
+                },
+                |group| {
+                    let group = syn::LitStr::new(group.as_str(), group.span());
+                    quote! {
+                        #krate::RegistrationFunc::with_group(
+                            <#registered>::register,
+                            #group
+                        )
+                    }
+                },
+            );
             Some(quote! {
-                ::ferrunix::autoregister!(::ferrunix::RegistrationFunc::new(
-                        <#struct_name>::register
-                ));
+                #krate::autoregister!(#registration_func);
             })
         }
     };
 
     let expanded = quote! {
         #[automatically_derived]
-        impl #struct_name {
+        impl #impl_type {
             #[allow(clippy::use_self, dead_code)]
             #sig {
                 #boxed_registration
@@ -57,19 +134,335 @@ pub(crate) fn derive_macro_impl(
         }
 
         #autoregistration
+
+        #newtype
     };
 
     Ok(expanded)
 }
 
-fn register_func_sig() -> proc_macro2::TokenStream {
-    #[cfg(not(feature = "tokio"))]
-    quote! { pub(crate) fn register(registry: &::ferrunix::Registry) }
+/// The `From` and `Deref` implementations for the inner type of newtypes,
+/// `None` if the type isn't a newtype.
+fn newtype_impls(
+    input: &DeriveInput,
+    attrs: &DeriveAttrInput,
+) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    if !attrs.is_newtype() {
+        return Ok(None);
+    }
+
+    let fields = attrs.fields();
+    let field = match (attrs.is_enum(), fields.fields.as_slice()) {
+        (false, [field]) => field,
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "`newtype` requires a struct with exactly one field",
+            ))
+        }
+    };
+
+    let struct_name = &input.ident;
+    let ty = field.ty();
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+    let (construct, access) = field.ident().map_or_else(
+        || (quote! { Self(value) }, quote! { self.0 }),
+        |ident| (quote! { Self { #ident: value } }, quote! { self.#ident }),
+    );
+
+    Ok(Some(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::From<#ty>
+            for #struct_name #ty_generics #where_clause
+        {
+            fn from(value: #ty) -> Self {
+                #construct
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::ops::Deref
+            for #struct_name #ty_generics #where_clause
+        {
+            type Target = #ty;
+
+            fn deref(&self) -> &Self::Target {
+                &#access
+            }
+        }
+    }))
+}
+
+/// Fields are injected as either transient, or singleton, never both.
+fn validate_lifetime_fields(fields: &Fields<DeriveField>) -> syn::Result<()> {
+    for field in fields.iter() {
+        if field.is_transient() && field.is_singleton() {
+            return Err(syn::Error::new(
+                field.ty().span(),
+                "`transient` and `singleton` are mutually exclusive",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Optional fields must be injected as transient or singleton, and must be of
+/// type `Option<T>`.
+fn validate_optional_fields(fields: &Fields<DeriveField>) -> syn::Result<()> {
+    for field in fields.iter().filter(|field| field.is_optional()) {
+        if !field.is_transient() && !field.is_singleton() {
+            return Err(syn::Error::new(
+                field.ty().span(),
+                "`optional` requires `transient` or `singleton`",
+            ));
+        }
+
+        if option_inner_type(field.ty()).is_none() {
+            return Err(syn::Error::new(
+                field.ty().span(),
+                "optional dependencies require a field of type `Option<T>`",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Named fields must be injected as transient or singleton, and can't be
+/// optional.
+fn validate_named_fields(fields: &Fields<DeriveField>) -> syn::Result<()> {
+    for field in fields.iter() {
+        let Some(name) = field.name() else {
+            continue;
+        };
+
+        if !field.is_transient() && !field.is_singleton() {
+            return Err(syn::Error::new(
+                name.span(),
+                "`name` requires `transient` or `singleton`",
+            ));
+        }
+
+        if field.is_optional() {
+            return Err(syn::Error::new(
+                name.span(),
+                "`name` can't be combined with `optional`",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Singletons are shared, singleton fields must be of type `Ref<T>`, or
+/// `Option<Ref<T>>` for optional singletons.
+fn validate_singleton_fields(fields: &Fields<DeriveField>) -> syn::Result<()> {
+    for field in fields.iter().filter(|field| field.is_singleton()) {
+        if singleton_inner_type(field).is_none() {
+            let ty = option_inner_type(field.ty()).unwrap_or(field.ty());
+            return Err(syn::Error::new(
+                ty.span(),
+                "singletons are shared, the field must be of type `Ref<T>` \
+                 (or `Arc<T>`, `Rc<T>`), e.g., `Ref<MySingleton>`",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Lazy fields must be of type `Lazy<T>`, and can't be combined with any of
+/// the other ways to inject a field.
+fn validate_lazy_fields(fields: &Fields<DeriveField>) -> syn::Result<()> {
+    for field in fields.iter().filter(|field| field.is_lazy()) {
+        if field.is_transient()
+            || field.is_singleton()
+            || field.is_optional()
+            || field.name().is_some()
+        {
+            return Err(syn::Error::new(
+                field.ty().span(),
+                "`lazy` can't be combined with `transient`, `singleton`, \
+                 `optional`, or `name`",
+            ));
+        }
+
+        if lazy_inner_type(field.ty()).is_none() {
+            return Err(syn::Error::new(
+                field.ty().span(),
+                "lazy dependencies require a field of type `Lazy<T>`",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Fields injected with `all` must be of type `Vec<T>`, and can't be combined
+/// with any of the other ways to inject a field.
+fn validate_all_fields(fields: &Fields<DeriveField>) -> syn::Result<()> {
+    for field in fields.iter().filter(|field| field.is_all()) {
+        if field.is_transient()
+            || field.is_singleton()
+            || field.is_optional()
+            || field.is_lazy()
+            || field.name().is_some()
+        {
+            return Err(syn::Error::new(
+                field.ty().span(),
+                "`all` can't be combined with `transient`, `singleton`, \
+                 `optional`, `lazy`, or `name`",
+            ));
+        }
+
+        if vec_inner_type(field.ty()).is_none() {
+            return Err(syn::Error::new(
+                field.ty().span(),
+                "`all` requires a field of type `Vec<T>`",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Fields injected with `clone_singleton` must be of the owned singleton type
+/// `T`, and can't be combined with any of the other ways to inject a field.
+fn validate_clone_singleton_fields(
+    fields: &Fields<DeriveField>,
+) -> syn::Result<()> {
+    for field in fields.iter().filter(|field| field.is_clone_singleton()) {
+        if field.is_transient()
+            || field.is_singleton()
+            || field.is_optional()
+            || field.is_lazy()
+            || field.is_all()
+            || field.name().is_some()
+        {
+            return Err(syn::Error::new(
+                field.ty().span(),
+                "`clone_singleton` can't be combined with `transient`, \
+                 `singleton`, `optional`, `lazy`, `all`, or `name`",
+            ));
+        }
+
+        if ref_inner_type(field.ty()).is_some() {
+            return Err(syn::Error::new(
+                field.ty().span(),
+                "`clone_singleton` requires a field of the owned singleton \
+                 type `T`, use `singleton` for fields of type `Ref<T>`",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Fields constructed with `ctor_try` return their error from the
+/// constructor, which requires a fallible constructor.
+fn validate_ctor_try_fields(
+    fields: &Fields<DeriveField>,
+    is_fallible: bool,
+) -> syn::Result<()> {
+    for field in fields.iter() {
+        let Some(ctor) = field.ctor_try() else {
+            continue;
+        };
+
+        if !is_fallible {
+            return Err(syn::Error::new(
+                ctor.span(),
+                "`ctor_try` requires `#[provides(fallible)]` on the type",
+            ));
+        }
+
+        if field.ctor().is_some() || field.ctor_async().is_some() {
+            return Err(syn::Error::new(
+                ctor.span(),
+                "`ctor_try` can't be combined with `ctor`, or `ctor_async`",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Fields parsed from an environment variable return the error from the
+/// constructor, which requires a fallible constructor. They can't be
+/// injected, or constructed, in any other way.
+fn validate_env_fields(
+    fields: &Fields<DeriveField>,
+    is_fallible: bool,
+) -> syn::Result<()> {
+    for field in fields.iter() {
+        let Some(env) = field.env() else {
+            continue;
+        };
+
+        if !is_fallible {
+            return Err(syn::Error::new(
+                env.span(),
+                "`env` requires `#[provides(fallible)]` on the type",
+            ));
+        }
+
+        if field.is_transient()
+            || field.is_singleton()
+            || field.is_lazy()
+            || field.ctor().is_some()
+            || field.ctor_async().is_some()
+            || field.ctor_try().is_some()
+        {
+            return Err(syn::Error::new(
+                env.span(),
+                "`env` can't be combined with `transient`, `singleton`, \
+                 `lazy`, `ctor`, `ctor_async`, or `ctor_try`",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// The type `T` of a singleton field of type `Ref<T>`, or `Option<Ref<T>>`
+/// for optional singletons.
+fn singleton_inner_type(field: &DeriveField) -> Option<&syn::Type> {
+    let ty = if field.is_optional() {
+        option_inner_type(field.ty())?
+    } else {
+        field.ty()
+    };
+    ref_inner_type(ty)
+}
+
+/// The type a singleton field of type `Ref<T>` is registered as.
+///
+/// Trait objects are registered as `Ref<dyn Trait>`, e.g., with
+/// `#[provides(singleton = "dyn Trait")]`, all other types as `T`. Must match
+/// the key of the registration, see `transform_type`.
+fn singleton_dependency_type(
+    field: &DeriveField,
+    krate: &syn::Path,
+) -> proc_macro2::TokenStream {
+    let inner = singleton_inner_type(field)
+        .expect("singleton fields are validated before");
+    if matches!(inner, syn::Type::TraitObject(_)) {
+        quote! { #krate::Ref<#inner> }
+    } else {
+        quote! { #inner }
+    }
+}
+
+fn register_func_sig(krate: &syn::Path) -> proc_macro2::TokenStream {
+    #[cfg(not(feature = "async"))]
+    quote! { pub(crate) fn register(registry: &#krate::Registry) }
 
-    #[cfg(feature = "tokio")]
+    #[cfg(feature = "async")]
     quote! {
         pub(crate) fn register<'reg>(
-            registry: &'reg ::ferrunix::Registry,
+            registry: &'reg #krate::Registry,
         ) -> ::std::pin::Pin<
             ::std::boxed::Box<dyn ::std::future::Future<Output = ()> + Send + 'reg>,
         >
@@ -81,12 +474,12 @@ fn register_func_sig() -> proc_macro2::TokenStream {
 fn box_if_required(
     tokens: &proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
-    #[cfg(not(feature = "tokio"))]
+    #[cfg(not(feature = "async"))]
     {
         quote! { #tokens }
     }
 
-    #[cfg(feature = "tokio")]
+    #[cfg(feature = "async")]
     {
         quote! {
             ::std::boxed::Box::pin(async move { #tokens })
@@ -99,12 +492,12 @@ fn box_ctor_if_required(
     registered_ty: &syn::Type,
     tokens: &proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
-    #[cfg(not(feature = "tokio"))]
+    #[cfg(not(feature = "async"))]
     {
         quote! { #tokens }
     }
 
-    #[cfg(feature = "tokio")]
+    #[cfg(feature = "async")]
     {
         quote! {
             ::std::boxed::Box::pin(async move { #tokens as #registered_ty })
@@ -112,8 +505,33 @@ fn box_ctor_if_required(
     }
 }
 
+/// Wrap the construction of `registered_ty` in `tokens` as required by the
+/// registration: fallible constructors return `Ok(..)`, in a boxed future for
+/// async registrations.
+fn wrap_ctor(
+    registered_ty: &syn::Type,
+    attrs: &DeriveAttrInput,
+    tokens: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if !attrs.is_fallible() {
+        return box_ctor_if_required(registered_ty, tokens);
+    }
+
+    let krate = attrs.crate_path();
+    let ok = quote! {
+        ::core::result::Result::<#registered_ty, #krate::error::BoxErr>::Ok(
+            #tokens
+        )
+    };
+    if cfg!(feature = "async") {
+        quote! { ::std::boxed::Box::pin(async move { #ok }) }
+    } else {
+        ok
+    }
+}
+
 fn await_if_needed() -> Option<proc_macro2::TokenStream> {
-    (cfg!(feature = "tokio")).then(|| {
+    (cfg!(feature = "async")).then(|| {
         quote! {
            .await
         }
@@ -124,58 +542,96 @@ fn registration(
     input: &DeriveInput,
     attrs: &DeriveAttrInput,
 ) -> syn::Result<proc_macro2::TokenStream> {
-    if attrs.transient().is_some() {
-        registration_transient(input, attrs)
-    } else if attrs.singleton().is_some() {
-        registration_singleton(input, attrs)
-    } else {
+    let transients = attrs.transients();
+    let singletons = attrs.singletons();
+    if transients.is_empty() && singletons.is_empty() {
         // eprintln!("input: {input:#?}");
         // eprintln!("attrs: {attrs:#?}");
-        Err(syn::Error::new(
-            input.span(),
-            "missing transient or singleton annotation.",
-        ))
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "missing transient or singleton annotation, add \
+             `#[provides(transient)]` or `#[provides(singleton)]` above the \
+             type",
+        ));
     }
+
+    // Every provided type gets its own registration, constructing the same
+    // concrete type.
+    let registrations = transients
+        .iter()
+        .map(|registered_ty| {
+            registration_of(
+                DependencyType::Transient,
+                registered_ty,
+                input,
+                attrs,
+            )
+        })
+        .chain(singletons.iter().map(|registered_ty| {
+            registration_of(
+                DependencyType::Singleton,
+                registered_ty,
+                input,
+                attrs,
+            )
+        }))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! { #(#registrations)* })
 }
 
-fn registration_transient(
+fn registration_of(
+    dependency_type: DependencyType,
+    registered_ty: &syn::Type,
     input: &DeriveInput,
     attrs: &DeriveAttrInput,
 ) -> syn::Result<proc_macro2::TokenStream> {
     let fields_is_empty = attrs.fields().is_empty();
-    let registered_ty = attrs.transient().expect("transient attribute");
     // eprintln!("registered_ty: {registered_ty:#?}");
 
     if fields_is_empty {
-        registration_empty(DependencyType::Transient, &registered_ty)
+        registration_empty(dependency_type, registered_ty, attrs)
     } else {
-        registration_fields(
-            DependencyType::Transient,
-            &registered_ty,
-            input,
-            attrs,
-        )
+        registration_fields(dependency_type, registered_ty, input, attrs)
     }
 }
 
 fn registration_empty(
     dependency_type: DependencyType,
     registered_ty: &syn::Type,
+    attrs: &DeriveAttrInput,
 ) -> syn::Result<proc_macro2::TokenStream> {
-    let ctor = get_ctor_for(registered_ty, quote!(Self {}))?;
-    let ctor = box_ctor_if_required(registered_ty, &ctor);
-    let ifawait = await_if_needed();
-    let generic_args = {
-        match dependency_type {
-            DependencyType::Singleton => quote! { <#registered_ty, _> },
-            DependencyType::Transient => quote! { <#registered_ty> },
+    // Unit structs and variants are constructed without braces.
+    let is_unit = matches!(attrs.fields().style, Style::Unit);
+    let empty = match (attrs.default_variant(), is_unit) {
+        (Some(variant), true) => {
+            let variant = variant.ident();
+            quote!(Self::#variant)
         }
+        (Some(variant), false) => {
+            let variant = variant.ident();
+            quote!(Self::#variant {})
+        }
+        (None, true) => quote!(Self),
+        (None, false) => quote!(Self {}),
     };
+    let ctor = get_ctor_for(
+        registered_ty,
+        with_post_construct(attrs, empty),
+        &attrs.crate_path(),
+    )?;
+    let ctor = wrap_ctor(registered_ty, attrs, &ctor);
+    let ifawait = await_if_needed();
+    let method = dependency_type.register_method(attrs.is_fallible());
 
+    // Types without dependencies are registered with the empty dependency
+    // tuple, the same way as types with dependencies.
     let tokens = quote! {
-        registry.#dependency_type::#generic_args(|| {
-            #ctor
-        })#ifawait;
+        registry
+            .with_deps::<#registered_ty, ()>()
+            .#method(|()| {
+                #ctor
+            })#ifawait;
     };
 
     Ok(tokens)
@@ -190,35 +646,28 @@ fn registration_fields(
     // let current_ty = &input.ident;
 
     let fields = attrs.fields();
-    let dependency_tuple = into_dependency_tuple(&fields);
+    let krate = attrs.crate_path();
+    let dependency_names = into_dependency_names(&fields, &krate);
+    let dependency_ctors = into_dependency_ctors(&fields, &krate)?;
+    let dependency_tuple = into_dependency_tuple(&fields, &krate);
     let dependency_idents = into_dependency_idents(&fields);
     let constructor = type_ctor(registered_ty, input, attrs, &fields)?;
-    let constructor = box_ctor_if_required(registered_ty, &constructor);
+    let constructor = wrap_ctor(registered_ty, attrs, &constructor);
     let ifawait = await_if_needed();
-    let generic_args = {
-        match dependency_type {
-            DependencyType::Singleton => quote! { <#registered_ty, _> },
-            DependencyType::Transient => quote! { <#registered_ty> },
-        }
-    };
+    let method = dependency_type.register_method(attrs.is_fallible());
 
-    let tokens = match (dependency_tuple, dependency_idents) {
-        (Some(types), Some(idents)) => {
-            quote! {
-                registry
-                    .with_deps::<#registered_ty, #types>()
-                    .#dependency_type(|#idents| {
-                        #constructor
-                    })#ifawait;
-            }
-        }
-
-        _ => {
-            quote! {
-                registry.#dependency_type::#generic_args(|| {
+    // Fields that aren't injected result in the empty dependency tuple.
+    let types = dependency_tuple.unwrap_or_else(|| quote! { () });
+    let idents = dependency_idents.unwrap_or_else(|| quote! { () });
+    let tokens = quote! {
+        {
+            #dependency_names
+            #dependency_ctors
+            registry
+                .with_deps::<#registered_ty, #types>()
+                .#method(|#idents| {
                     #constructor
                 })#ifawait;
-            }
         }
     };
 
@@ -236,7 +685,13 @@ fn into_dependency_idents(
                 .ident()
                 .cloned()
                 .unwrap_or_else(|| format_ident!("_{i}"));
-            (field.is_transient() || field.is_singleton()).then_some(ident)
+            (field.is_transient()
+                || field.is_singleton()
+                || field.is_lazy()
+                || field.is_all()
+                || field.is_clone_singleton()
+                || field.ctor_async().is_some())
+            .then_some(ident)
         })
         .collect::<Vec<_>>();
     if !idents.is_empty() {
@@ -246,12 +701,86 @@ fn into_dependency_idents(
     None
 }
 
+/// The marker type implementing `DepName` for the named field at `idx`.
+fn dependency_name_ident(idx: usize) -> syn::Ident {
+    format_ident!("__FerrunixDepName{idx}")
+}
+
+/// Declare a marker type implementing `DepName` for every named field.
+fn into_dependency_names(
+    fields: &Fields<DeriveField>,
+    krate: &syn::Path,
+) -> proc_macro2::TokenStream {
+    let names = fields.iter().enumerate().filter_map(|(idx, field)| {
+        let name = field.name()?;
+        let name = syn::LitStr::new(name.as_str(), name.span());
+        let marker = dependency_name_ident(idx);
+        Some(quote! {
+            struct #marker;
+
+            impl #krate::dependencies::DepName for #marker {
+                const NAME: &'static str = #name;
+            }
+        })
+    });
+
+    quote! { #(#names)* }
+}
+
+/// The marker type implementing `DepCtor` for the `ctor_async` field at
+/// `idx`.
+fn dependency_ctor_ident(idx: usize) -> syn::Ident {
+    format_ident!("__FerrunixDepCtor{idx}")
+}
+
+/// Declare a marker type implementing `DepCtor` for every field constructed
+/// with `ctor_async`. The expression is evaluated while the dependencies are
+/// resolved, with the registry bound to `registry`.
+fn into_dependency_ctors(
+    fields: &Fields<DeriveField>,
+    krate: &syn::Path,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let ctors = fields
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, field)| {
+            let ctor = field.ctor_async()?;
+            Some(parse_ctor(ctor).map(|ctor| {
+                let ty = field.ty();
+                let marker = dependency_ctor_ident(idx);
+                quote! {
+                    struct #marker;
+
+                    impl #krate::dependencies::DepCtor<#ty> for #marker {
+                        #[allow(unused_variables)]
+                        fn construct(
+                            registry: &#krate::Registry,
+                        ) -> ::std::pin::Pin<
+                            ::std::boxed::Box<
+                                dyn ::std::future::Future<Output = #ty>
+                                    + Send
+                                    + '_,
+                            >,
+                        > {
+                            ::std::boxed::Box::pin(async move { #ctor })
+                        }
+                    }
+                }
+            }))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! { #(#ctors)* })
+}
+
 fn into_dependency_tuple(
     fields: &Fields<DeriveField>,
+    krate: &syn::Path,
 ) -> Option<proc_macro2::TokenStream> {
     let types = fields
         .iter()
-        .filter_map(into_dependency_type)
+        .enumerate()
+        .filter_map(|(idx, field)| into_dependency_type(idx, field, krate))
         .collect::<Vec<_>>();
     if !types.is_empty() {
         return Some(quote! { ( #(#types,)* ) });
@@ -261,13 +790,72 @@ fn into_dependency_tuple(
 }
 
 fn into_dependency_type(
+    idx: usize,
     field: &DeriveField,
+    krate: &syn::Path,
 ) -> Option<proc_macro2::TokenStream> {
+    if field.is_optional() {
+        let ty = option_inner_type(field.ty())
+            .expect("optional fields are validated before");
+        if field.is_transient() {
+            return Some(
+                quote! { #krate::dependencies::OptionalTransient<#ty> },
+            );
+        }
+        if field.is_singleton() {
+            let ty = singleton_dependency_type(field, krate);
+            return Some(
+                quote! { #krate::dependencies::OptionalSingleton<#ty> },
+            );
+        }
+    }
+
+    if field.is_lazy() {
+        let ty = lazy_inner_type(field.ty())
+            .expect("lazy fields are validated before");
+        return Some(quote! { #krate::dependencies::Lazy<#ty> });
+    }
+
+    if field.is_all() {
+        let ty = vec_inner_type(field.ty())
+            .expect("all fields are validated before");
+        return Some(quote! { #krate::dependencies::TransientAll<#ty> });
+    }
+
+    if field.is_clone_singleton() {
+        let ty = field.ty();
+        return Some(quote! { #krate::Singleton<#ty> });
+    }
+
+    if field.ctor_async().is_some() {
+        let ty = field.ty();
+        let marker = dependency_ctor_ident(idx);
+        return Some(
+            quote! { #krate::dependencies::Constructed<#ty, #marker> },
+        );
+    }
+
     let ty = field.ty();
+    if field.name().is_some() {
+        let marker = dependency_name_ident(idx);
+        if field.is_transient() {
+            return Some(
+                quote! { #krate::dependencies::NamedTransient<#ty, #marker> },
+            );
+        }
+        if field.is_singleton() {
+            let ty = singleton_dependency_type(field, krate);
+            return Some(
+                quote! { #krate::dependencies::NamedSingleton<#ty, #marker> },
+            );
+        }
+    }
+
     if field.is_transient() {
-        Some(quote! { ::ferrunix::Transient<#ty> })
+        Some(quote! { #krate::Transient<#ty> })
     } else if field.is_singleton() {
-        Some(quote! { ::ferrunix::Singleton<#ty> })
+        let ty = singleton_dependency_type(field, krate);
+        Some(quote! { #krate::Singleton<#ty> })
     } else {
         None
     }
@@ -279,17 +867,26 @@ fn type_ctor(
     attrs: &DeriveAttrInput,
     fields: &Fields<DeriveField>,
 ) -> syn::Result<proc_macro2::TokenStream> {
+    let krate = attrs.crate_path();
     let params = fields
         .iter()
         .enumerate()
         .filter(|(_, field)| !field.not_injected())
-        .map(|(idx, field)| field_ctor_rhs(idx, field))
+        .map(|(idx, field)| field_ctor_rhs(idx, field, &krate))
         .collect::<syn::Result<Vec<_>>>()?;
     if let Some(ctor_name) = attrs.custom_ctor() {
         let ctor_name = ctor_name.as_ident();
-        let ctor = get_ctor_for(registered_ty, quote! {
-            Self::#ctor_name(#(#params),*)
-        });
+        // Fallible constructors return a `Result`, the error is returned from
+        // the registered constructor.
+        let try_op = attrs.is_fallible().then(|| quote!(?));
+        let ctor = get_ctor_for(
+            registered_ty,
+            with_post_construct(
+                attrs,
+                quote! { Self::#ctor_name(#(#params),*)#try_op },
+            ),
+            &krate,
+        );
         let ctor = ctor?;
 
         return Ok(ctor);
@@ -298,15 +895,31 @@ fn type_ctor(
     let ctors = fields
         .iter()
         .enumerate()
-        .map(|(idx, field)| field_ctor(idx, field))
+        .map(|(idx, field)| field_ctor(idx, field, &krate))
         .collect::<syn::Result<Vec<_>>>()?;
 
+    if let Some(variant) = attrs.default_variant() {
+        let variant = variant.ident();
+        let ctor = match fields.style {
+            Style::Struct => quote! { Self::#variant { #(#ctors),* } },
+            Style::Tuple => quote! { Self::#variant ( #(#ctors),* ) },
+            Style::Unit => quote! { Self::#variant },
+        };
+
+        return get_ctor_for(
+            registered_ty,
+            with_post_construct(attrs, ctor),
+            &krate,
+        );
+    }
+
     if let Data::Struct(ref s) = input.data {
         match s.fields {
             syn::Fields::Named(_) => {
                 let ctor = get_ctor_for(
                     registered_ty,
-                    quote! { Self { #(#ctors),* } },
+                    with_post_construct(attrs, quote! { Self { #(#ctors),* } }),
+                    &krate,
                 )?;
 
                 return Ok(ctor);
@@ -314,7 +927,8 @@ fn type_ctor(
             syn::Fields::Unnamed(_) => {
                 let ctor = get_ctor_for(
                     registered_ty,
-                    quote! { Self ( #(#ctors),* ) },
+                    with_post_construct(attrs, quote! { Self ( #(#ctors),* ) }),
+                    &krate,
                 )?;
                 return Ok(ctor);
             }
@@ -324,20 +938,36 @@ fn type_ctor(
 
     Err(syn::Error::new(
         input.span(),
-        "only named and unnamed structs supported",
+        "only named and unnamed structs, and enums supported",
     ))
 }
 
+/// Wrap the construction of `Self` in `ctor` with the call to the
+/// `post_construct` hook, if there is one.
+fn with_post_construct(
+    attrs: &DeriveAttrInput,
+    ctor: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let Some(hook) = attrs.post_construct() else {
+        return ctor;
+    };
+
+    let hook = hook.as_ident();
+    let krate = attrs.crate_path();
+    quote! { #krate::post_construct(#ctor, Self::#hook) }
+}
+
 fn field_ctor(
     idx: usize,
     attrs: &DeriveField,
+    krate: &syn::Path,
 ) -> syn::Result<proc_macro2::TokenStream> {
     let ident = attrs
         .ident()
         .cloned()
         .unwrap_or_else(|| format_ident!("_{idx}"));
 
-    let ctor = field_ctor_rhs(idx, attrs)?;
+    let ctor = field_ctor_rhs(idx, attrs, krate)?;
 
     // We have a named struct.
     let tokens = if attrs.ident().is_some() {
@@ -353,49 +983,88 @@ fn field_ctor(
 fn field_ctor_rhs(
     idx: usize,
     attrs: &DeriveField,
+    krate: &syn::Path,
 ) -> syn::Result<proc_macro2::TokenStream> {
     let ident = attrs
         .ident()
         .cloned()
         .unwrap_or_else(|| format_ident!("_{idx}"));
 
-    if attrs.is_transient() || attrs.is_singleton() {
+    let is_trait_object = singleton_inner_type(attrs)
+        .map_or(false, |ty| matches!(ty, syn::Type::TraitObject(_)));
+    if attrs.is_singleton() && is_trait_object {
+        // Trait objects are registered as `Ref<dyn Trait>`, and resolved as
+        // `Ref<Ref<dyn Trait>>`.
+        if attrs.is_optional() {
+            Ok(quote! { #ident.get().map(|dep| #krate::Ref::clone(&*dep)) })
+        } else {
+            Ok(quote! { #krate::Ref::clone(&*#ident.get()) })
+        }
+    } else if attrs.is_transient() || attrs.is_singleton() || attrs.is_all() {
         Ok(quote! { #ident.get() })
-    } else if let Some(ctor) = attrs.ctor() {
-        let parsed = syn::parse_str::<syn::Expr>(ctor);
-        if let Err(err) = parsed {
+    } else if attrs.is_clone_singleton() {
+        // The singleton is resolved as `Ref<T>`, the field is an owned clone.
+        let ty = attrs.ty();
+        Ok(quote! { <#ty as ::core::clone::Clone>::clone(&*#ident.get()) })
+    } else if attrs.is_lazy() {
+        // The `Lazy<T>` handle itself is stored, `T` is constructed on first
+        // access.
+        Ok(quote! { #ident })
+    } else if let Some(var) = attrs.env() {
+        // Validated to be fallible, a missing variable, or a parse error, is
+        // returned from the registered constructor.
+        let ty = attrs.ty();
+        let var = syn::LitStr::new(var.as_str(), var.span());
+        Ok(quote! { ::std::env::var(#var)?.parse::<#ty>()? })
+    } else if let Some(ctor) = attrs.ctor_try() {
+        // Validated to be fallible, the error is returned from the registered
+        // constructor.
+        let ctor = parse_ctor(ctor)?;
+        Ok(quote! { (#ctor)? })
+    } else if let Some(ctor) = attrs.ctor_async() {
+        if attrs.ctor().is_some() {
             return Err(syn::Error::new(
                 ctor.span(),
-                format!(
-                    "couldn't parse ctor expression: {err}\n\nTo \
-                         construct a string, you need to double quote it."
-                ),
+                "`ctor` and `ctor_async` are mutually exclusive",
             ));
-        };
+        }
 
-        let parsed = parsed.expect("error handled above");
-        Ok(quote! { #parsed })
+        if !cfg!(feature = "async") {
+            return Err(syn::Error::new(
+                ctor.span(),
+                "`ctor_async` requires an async runtime feature (`tokio` or \
+                 `async-std`)",
+            ));
+        }
+
+        // The expression is evaluated by the `DepCtor` marker, see
+        // `into_dependency_ctors`.
+        Ok(quote! { #ident.get() })
+    } else if let Some(ctor) = attrs.ctor() {
+        parse_ctor(ctor)
     } else {
-        // Always fall back to `Default::default()`.
-        Ok(quote! { Default::default() })
+        // Always fall back to `Default::default()`. The type is spelled
+        // out, inference can't always decide on one, e.g., for numbers.
+        let ty = attrs.ty();
+        Ok(quote! { <#ty as ::core::default::Default>::default() })
     }
 }
 
-fn registration_singleton(
-    input: &DeriveInput,
-    attrs: &DeriveAttrInput,
+/// Parse the expression, or block, of a `ctor` or `ctor_async` attribute.
+fn parse_ctor(
+    ctor: &SpannedValue<String>,
 ) -> syn::Result<proc_macro2::TokenStream> {
-    let fields_is_empty = attrs.fields().is_empty();
-    let registered_ty = attrs.singleton().expect("singleton attribute");
+    let parsed = syn::parse_str::<syn::Expr>(ctor);
+    if let Err(err) = parsed {
+        return Err(syn::Error::new(
+            ctor.span(),
+            format!(
+                "couldn't parse ctor expression: {err}; to construct a \
+                 string, you need to double quote it"
+            ),
+        ));
+    };
 
-    if fields_is_empty {
-        registration_empty(DependencyType::Singleton, &registered_ty)
-    } else {
-        registration_fields(
-            DependencyType::Singleton,
-            &registered_ty,
-            input,
-            attrs,
-        )
-    }
+    let parsed = parsed.expect("error handled above");
+    Ok(quote! { #parsed })
 }
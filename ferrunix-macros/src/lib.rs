@@ -48,10 +48,41 @@ mod utils;
 ///     - The object isn't constructed using member-wise construction, but it's
 ///       constructed using a custom constructor (e.g., `new`). The constructor
 ///       will be passed the members in order of declaration as parameters.
+/// - `post_construct = <IDENTIFIER>`
+///     - After construction, the method `<IDENTIFIER>` is called on the object.
+///       The method must either take `&mut self`, or take `self` and return
+///       `Self`.
+/// - `fallible`
+///     - The construction of the object can fail. The constructor returns a
+///       `Result<_, ferrunix::error::BoxErr>`, and the object is registered
+///       with `try_register_transient` (or `try_register_singleton`). A custom
+///       `ctor` must return a `Result`, its error is returned with `?`.
+/// - `newtype`
+///     - The type is a newtype wrapper around its single field, e.g.,
+///       `struct Port(u16)`, to register it distinctly from the inner type.
+///       The field is injected as a transient, unless it has an `inject`
+///       attribute, and `From` and `Deref` are implemented for the inner type.
 /// - `no_registration`
 ///     - The type isn't registered automatically and the generated
 ///       `Self::register(&ferrunix::Registry)` function needs to be called
 ///       manually to register the type.
+/// - `group = "<GROUP>"`
+///     - The type isn't registered in the global registry, but it's registered
+///       into any registry with `Registry::populate_group("<GROUP>")`.
+/// - `crate = "<PATH>"`
+///     - The generated code refers to `ferrunix` with `<PATH>`, instead of
+///       `::ferrunix`, e.g., when it's re-exported by a facade crate.
+///
+/// The `provides` attribute can be repeated, e.g., to register the type under
+/// multiple trait objects. Every provided type is registered separately.
+/// `transient` and `singleton` are mutually exclusive.
+///
+/// Type aliases, e.g., `type Port = u16;`, are registered as the aliased
+/// type, and collide with it. Use a `newtype` instead.
+///
+/// Generic types are only registered automatically if a concrete type is
+/// pinned with `transient = "MyType<u8>"` (or `singleton`, respectively),
+/// otherwise `no_registration` is required.
 ///
 /// ## `inject` Properties
 ///
@@ -59,10 +90,51 @@ mod utils;
 ///     - Construct the field using the `Default` implementation.
 /// - `ctor = "<RUST-CODE>"`
 ///     - Construct the field using the provided Rust code.
+/// - `ctor_async = "<RUST-CODE>"`
+///     - Same as `ctor`, except that the code may `.await`. Requires an
+///       async runtime feature (`tokio` or `async-std`). The code can refer
+///       to the registry the type is resolved from as `registry`, e.g.,
+///       `registry.get_transient::<u16>().await`. The code is evaluated
+///       while the dependencies are resolved.
+/// - `ctor_try = "<RUST-CODE>"`
+///     - Same as `ctor`, except that the code returns a `Result`, whose error
+///       is returned with `?`. Requires `fallible` on the type.
+/// - `env = "<VARIABLE>"`
+///     - Parse the field from the environment variable, using its `FromStr`
+///       implementation. A missing variable, or a parse error, is returned
+///       with `?`. Requires `fallible` on the type.
 /// - `transient [= true]`
 ///     - Construct the field as a transient by retrieving it from the `Registry`.
 /// - `singleton [= true]`
 ///     - Construct the field as a singleton by retrieving it from the `Registry`.
+///       The field must be of type `Ref<T>` (or `Arc<T>`, `Rc<T>`), e.g.,
+///       `Ref<MyConfig>`, or `Ref<dyn MyTrait>` for singletons provided as
+///       `dyn MyTrait`.
+/// - `optional`
+///     - Only valid together with `transient` or `singleton`, the field must be
+///       of type `Option<T>`, e.g., `Option<Ref<dyn MyTrait>>` for singletons.
+///       The field is `None` if `T` isn't registered, instead of failing the
+///       construction.
+/// - `lazy`
+///     - The field must be of type `Lazy<T>`, the transient `T` is only
+///       constructed on the first call to `Lazy::get`.
+/// - `all`
+///     - The field must be of type `Vec<T>`, it's constructed from every `T`
+///       registered with `Registry::register_all`, e.g.,
+///       `Vec<Box<dyn MyPlugin>>`. The field is empty if no `T` is
+///       registered.
+/// - `clone_singleton`
+///     - The field must be of the owned type `T`, e.g., `MyConfig`, which
+///       implements `Clone`. The singleton `T` is resolved, and the field is
+///       initialized with a clone of it.
+/// - `name = "<NAME>"`
+///     - Only valid together with `transient` or `singleton`, the field is
+///       resolved from the registration named `<NAME>`, e.g., registered with
+///       `Registry::transient_named("<NAME>", ...)`.
+/// - `default_variant`
+///     - Only valid on enum variants, exactly one variant must be marked. The
+///       enum is constructed as this variant, with its fields injected like
+///       the fields of a struct.
 ///
 /// ```rust,ignore,no_run
 /// # #![allow(unused)]
@@ -97,10 +169,23 @@ mod utils;
 ///     logger: Box<dyn Logger>,
 /// }
 ///
+/// #[derive(Inject)]
+/// #[provides(transient, no_registration)]
+/// pub enum MyMode {
+///     #[inject(default_variant)]
+///     // The enum is constructed as this variant.
+///     Verbose {
+///         #[inject(transient)]
+///         logger: Box<dyn Logger>,
+///     },
+///     Quiet,
+/// }
+///
 /// fn main() {
 ///     let registry = ferrunix::Registry::empty();
 ///     MyLogger::register(&registry);
 ///     MyConfig::register(&registry);
+///     MyMode::register(&registry);
 /// }
 /// ```
 ///
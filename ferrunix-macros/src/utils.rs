@@ -16,6 +16,7 @@ mod tests;
 pub(crate) fn get_ctor_for(
     ty: &syn::Type,
     inner: proc_macro2::TokenStream,
+    krate: &syn::Path,
 ) -> syn::Result<proc_macro2::TokenStream> {
     // eprintln!("get_ctor_for: {ty:?}");
     let span = ty.span();
@@ -26,23 +27,27 @@ pub(crate) fn get_ctor_for(
             let is_std_type = segments
                 .first()
                 .map_or_else(|| false, |seg| seg.ident == format_ident!("std"));
+            let our_crate = krate.segments.first().map(|seg| &seg.ident);
             let is_our_type = segments.first().map_or_else(
                 || false,
-                |seg| seg.ident == format_ident!("ferrunix"),
+                |seg| {
+                    seg.ident == format_ident!("ferrunix")
+                        || Some(&seg.ident) == our_crate
+                },
             );
 
             let supported_types = [
-                ("Box", "::std::boxed::Box", "new"),
-                ("Rc", "::std::rc::Rc", "new"),
-                ("Arc", "::std::sync::Arc", "new"),
-                ("RwLock", "::sync::RwLock", "new"),
-                ("Mutex", "::std::sync::Mutex", "new"),
-                ("Option", "::std::option::Option", "new"),
-                ("Result", "::std::result::Result", "new"),
-                ("Vec", "::std::vec::Vec", "new"),
-                ("Cell", "::std::cell::Cell", "new"),
-                ("RefCell", "::std::cell::RefCell", "new"),
-                ("Ref", "::ferrunix::Ref", "new"),
+                ("Box", quote!(::std::boxed::Box), "new"),
+                ("Rc", quote!(::std::rc::Rc), "new"),
+                ("Arc", quote!(::std::sync::Arc), "new"),
+                ("RwLock", quote!(::sync::RwLock), "new"),
+                ("Mutex", quote!(::std::sync::Mutex), "new"),
+                ("Option", quote!(::std::option::Option), "new"),
+                ("Result", quote!(::std::result::Result), "new"),
+                ("Vec", quote!(::std::vec::Vec), "new"),
+                ("Cell", quote!(::std::cell::Cell), "new"),
+                ("RefCell", quote!(::std::cell::RefCell), "new"),
+                ("Ref", quote!(#krate::Ref), "new"),
             ];
 
             let is_supported_type = |segment: &PathSegment| {
@@ -51,8 +56,6 @@ pub(crate) fn get_ctor_for(
                         segment.ident == format_ident!("{ident}")
                     })
                 {
-                    let fullname: syn::Type =
-                        syn::parse_str(fullname).expect("fullname to be valid");
                     let ctor = format_ident!("{ctor}");
                     return Some(quote! {
                         #fullname::#ctor(#inner)
@@ -90,10 +93,11 @@ pub(crate) enum TransformType {
 }
 
 #[allow(clippy::needless_pass_by_value)]
-pub(crate) fn transform_type(
-    ty: &'_ syn::Type,
+pub(crate) fn transform_type<'ty>(
+    ty: &'ty syn::Type,
     what: TransformType,
-) -> syn::Result<Cow<'_, syn::Type>> {
+    krate: &syn::Path,
+) -> syn::Result<Cow<'ty, syn::Type>> {
     let span = ty.span();
     match what {
         TransformType::Transient => match ty {
@@ -106,20 +110,63 @@ pub(crate) fn transform_type(
             _ => Ok(Cow::Borrowed(ty)),
         },
 
+        // Must match the key singleton fields are resolved with, see
+        // `singleton_dependency_type`.
         TransformType::Singleton => match ty {
-            syn::Type::Path(path) => {
-                let ret: syn::Type =
-                    syn::parse2(quote! { ::ferrunix::Ref<#path> })?;
-                Ok(Cow::Owned(ret))
-            }
-
+            // Trait objects are unsized, they're registered as
+            // `Ref<dyn Trait>`.
             syn::Type::TraitObject(obj) => {
-                let ret: syn::Type =
-                    syn::parse2(quote! { ::ferrunix::Ref<#obj> })?;
+                let ret: syn::Type = syn::parse2(quote! { #krate::Ref<#obj> })?;
                 Ok(Cow::Owned(ret))
             }
 
+            // The registry wraps singletons in a `Ref` itself, all other
+            // types, e.g., `Foo` or `Box<dyn Trait>`, are registered as is.
             _ => Ok(Cow::Borrowed(ty)),
         },
     }
 }
+
+/// Returns the inner type `T` of `ty`, if `ty` is an `Option<T>`.
+pub(crate) fn option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    generic_inner_type(ty, &["Option"])
+}
+
+/// Returns the inner type `T` of `ty`, if `ty` is a reference counted
+/// pointer, i.e., `Ref<T>`, `Arc<T>`, or `Rc<T>`.
+pub(crate) fn ref_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    generic_inner_type(ty, &["Ref", "Arc", "Rc"])
+}
+
+/// Returns the inner type `T` of `ty`, if `ty` is a `Lazy<T>`.
+pub(crate) fn lazy_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    generic_inner_type(ty, &["Lazy"])
+}
+
+/// Returns the inner type `T` of `ty`, if `ty` is a `Vec<T>`.
+pub(crate) fn vec_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    generic_inner_type(ty, &["Vec"])
+}
+
+/// Returns the first generic type argument `T` of `ty`, if `ty` is one of
+/// the types in `names`, e.g., `Option<T>`.
+fn generic_inner_type<'ty>(
+    ty: &'ty syn::Type,
+    names: &[&str],
+) -> Option<&'ty syn::Type> {
+    let syn::Type::Path(ref path) = *ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if !names.iter().any(|name| segment.ident == name) {
+        return None;
+    }
+
+    let syn::PathArguments::AngleBracketed(ref args) = segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        syn::GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
@@ -9,7 +9,8 @@ fn test_get_ctor_for() {
     let run_test = |ty: &str, result: &str| {
         let inner = quote! { Self {} };
         let parsed: syn::Type = syn::parse_str(ty).unwrap();
-        let ctor = get_ctor_for(&parsed, inner).unwrap();
+        let ctor =
+            get_ctor_for(&parsed, inner, &parse_quote!(::ferrunix)).unwrap();
         let result_from_test: syn::Expr = parse2(ctor).unwrap();
         let result_required: syn::Expr = parse_str(result).unwrap();
         assert_eq!(result_from_test, result_required);
@@ -29,7 +30,8 @@ fn test_get_ctor_for() {
 fn test_transform_type() {
     let run_test = |ty: &str, what: TransformType, result: &str| {
         let parsed: syn::Type = syn::parse_str(ty).unwrap();
-        let result_from_test = transform_type(&parsed, what).unwrap();
+        let result_from_test =
+            transform_type(&parsed, what, &parse_quote!(::ferrunix)).unwrap();
         let result_required: syn::Type = parse_str(result).unwrap();
         assert_eq!(*result_from_test, result_required, "test failed");
     };
@@ -61,11 +63,47 @@ fn test_transform_type() {
         TransformType::Singleton,
         "::ferrunix::Ref<dyn Foo + Send + Sync>",
     );
-    run_test("Foo", TransformType::Singleton, "::ferrunix::Ref<Foo>");
-    // TODO: Fix this case.
-    // run_test(
-    //     "::ferrunix::Ref<Foo>",
-    //     TransformType::Singleton,
-    //     "::ferrunix::Ref<Foo>",
-    // );
+    run_test("Foo", TransformType::Singleton, "Foo");
+    run_test(
+        "Box<dyn Foo + Send + Sync>",
+        TransformType::Singleton,
+        "Box<dyn Foo + Send + Sync>",
+    );
+    run_test(
+        "::ferrunix::Ref<Foo>",
+        TransformType::Singleton,
+        "::ferrunix::Ref<Foo>",
+    );
+}
+
+#[test]
+fn test_option_inner_type() {
+    let run_test = |ty: &str, result: Option<&str>| {
+        let parsed: syn::Type = syn::parse_str(ty).unwrap();
+        let result_required =
+            result.map(|result| syn::parse_str::<syn::Type>(result).unwrap());
+        assert_eq!(option_inner_type(&parsed), result_required.as_ref());
+    };
+
+    run_test("Option<u8>", Some("u8"));
+    run_test("::std::option::Option<Box<dyn Foo>>", Some("Box<dyn Foo>"));
+    run_test("Box<u8>", None);
+    run_test("u8", None);
+}
+
+#[test]
+fn test_ref_inner_type() {
+    let run_test = |ty: &str, result: Option<&str>| {
+        let parsed: syn::Type = syn::parse_str(ty).unwrap();
+        let result_required =
+            result.map(|result| syn::parse_str::<syn::Type>(result).unwrap());
+        assert_eq!(ref_inner_type(&parsed), result_required.as_ref());
+    };
+
+    run_test("Ref<u8>", Some("u8"));
+    run_test("::ferrunix::Ref<dyn Foo>", Some("dyn Foo"));
+    run_test("std::sync::Arc<Foo>", Some("Foo"));
+    run_test("Rc<Foo>", Some("Foo"));
+    run_test("Box<u8>", None);
+    run_test("u8", None);
 }
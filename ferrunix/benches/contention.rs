@@ -0,0 +1,265 @@
+//! Benchmarks the resolve throughput of a small dependency graph, and the
+//! contention of resolving a singleton from multiple threads.
+//!
+//! The contended benchmark runs with 1, 4, and 16 threads, and is only
+//! available with the `multithread` or `tokio` feature:
+//!
+//! Without any features, the same workload is additionally resolved from a
+//! [`LocalRegistry`], to compare it against the `RefCell` based [`Registry`].
+//!
+//! ```sh
+//! cargo bench -p ferrunix --bench contention
+//! cargo bench -p ferrunix --bench contention --features multithread
+//! cargo bench -p ferrunix --bench contention --features tokio
+//! ```
+#![allow(missing_docs, clippy::missing_docs_in_private_items)]
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+#[cfg(any(feature = "multithread", feature = "async"))]
+use criterion::{BenchmarkId, Throughput};
+#[cfg(not(any(feature = "multithread", feature = "async")))]
+use ferrunix::local::LocalRegistry;
+use ferrunix::{Registry, Singleton, Transient};
+
+/// The singleton configuration, shared by most types.
+#[derive(Debug, Clone, Copy)]
+struct Config;
+
+/// A transient without dependencies.
+#[derive(Debug, Clone, Copy)]
+struct Leaf;
+
+/// A transient depending on [`Leaf`] and [`Config`].
+#[derive(Debug, Clone, Copy)]
+struct Mid;
+
+/// A transient depending on [`Mid`] and [`Leaf`].
+#[derive(Debug, Clone, Copy)]
+struct Root;
+
+/// The singleton depending on [`Root`] and [`Config`], resolved by all
+/// threads.
+#[derive(Debug, Clone, Copy)]
+struct Service;
+
+/// Number of threads resolving [`Service`] concurrently.
+#[cfg(any(feature = "multithread", feature = "async"))]
+const THREADS: [usize; 3] = [1, 4, 16];
+
+#[cfg(not(feature = "async"))]
+fn registry() -> Registry {
+    let registry = Registry::empty();
+    registry.singleton(|| Config);
+    registry.transient(|| Leaf);
+    registry
+        .with_deps::<_, (Transient<Leaf>, Singleton<Config>)>()
+        .transient(|_| Mid);
+    registry
+        .with_deps::<_, (Transient<Mid>, Transient<Leaf>)>()
+        .transient(|_| Root);
+    registry
+        .with_deps::<_, (Transient<Root>, Singleton<Config>)>()
+        .singleton(|_| Service);
+    registry
+}
+
+#[cfg(feature = "async")]
+fn registry() -> Registry {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .expect("failed to build tokio runtime");
+
+    let registry = Registry::empty();
+    runtime.block_on(async {
+        registry.singleton(|| Box::pin(async move { Config })).await;
+        registry.transient(|| Box::pin(async move { Leaf })).await;
+        registry
+            .with_deps::<_, (Transient<Leaf>, Singleton<Config>)>()
+            .transient(|_| Box::pin(async move { Mid }))
+            .await;
+        registry
+            .with_deps::<_, (Transient<Mid>, Transient<Leaf>)>()
+            .transient(|_| Box::pin(async move { Root }))
+            .await;
+        registry
+            .with_deps::<_, (Transient<Root>, Singleton<Config>)>()
+            .singleton(|_| Box::pin(async move { Service }))
+            .await;
+    });
+    registry
+}
+
+#[cfg(not(feature = "async"))]
+fn resolve(crit: &mut Criterion) {
+    let registry = registry();
+
+    crit.bench_function("transient_graph", |bench| {
+        bench.iter(|| black_box(registry.get_transient::<Root>()));
+    });
+
+    // Construct the singleton once, only the cached lookup is measured.
+    black_box(registry.get_singleton::<Service>());
+    crit.bench_function("singleton_cached", |bench| {
+        bench.iter(|| black_box(registry.get_singleton::<Service>()));
+    });
+}
+
+/// The same types as [`registry`], registered on a [`LocalRegistry`].
+#[cfg(not(any(feature = "multithread", feature = "async")))]
+fn local_registry() -> LocalRegistry {
+    let mut registry = LocalRegistry::empty();
+    registry.singleton(|| Config);
+    registry.transient(|| Leaf);
+    registry.transient_with(|registry| {
+        registry.get_transient::<Leaf>()?;
+        registry.get_singleton::<Config>()?;
+        Some(Mid)
+    });
+    registry.transient_with(|registry| {
+        registry.get_transient::<Mid>()?;
+        registry.get_transient::<Leaf>()?;
+        Some(Root)
+    });
+    registry.singleton_with(|registry| {
+        registry.get_transient::<Root>()?;
+        registry.get_singleton::<Config>()?;
+        Some(Service)
+    });
+    registry
+}
+
+#[cfg(feature = "async")]
+fn resolve(crit: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .expect("failed to build tokio runtime");
+    let registry = registry();
+
+    crit.bench_function("transient_graph", |bench| {
+        bench.iter(|| {
+            runtime.block_on(async {
+                black_box(registry.get_transient::<Root>().await)
+            })
+        });
+    });
+
+    // Construct the singleton once, only the cached lookup is measured.
+    runtime.block_on(async {
+        black_box(registry.get_singleton::<Service>().await);
+    });
+    crit.bench_function("singleton_cached", |bench| {
+        bench.iter(|| {
+            runtime.block_on(async {
+                black_box(registry.get_singleton::<Service>().await)
+            })
+        });
+    });
+}
+
+/// Resolves the same workload as [`resolve`] from a [`LocalRegistry`].
+#[cfg(not(any(feature = "multithread", feature = "async")))]
+fn local(crit: &mut Criterion) {
+    let registry = local_registry();
+
+    crit.bench_function("local_transient_graph", |bench| {
+        bench.iter(|| black_box(registry.get_transient::<Root>()));
+    });
+
+    // Construct the singleton once, only the cached lookup is measured.
+    black_box(registry.get_singleton::<Service>());
+    crit.bench_function("local_singleton_cached", |bench| {
+        bench.iter(|| black_box(registry.get_singleton::<Service>()));
+    });
+}
+
+/// Every iteration resolves the cached singleton once on each thread.
+#[cfg(all(feature = "multithread", not(feature = "async")))]
+fn contention(crit: &mut Criterion) {
+    let registry = registry();
+    black_box(registry.get_singleton::<Service>());
+
+    let mut group = crit.benchmark_group("singleton_contended");
+    for threads in THREADS {
+        group.throughput(Throughput::Elements(threads as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(threads),
+            &threads,
+            |bench, &threads| {
+                bench.iter_custom(|iters| {
+                    let start = std::time::Instant::now();
+                    std::thread::scope(|scope| {
+                        for _ in 0..threads {
+                            scope.spawn(|| {
+                                for _ in 0..iters {
+                                    black_box(
+                                        registry.get_singleton::<Service>(),
+                                    );
+                                }
+                            });
+                        }
+                    });
+                    start.elapsed()
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+/// Every iteration resolves the cached singleton once on each task, with as
+/// many worker threads as tasks.
+#[cfg(feature = "async")]
+fn contention(crit: &mut Criterion) {
+    let registry = registry().into_ref();
+
+    let mut group = crit.benchmark_group("singleton_contended");
+    for threads in THREADS {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(threads)
+            .build()
+            .expect("failed to build tokio runtime");
+        runtime.block_on(async {
+            black_box(registry.get_singleton::<Service>().await);
+        });
+
+        group.throughput(Throughput::Elements(threads as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(threads),
+            &threads,
+            |bench, &threads| {
+                bench.iter_custom(|iters| {
+                    runtime.block_on(async {
+                        let start = std::time::Instant::now();
+                        let tasks = (0..threads)
+                            .map(|_| {
+                                let registry = ferrunix::Ref::clone(&registry);
+                                tokio::spawn(async move {
+                                    for _ in 0..iters {
+                                        black_box(
+                                            registry
+                                                .get_singleton::<Service>()
+                                                .await,
+                                        );
+                                    }
+                                })
+                            })
+                            .collect::<Vec<_>>();
+                        for task in tasks {
+                            task.await.expect("resolving task panicked");
+                        }
+                        start.elapsed()
+                    })
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+#[cfg(any(feature = "multithread", feature = "async"))]
+criterion_group!(benches, resolve, contention);
+#[cfg(not(any(feature = "multithread", feature = "async")))]
+criterion_group!(benches, resolve, local);
+criterion_main!(benches);
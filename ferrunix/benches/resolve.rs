@@ -0,0 +1,134 @@
+//! Benchmarks the resolve throughput of a registry with ~100 registered types.
+//!
+//! Compare the default hasher with the `fxhash` feature:
+//!
+//! ```sh
+//! cargo bench -p ferrunix --bench resolve -- --save-baseline default
+//! cargo bench -p ferrunix --bench resolve --features fxhash -- --baseline default
+//! ```
+#![allow(missing_docs, clippy::missing_docs_in_private_items)]
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use ferrunix::Registry;
+
+/// A distinct transient type for every `N`.
+#[derive(Debug, Clone, Copy)]
+struct Ty<const N: usize>;
+
+/// A distinct singleton type for every `N`.
+#[derive(Debug, Clone, Copy)]
+struct Single<const N: usize>;
+
+/// Invokes `$mac` with the numbers `0..100`.
+macro_rules! hundred_types {
+    ($mac:ident) => {
+        $mac!(
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25
+            26 27 28 29 30 31 32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+            48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63 64 65 66 67 68 69
+            70 71 72 73 74 75 76 77 78 79 80 81 82 83 84 85 86 87 88 89 90 91
+            92 93 94 95 96 97 98 99
+        )
+    };
+}
+
+#[cfg(not(feature = "async"))]
+fn resolve(crit: &mut Criterion) {
+    let registry = Registry::empty();
+    macro_rules! register {
+        ($($num:literal)*) => {
+            $(
+                registry.transient(|| Ty::<$num>);
+                registry.singleton(|| Single::<$num>);
+            )*
+        };
+    }
+    hundred_types!(register);
+
+    crit.bench_function("transient_100", |bench| {
+        macro_rules! get_transient {
+            ($($num:literal)*) => {
+                $( black_box(registry.get_transient::<Ty<$num>>()); )*
+            };
+        }
+        bench.iter(|| {
+            hundred_types!(get_transient);
+        });
+    });
+
+    crit.bench_function("singleton_100", |bench| {
+        macro_rules! get_singleton {
+            ($($num:literal)*) => {
+                $(
+                    black_box(
+                        registry.get_singleton::<Single<$num>>()
+                    );
+                )*
+            };
+        }
+        bench.iter(|| {
+            hundred_types!(get_singleton);
+        });
+    });
+}
+
+#[cfg(feature = "async")]
+fn resolve(crit: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .expect("failed to build tokio runtime");
+
+    let registry = Registry::empty();
+    macro_rules! register {
+        ($($num:literal)*) => {
+            $(
+                registry
+                    .transient(|| Box::pin(async move { Ty::<$num> }))
+                    .await;
+                registry
+                    .singleton(|| {
+                        Box::pin(async move { Single::<$num> })
+                    })
+                    .await;
+            )*
+        };
+    }
+    runtime.block_on(async {
+        hundred_types!(register);
+    });
+
+    crit.bench_function("transient_100", |bench| {
+        macro_rules! get_transient {
+            ($($num:literal)*) => {
+                $( black_box(registry.get_transient::<Ty<$num>>().await); )*
+            };
+        }
+        bench.iter(|| {
+            runtime.block_on(async {
+                hundred_types!(get_transient);
+            });
+        });
+    });
+
+    crit.bench_function("singleton_100", |bench| {
+        macro_rules! get_singleton {
+            ($($num:literal)*) => {
+                $(
+                    black_box(
+                        registry.get_singleton::<Single<$num>>().await
+                    );
+                )*
+            };
+        }
+        bench.iter(|| {
+            runtime.block_on(async {
+                hundred_types!(get_singleton);
+            });
+        });
+    });
+}
+
+criterion_group!(benches, resolve);
+criterion_main!(benches);
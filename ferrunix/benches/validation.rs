@@ -0,0 +1,101 @@
+//! Benchmarks repeated validations of a dependency graph with ~200 registered
+//! types.
+//!
+//! ```sh
+//! cargo bench -p ferrunix --bench validation
+//! ```
+#![allow(missing_docs, clippy::missing_docs_in_private_items)]
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use ferrunix::{Registry, Singleton, Transient};
+
+/// The singleton all other types depend on.
+#[derive(Debug, Clone, Copy)]
+struct Base;
+
+/// A distinct transient type for every `N`, depending on [`Base`].
+#[derive(Debug, Clone, Copy)]
+struct Ty<const N: usize>;
+
+/// A distinct singleton type for every `N`, depending on [`Ty<N>`] and
+/// [`Base`].
+#[derive(Debug, Clone, Copy)]
+struct Single<const N: usize>;
+
+/// Invokes `$mac` with the numbers `0..100`.
+macro_rules! hundred_types {
+    ($mac:ident) => {
+        $mac!(
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25
+            26 27 28 29 30 31 32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+            48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63 64 65 66 67 68 69
+            70 71 72 73 74 75 76 77 78 79 80 81 82 83 84 85 86 87 88 89 90 91
+            92 93 94 95 96 97 98 99
+        )
+    };
+}
+
+#[cfg(not(feature = "async"))]
+fn registry() -> Registry {
+    let registry = Registry::empty();
+    registry.singleton(|| Base);
+    macro_rules! register {
+        ($($num:literal)*) => {
+            $(
+                registry
+                    .with_deps::<_, (Singleton<Base>,)>()
+                    .transient(|_| Ty::<$num>);
+                registry
+                    .with_deps::<_, (Transient<Ty<$num>>, Singleton<Base>)>()
+                    .singleton(|_| Single::<$num>);
+            )*
+        };
+    }
+    hundred_types!(register);
+    registry
+}
+
+#[cfg(feature = "async")]
+fn registry() -> Registry {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .expect("failed to build tokio runtime");
+
+    let registry = Registry::empty();
+    macro_rules! register {
+        ($($num:literal)*) => {
+            $(
+                registry
+                    .with_deps::<_, (Singleton<Base>,)>()
+                    .transient(|_| Box::pin(async move { Ty::<$num> }))
+                    .await;
+                registry
+                    .with_deps::<_, (Transient<Ty<$num>>, Singleton<Base>)>()
+                    .singleton(|_| Box::pin(async move { Single::<$num> }))
+                    .await;
+            )*
+        };
+    }
+    runtime.block_on(async {
+        registry.singleton(|| Box::pin(async move { Base })).await;
+        hundred_types!(register);
+    });
+    registry
+}
+
+fn validation(crit: &mut Criterion) {
+    let registry = registry();
+
+    crit.bench_function("validate_all_200", |bench| {
+        bench.iter(|| black_box(registry.validate_all()));
+    });
+
+    crit.bench_function("validate_all_full_200", |bench| {
+        bench.iter(|| black_box(registry.validate_all_full()));
+    });
+}
+
+criterion_group!(benches, validation);
+criterion_main!(benches);
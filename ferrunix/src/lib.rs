@@ -7,7 +7,11 @@
     doc = "_You're viewing the documentation with the **`tokio` feature** turned on._\n\n"
 )]
 #![cfg_attr(
-    all(not(feature = "tokio"), not(feature = "multithread")),
+    all(feature = "async-std", not(feature = "tokio")),
+    doc = "_You're viewing the documentation with the **`async-std` feature** turned on._\n\n"
+)]
+#![cfg_attr(
+    all(not(feature = "async"), not(feature = "multithread")),
     doc = "#### _You're viewing the documentation with **`no features`** turned on._\n\n"
 )]
 //! # Ferrunix
@@ -46,23 +50,42 @@
 //!
 //! - `multithread`: Enables support for accessing the registry from multiple
 //!     threads. This adds a bound that all registered types must be `Send`.
+//! - `ref-arc`: Uses [`std::sync::Arc`] for [`Ref`] without `multithread`,
+//!     so singletons can be sent to other threads, while the registry
+//!     itself stays single-threaded. This adds a bound that all singletons
+//!     must be `Send + Sync`.
 //! - `derive` (`*`): Enables support for the `#[derive(Inject)]` macro.
 //! - `tokio`: Enables support for `async` constructors. Bumps the MSRV up to
 //!     `1.75.0` because some of the internal traits require [RPITIT].
+//! - `async-std`: Like `tokio`, but uses [async-std] instead. If both are
+//!     enabled, `tokio` is used.
 //! - `tracing`: Enables support for [tracing] and annotates all public functions with
 //!     [`tracing::instrument`].
+//! - `fxhash`: Hashes the registered types with the faster, non-cryptographic
+//!     `FxHasher` instead of the default hasher.
+//! - `metrics`: Enables reporting how often types are resolved, and how long
+//!     their construction takes, to a sink set with
+//!     `Registry::set_metrics_sink`.
 //!
 //! [dependency injection]: https://en.wikipedia.org/wiki/Dependency_injection
 //! [docs.rs]: https://docs.rs/ferrunix
 //! [user guide]: https://leandros.github.io/ferrunix/user-guide/first-steps.html
 //! [RPITIT]: https://blog.rust-lang.org/2023/12/21/async-fn-rpit-in-traits.html#whats-stabilizing
+//! [async-std]: https://docs.rs/async-std/latest/async_std/index.html
 //! [features]: https://doc.rust-lang.org/cargo/reference/manifest.html#the-features-section
 //! [tracing]: https://docs.rs/tracing/latest/tracing/index.html
 //! [`tracing::instrument`]: https://docs.rs/tracing/latest/tracing/attr.instrument.html
 
+pub use ferrunix_core::cycle_detection;
 pub use ferrunix_core::dependencies;
 pub use ferrunix_core::dependency_builder;
+pub use ferrunix_core::error;
+#[cfg(all(not(feature = "multithread"), not(feature = "async")))]
+pub use ferrunix_core::local;
+#[cfg(feature = "metrics")]
+pub use ferrunix_core::metrics;
 pub use ferrunix_core::registry;
+pub use ferrunix_core::scope;
 pub use ferrunix_core::types;
 
 pub use dependencies::Singleton;
@@ -75,6 +98,8 @@ pub use ferrunix_macros::Inject;
 /// Register a [`RegistrationFunc`]. Usually invoked by the derive macro.
 ///
 pub use ferrunix_core::registration::autoregister;
+#[doc(hidden)]
+pub use ferrunix_core::registration::post_construct;
 pub use ferrunix_core::registration::RegistrationFunc;
 
 pub use ferrunix_core::types::Ref;
@@ -0,0 +1,303 @@
+use ferrunix::{Ref, Registry, Singleton, Transient};
+
+#[test]
+fn transient_override() {
+    let parent = Ref::new(Registry::empty());
+    parent.transient(|| 1_u8);
+
+    let child = parent.child();
+    child.transient(|| 2_u8);
+
+    assert_eq!(child.get_transient::<u8>(), Some(2));
+    assert_eq!(parent.get_transient::<u8>(), Some(1));
+}
+
+#[test]
+fn singleton_override() {
+    let parent = Ref::new(Registry::empty());
+    parent.singleton(|| String::from("parent"));
+    // The parent's singleton is constructed, and cached, before the child
+    // registers its own singleton.
+    let from_parent = parent.get_singleton::<String>().unwrap();
+
+    let child = parent.child();
+    assert!(Ref::ptr_eq(
+        &child.get_singleton::<String>().unwrap(),
+        &from_parent
+    ));
+
+    child.singleton(|| String::from("child"));
+    let from_child = child.get_singleton::<String>().unwrap();
+    assert_eq!(*from_child, "child");
+    assert!(!Ref::ptr_eq(&from_child, &from_parent));
+    assert_eq!(*parent.get_singleton::<String>().unwrap(), "parent");
+}
+
+#[test]
+fn get_or_register_singleton_from_parent() {
+    let parent = Ref::new(Registry::empty());
+    parent.singleton(|| String::from("parent"));
+
+    let child = parent.child();
+    let from_child = child.get_or_register_singleton(|| String::from("child"));
+    assert_eq!(*from_child, "parent");
+    assert!(Ref::ptr_eq(
+        &from_child,
+        &parent.get_singleton::<String>().unwrap()
+    ));
+    assert!(!child.contains_local::<String>());
+
+    parent.freeze();
+    let child = parent.child();
+    child.freeze();
+    let from_frozen =
+        child.get_or_register_singleton(|| String::from("frozen"));
+    assert!(Ref::ptr_eq(&from_child, &from_frozen));
+}
+
+#[test]
+fn parent_only() {
+    let parent = Ref::new(Registry::empty());
+    parent.transient(|| 1_u8);
+    parent.singleton(|| 2_u32);
+
+    let child = parent.child();
+    child
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| u16::from(num.get()) + 1);
+    child.validate_all().unwrap();
+    child.validate_all_full().unwrap();
+
+    assert!(child.contains::<u8>());
+    assert!(!child.contains_local::<u8>());
+    assert_eq!(child.get_transient::<u8>(), Some(1));
+    assert_eq!(child.get_transient::<u16>(), Some(2));
+    assert!(Ref::ptr_eq(
+        &child.get_singleton::<u32>().unwrap(),
+        &parent.get_singleton::<u32>().unwrap()
+    ));
+    assert!(parent.get_transient::<u16>().is_none());
+}
+
+#[test]
+fn override_dependency() {
+    let parent = Ref::new(Registry::empty());
+    parent.transient(|| 1_u8);
+    parent
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| u16::from(num.get()));
+
+    let child = parent.child();
+    child.transient(|| 2_u8);
+    child
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| u32::from(num.get()));
+
+    // Types registered on the child use the dependencies of the child.
+    assert_eq!(child.get_transient::<u32>(), Some(2));
+    // Types registered on the parent always use the dependencies of the
+    // parent.
+    assert_eq!(child.get_transient::<u16>(), Some(1));
+}
+
+#[test]
+fn missing_in_child_and_parent() {
+    let parent = Ref::new(Registry::empty());
+    let child = parent.child();
+    child
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| u16::from(num.get()));
+
+    child.validate_all().unwrap_err();
+    assert!(child.get_transient::<u16>().is_none());
+
+    // Registering the dependency on the parent fulfills it for the child.
+    parent.transient(|| 1_u8);
+    child.validate_all().unwrap();
+    assert_eq!(child.get_transient::<u16>(), Some(1));
+}
+
+#[test]
+fn three_levels() {
+    let root = Registry::root();
+    root.transient(|| 1_u8);
+    root.singleton(|| 1_u16);
+
+    let child = root.child();
+    child.transient(|| 2_u8);
+    child
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| u32::from(num.get()));
+
+    let grandchild = child.child();
+    grandchild.transient(|| 3_u8);
+    grandchild
+        .with_deps::<_, (Transient<u8>, Singleton<u16>)>()
+        .transient(|(num, single)| {
+            u64::from(num.get()) + u64::from(*single.get())
+        });
+    grandchild.validate_all().unwrap();
+
+    assert_eq!(grandchild.get_transient::<u8>(), Some(3));
+    assert_eq!(grandchild.get_transient::<u32>(), Some(2));
+    assert_eq!(grandchild.get_transient::<u64>(), Some(4));
+    assert!(Ref::ptr_eq(
+        &grandchild.get_singleton::<u16>().unwrap(),
+        &root.get_singleton::<u16>().unwrap()
+    ));
+    assert!(child.get_transient::<u64>().is_none());
+}
+
+#[test]
+fn resolve_source() {
+    use ferrunix::registry::ResolveSource;
+
+    let root = Registry::root();
+    root.transient(|| 1_u8);
+    root.singleton(|| 1_u16);
+
+    let child = root.child();
+    child.transient(|| 2_u8);
+    let grandchild = child.child();
+    grandchild.singleton(|| 3_u32);
+
+    assert_eq!(
+        grandchild.resolve_source::<u32>(),
+        Some(ResolveSource::Local)
+    );
+    assert_eq!(
+        grandchild.resolve_source::<u8>(),
+        Some(ResolveSource::Parent(1))
+    );
+    assert_eq!(
+        grandchild.resolve_source::<u16>(),
+        Some(ResolveSource::Parent(2))
+    );
+    assert_eq!(grandchild.resolve_source::<u64>(), None);
+    assert_eq!(child.resolve_source::<u8>(), Some(ResolveSource::Local));
+    assert_eq!(root.resolve_source::<u32>(), None);
+}
+
+#[test]
+fn parent_dropped() {
+    use ferrunix::error::ResolveError;
+
+    let parent = Ref::new(Registry::empty());
+    parent.transient(|| 1_u8);
+    parent.singleton(|| 2_u32);
+
+    let child = parent.child();
+    assert_eq!(child.get_transient::<u8>(), Some(1));
+    drop(parent);
+
+    let err = child.try_get_transient::<u8>().unwrap_err();
+    assert!(matches!(
+        err,
+        ResolveError::DependencyChain { ref source, .. }
+            if matches!(**source, ResolveError::ParentDropped)
+    ));
+    let err = child.try_get_singleton::<u32>().unwrap_err();
+    assert!(matches!(
+        err,
+        ResolveError::DependencyChain { ref source, .. }
+            if matches!(**source, ResolveError::ParentDropped)
+    ));
+
+    // Types missing without any parent are still reported as missing.
+    let err = Registry::empty().try_get_transient::<u8>().unwrap_err();
+    assert!(err.is_missing());
+}
+
+#[test]
+fn registered_types_recursive() {
+    let parent = Ref::new(Registry::empty());
+    parent.transient(|| 1_u8);
+    parent.transient(|| 1_u16);
+
+    let child = parent.child();
+    child.transient(|| 2_u8);
+    child.transient(|| 2_u32);
+
+    let mut local = child.registered_types();
+    local.sort_by_key(|(_, name)| *name);
+    assert_eq!(
+        local,
+        vec![
+            (std::any::TypeId::of::<u32>(), "u32"),
+            (std::any::TypeId::of::<u8>(), "u8"),
+        ]
+    );
+
+    let mut types = child.registered_types_recursive();
+    types.sort_by_key(|(_, name)| *name);
+    assert_eq!(
+        types,
+        vec![
+            (std::any::TypeId::of::<u16>(), "u16"),
+            (std::any::TypeId::of::<u32>(), "u32"),
+            (std::any::TypeId::of::<u8>(), "u8"),
+        ]
+    );
+    assert_eq!(parent.registered_types_recursive().len(), 2);
+}
+
+#[test]
+fn validate_parent_only() {
+    use ferrunix::cycle_detection::ValidationError;
+
+    struct ParentOnly;
+
+    let parent = Ref::new(Registry::empty());
+    parent.transient(|| 1_u8);
+    parent
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|_| ParentOnly);
+    parent
+        .with_deps::<_, (Transient<u64>,)>()
+        .transient(|_| 1_u32);
+
+    let child = parent.child();
+    child.validate::<ParentOnly>().unwrap();
+    assert!(child.get_transient::<ParentOnly>().is_some());
+
+    // The parent's registration is missing dependencies.
+    assert_eq!(
+        child.validate::<u32>(),
+        Err(ValidationError::Missing(Some("u64")))
+    );
+
+    let grandchild = child.child();
+    grandchild.validate::<ParentOnly>().unwrap();
+    assert_eq!(
+        grandchild.validate::<u16>(),
+        Err(ValidationError::Missing(Some("u16")))
+    );
+}
+
+#[test]
+fn validate_after_parent_registration() {
+    struct Dependent;
+
+    let parent = Ref::new(Registry::empty());
+    let child = parent.child();
+    let grandchild = child.child();
+    grandchild
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|_| Dependent);
+
+    assert!(grandchild.validate::<Dependent>().is_err());
+    assert!(grandchild.validate_all().is_err());
+    assert!(!grandchild.is_validated());
+
+    child.validate_all().unwrap();
+    assert!(child.is_validated());
+
+    // Registering on the parent invalidates the cached results of the
+    // child registries.
+    parent.transient(|| 1_u8);
+    assert!(!child.is_validated());
+    grandchild.validate::<Dependent>().unwrap();
+    grandchild.validate_all().unwrap();
+    assert!(grandchild.is_validated());
+    assert!(grandchild.get_transient::<Dependent>().is_some());
+}
@@ -0,0 +1,149 @@
+use ferrunix::{Ref, Registry, Transient};
+
+#[tokio::test]
+async fn transient_override() {
+    let parent = Registry::root();
+    parent.transient(|| Box::pin(async move { 1_u8 })).await;
+
+    let child = parent.child();
+    child.transient(|| Box::pin(async move { 2_u8 })).await;
+
+    assert_eq!(child.get_transient::<u8>().await, Some(2));
+    assert_eq!(parent.get_transient::<u8>().await, Some(1));
+}
+
+#[tokio::test]
+async fn get_or_register_singleton_from_parent() {
+    let parent = Registry::root();
+    parent
+        .singleton(|| Box::pin(async move { String::from("parent") }))
+        .await;
+
+    let child = parent.child();
+    let from_child = child
+        .get_or_register_singleton(|| {
+            Box::pin(async move { String::from("child") })
+        })
+        .await;
+    assert_eq!(*from_child, "parent");
+    assert!(Ref::ptr_eq(
+        &from_child,
+        &parent.get_singleton::<String>().await.unwrap()
+    ));
+    assert!(!child.contains_local::<String>().await);
+}
+
+#[tokio::test]
+async fn parent_only() {
+    let parent = Registry::root();
+    parent.transient(|| Box::pin(async move { 1_u8 })).await;
+    parent
+        .singleton(|| Box::pin(async move { String::from("parent") }))
+        .await;
+
+    let child = parent.child();
+    child
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| Box::pin(async move { u16::from(num.get()) + 1 }))
+        .await;
+    child.validate_all().unwrap();
+
+    let from_child = child.get_singleton::<String>().await.unwrap();
+    tokio::task::yield_now().await;
+    let from_parent = parent.get_singleton::<String>().await.unwrap();
+    assert!(Ref::ptr_eq(&from_child, &from_parent));
+    assert_eq!(child.get_transient::<u16>().await, Some(2));
+    assert!(parent.get_transient::<u16>().await.is_none());
+}
+
+#[tokio::test]
+async fn override_dependency() {
+    let parent = Registry::root();
+    parent.transient(|| Box::pin(async move { 1_u8 })).await;
+    parent
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| Box::pin(async move { u16::from(num.get()) }))
+        .await;
+
+    let child = parent.child();
+    child.transient(|| Box::pin(async move { 2_u8 })).await;
+    child
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| Box::pin(async move { u32::from(num.get()) }))
+        .await;
+
+    // Types registered on the child use the dependencies of the child, types
+    // registered on the parent always use the dependencies of the parent.
+    assert_eq!(child.get_transient::<u32>().await, Some(2));
+    assert_eq!(child.get_transient::<u16>().await, Some(1));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn spawned_tasks() {
+    let parent = Registry::root();
+    parent
+        .singleton(|| Box::pin(async move { String::from("shared") }))
+        .await;
+    parent.transient(|| Box::pin(async move { 0_u32 })).await;
+    let shared = parent.get_singleton::<String>().await.unwrap();
+
+    let handles = (1..=8_u32)
+        .map(|request| {
+            // Every task overrides the request-scoped value on its own child.
+            let child = parent.child();
+            tokio::spawn(async move {
+                child.transient(|| Box::pin(async move { 1_u32 })).await;
+                tokio::task::yield_now().await;
+                let value = child.get_transient::<u32>().await.unwrap();
+                let singleton = child.get_singleton::<String>().await.unwrap();
+                (request * value, singleton)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for (request, handle) in (1..=8_u32).zip(handles) {
+        let (value, singleton) = handle.await.unwrap();
+        assert_eq!(value, request);
+        assert!(Ref::ptr_eq(&singleton, &shared));
+    }
+    assert_eq!(parent.get_transient::<u32>().await, Some(0));
+}
+
+#[tokio::test]
+async fn parent_dropped() {
+    use ferrunix::error::ResolveError;
+
+    let parent = Registry::root();
+    parent.transient(|| Box::pin(async move { 1_u8 })).await;
+
+    let child = parent.child();
+    assert_eq!(child.get_transient::<u8>().await, Some(1));
+    drop(parent);
+
+    let err = child.try_get_transient::<u8>().await.unwrap_err();
+    assert!(matches!(
+        err,
+        ResolveError::DependencyChain { ref source, .. }
+            if matches!(**source, ResolveError::ParentDropped)
+    ));
+}
+
+#[tokio::test]
+async fn registered_types_recursive() {
+    let parent = Registry::root();
+    parent.transient(|| Box::pin(async move { 1_u8 })).await;
+    parent.transient(|| Box::pin(async move { 1_u16 })).await;
+
+    let child = parent.child();
+    child.transient(|| Box::pin(async move { 2_u8 })).await;
+
+    let mut types = child.registered_types_recursive().await;
+    types.sort_by_key(|(_, name)| *name);
+    assert_eq!(
+        types,
+        vec![
+            (std::any::TypeId::of::<u16>(), "u16"),
+            (std::any::TypeId::of::<u8>(), "u8"),
+        ]
+    );
+}
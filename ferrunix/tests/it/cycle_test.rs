@@ -1,4 +1,4 @@
-#![cfg(not(feature = "tokio"))]
+#![cfg(not(feature = "async"))]
 #![allow(unused)]
 
 use ferrunix::{Registry, Transient};
@@ -77,6 +77,49 @@ fn detect_cycle() {
     assert!(registry.validate_all_full().is_err());
 }
 
+#[test]
+fn detect_cycle_path() {
+    use broken::*;
+    use ferrunix::cycle_detection::FullValidationError;
+
+    let registry = Registry::empty();
+    registry
+        .with_deps::<_, (Transient<Dep1>,)>()
+        .transient(|(dep1,)| Dep0 {
+            dep1: Box::new(dep1.get()),
+        });
+
+    registry
+        .with_deps::<_, (Transient<Dep2>,)>()
+        .transient(|(dep2,)| Dep1 {
+            dep2: Box::new(dep2.get()),
+        });
+
+    registry
+        .with_deps::<_, (Transient<Dep0>,)>()
+        .transient(|(dep0,)| Dep2 {
+            dep0: Box::new(dep0.get()),
+        });
+
+    let (dep0, dep1, dep2) = (
+        std::any::type_name::<Dep0>(),
+        std::any::type_name::<Dep1>(),
+        std::any::type_name::<Dep2>(),
+    );
+    let rotations = [
+        format!("cycle detected: {dep0} -> {dep1} -> {dep2} -> {dep0}"),
+        format!("cycle detected: {dep1} -> {dep2} -> {dep0} -> {dep1}"),
+        format!("cycle detected: {dep2} -> {dep0} -> {dep1} -> {dep2}"),
+    ];
+
+    let err = registry.validate_all_full().unwrap_err();
+    assert!(
+        matches!(err, FullValidationError::Cycle(ref path) if path.len() == 4)
+    );
+    let message = err.to_string();
+    assert!(rotations.contains(&message), "{message}");
+}
+
 #[test]
 fn detect_missing() {
     use broken::*;
@@ -140,3 +183,159 @@ fn all_fine() {
     registry.validate_all().unwrap();
     registry.validate_all_full().unwrap();
 }
+
+#[test]
+fn validate_only_reachable() {
+    let registry = Registry::empty();
+    registry
+        .with_deps::<_, (Transient<fine::Dep0>,)>()
+        .transient(|(dep0,)| fine::TypeZero {
+            dep0: Box::new(dep0.get()),
+        });
+    registry
+        .with_deps::<_, (Transient<fine::Dep1>,)>()
+        .transient(|(dep1,)| fine::Dep0 {
+            dep1: Box::new(dep1.get()),
+        });
+    registry
+        .with_deps::<_, (Transient<fine::Dep2>,)>()
+        .transient(|(dep2,)| fine::Dep1 {
+            dep2: Box::new(dep2.get()),
+        });
+    registry.transient(|| fine::Dep2 {});
+
+    // Unrelated cycle.
+    registry
+        .with_deps::<_, (Transient<broken::Dep1>,)>()
+        .transient(|(dep1,)| broken::Dep0 {
+            dep1: Box::new(dep1.get()),
+        });
+    registry
+        .with_deps::<_, (Transient<broken::Dep2>,)>()
+        .transient(|(dep2,)| broken::Dep1 {
+            dep2: Box::new(dep2.get()),
+        });
+    registry
+        .with_deps::<_, (Transient<broken::Dep0>,)>()
+        .transient(|(dep0,)| broken::Dep2 {
+            dep0: Box::new(dep0.get()),
+        });
+
+    // Unrelated missing dependency.
+    registry
+        .with_deps::<_, (Transient<broken::DepMissing>,)>()
+        .transient(|(dep_missing,)| broken::Dep3 {
+            dep_missing: Box::new(dep_missing.get()),
+        });
+
+    registry.validate::<fine::TypeZero>().unwrap();
+    registry.validate::<fine::Dep2>().unwrap();
+    assert!(registry.get_transient::<fine::TypeZero>().is_some());
+
+    assert!(registry.validate::<broken::Dep0>().is_err());
+    assert!(registry.validate::<broken::Dep3>().is_err());
+    assert!(registry.validate::<broken::DepMissing>().is_err());
+    assert!(registry.validate_all().is_err());
+}
+
+#[test]
+fn construct_missing_dependencies() {
+    use ferrunix::cycle_detection::{FullValidationError, MissingDependencies};
+    use std::any::{type_name, TypeId};
+
+    let missing = MissingDependencies::new(
+        (TypeId::of::<u16>(), type_name::<u16>()),
+        vec![(TypeId::of::<u8>(), type_name::<u8>())],
+    );
+    assert_eq!(missing.ty().1, "u16");
+    assert_eq!(
+        missing.missing_dependencies(),
+        &[(TypeId::of::<u8>(), "u8")]
+    );
+
+    let registry = Registry::empty();
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| u16::from(num.get()));
+    assert_eq!(
+        registry.validate_all_full(),
+        Err(FullValidationError::Missing(vec![missing]))
+    );
+}
+
+#[test]
+fn validate_all_missing_name() {
+    use ferrunix::cycle_detection::ValidationError;
+
+    let registry = Registry::empty();
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| u16::from(num.get()));
+
+    assert_eq!(
+        registry.validate_all(),
+        Err(ValidationError::Missing(Some("u8")))
+    );
+    assert_eq!(
+        registry.validate::<u16>(),
+        Err(ValidationError::Missing(Some("u8")))
+    );
+    assert_eq!(
+        registry.validate::<u32>(),
+        Err(ValidationError::Missing(Some("u32")))
+    );
+}
+
+#[test]
+fn singleton_depending_on_transient() {
+    use ferrunix::Singleton;
+    use std::any::TypeId;
+
+    let registry = Registry::empty();
+    registry.transient(|| 1_u8);
+    registry.singleton(|| 2_u16);
+    registry
+        .with_deps::<_, (Transient<u8>, Singleton<u16>)>()
+        .singleton(|(a, b)| u32::from(a.get()) + u32::from(*b.get()));
+    registry
+        .with_deps::<_, (Singleton<u32>,)>()
+        .transient(|(num,)| u64::from(*num.get()));
+    registry
+        .with_deps::<_, (Transient<u64>,)>()
+        .transient(|(num,)| num.get().to_string());
+
+    let warnings = registry.validate_lifetimes();
+    let messages = warnings.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        ["singleton u32 depends on transient u8, it's only constructed once"]
+    );
+    assert!(warnings.iter().all(|warning| {
+        warning.singleton() == &(TypeId::of::<u32>(), "u32")
+            && warning.transient() == &(TypeId::of::<u8>(), "u8")
+    }));
+    assert!(registry.validate_all().is_ok());
+}
+
+#[test]
+fn is_validated() {
+    use broken::*;
+
+    let registry = Registry::empty();
+    assert!(!registry.is_validated());
+    registry.validate_all().unwrap();
+    assert!(registry.is_validated());
+
+    registry.transient(|| DepMissing {});
+    assert!(!registry.is_validated());
+    registry.validate_all().unwrap();
+    assert!(registry.is_validated());
+
+    registry
+        .with_deps::<_, (Transient<Dep1>,)>()
+        .transient(|(dep1,)| Dep0 {
+            dep1: Box::new(dep1.get()),
+        });
+    assert!(registry.validate_all().is_err());
+    assert!(!registry.is_validated());
+}
@@ -79,3 +79,50 @@ async fn simple_derive() {
 
     // let _obj = registry.get_transient::<Empty>().await.unwrap();
 }
+
+async fn fetch_port() -> u16 {
+    tokio::task::yield_now().await;
+    8080
+}
+
+#[derive(Inject)]
+#[provides(transient, no_registration)]
+struct AsyncConfig {
+    #[inject(ctor_async = "fetch_port().await")]
+    port: u16,
+    #[inject(ctor_async = "{ let port = fetch_port().await; port + 1 }")]
+    admin_port: u16,
+}
+
+#[tokio::test]
+async fn ctor_async_derive() {
+    let registry = Registry::empty();
+    AsyncConfig::register(&registry).await;
+
+    let config = registry.get_transient::<AsyncConfig>().await.unwrap();
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.admin_port, 8081);
+}
+
+#[derive(Inject)]
+#[provides(transient, no_registration)]
+struct RegistryConfig {
+    #[inject(
+        ctor_async = "registry.get_transient::<u16>().await.unwrap_or_default()"
+    )]
+    port: u16,
+}
+
+#[tokio::test]
+async fn ctor_async_with_registry() {
+    let registry = Registry::empty();
+    registry
+        .transient(|| Box::pin(async move { 9090_u16 }))
+        .await;
+    RegistryConfig::register(&registry).await;
+    registry.validate_all().unwrap();
+
+    // The registry doesn't need to be ref-counted.
+    let config = registry.get_transient::<RegistryConfig>().await.unwrap();
+    assert_eq!(config.port, 9090);
+}
@@ -0,0 +1,14 @@
+//! Pins the compiler output of `#[derive(Inject)]` for misuse.
+//!
+//! Only errors emitted by the derive macro itself are pinned. Errors of
+//! unsatisfied trait bounds, e.g., a `singleton` that isn't `Send` with the
+//! `multithread` feature, are reported by rustc, and their output differs
+//! between the toolchains we test against.
+//!
+//! Run with `TRYBUILD=overwrite` to update the expected output.
+
+#[test]
+fn derive_compile_fail() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
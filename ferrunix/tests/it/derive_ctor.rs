@@ -35,7 +35,7 @@ impl DerivedCustomCtor {
 }
 
 #[test]
-#[cfg(not(feature = "tokio"))]
+#[cfg(not(feature = "async"))]
 fn custom_ctor() {
     let registry = Registry::empty();
     MyAdder::register(&registry);
@@ -48,7 +48,7 @@ fn custom_ctor() {
 }
 
 #[tokio::test]
-#[cfg(feature = "tokio")]
+#[cfg(feature = "async")]
 async fn custom_ctor() {
     let registry = Registry::empty();
     MyAdder::register(&registry).await;
@@ -59,3 +59,139 @@ async fn custom_ctor() {
     assert_eq!(derived.prefix, "log-prefix: ");
     assert_eq!(derived.adder.add(1, 3), 4);
 }
+
+pub struct BrokenAdder;
+impl Adder for BrokenAdder {
+    fn add(&self, _lhs: u32, _rhs: u32) -> u32 {
+        0
+    }
+}
+
+#[derive(Inject)]
+#[provides(transient, fallible, no_registration, ctor = "new")]
+pub struct FallibleCustomCtor {
+    #[inject(transient)]
+    adder: Box<dyn Adder>,
+}
+
+impl FallibleCustomCtor {
+    pub fn new(adder: Box<dyn Adder>) -> Result<Self, String> {
+        if adder.add(1, 1) != 2 {
+            return Err("broken adder".to_owned());
+        }
+
+        Ok(Self { adder })
+    }
+}
+
+#[derive(Inject)]
+#[provides(singleton, fallible, no_registration)]
+pub struct FallibleField {
+    #[inject(ctor_try = "\"8080\".parse::<u16>()")]
+    port: u16,
+}
+
+#[derive(Inject)]
+#[provides(transient, fallible, no_registration)]
+pub struct EnvConfig {
+    #[inject(env = "FERRUNIX_DERIVE_CTOR_PORT")]
+    port: u16,
+}
+
+#[derive(Inject)]
+#[provides(transient, fallible, no_registration)]
+pub struct MissingEnvConfig {
+    #[inject(env = "FERRUNIX_DERIVE_CTOR_MISSING")]
+    port: u16,
+}
+
+#[test]
+#[cfg(not(feature = "async"))]
+fn fallible_ctor() {
+    let registry = Registry::empty();
+    MyAdder::register(&registry);
+    FallibleCustomCtor::register(&registry);
+    FallibleField::register(&registry);
+
+    let derived = registry.get_transient::<FallibleCustomCtor>().unwrap();
+    assert_eq!(derived.adder.add(1, 3), 4);
+    assert_eq!(
+        registry.get_singleton::<FallibleField>().unwrap().port,
+        8080
+    );
+
+    let broken = Registry::empty();
+    broken.transient::<Box<dyn Adder>>(|| Box::new(BrokenAdder));
+    FallibleCustomCtor::register(&broken);
+
+    let err = broken
+        .try_get_transient::<FallibleCustomCtor>()
+        .unwrap_err();
+    assert!(err.to_string().contains("broken adder"));
+}
+
+#[tokio::test]
+#[cfg(feature = "async")]
+async fn fallible_ctor() {
+    let registry = Registry::empty();
+    MyAdder::register(&registry).await;
+    FallibleCustomCtor::register(&registry).await;
+    FallibleField::register(&registry).await;
+
+    let derived = registry
+        .get_transient::<FallibleCustomCtor>()
+        .await
+        .unwrap();
+    assert_eq!(derived.adder.add(1, 3), 4);
+    let field = registry.get_singleton::<FallibleField>().await.unwrap();
+    assert_eq!(field.port, 8080);
+
+    let broken = Registry::empty();
+    broken
+        .transient::<Box<dyn Adder>>(|| {
+            Box::pin(async move { Box::new(BrokenAdder) as Box<dyn Adder> })
+        })
+        .await;
+    FallibleCustomCtor::register(&broken).await;
+
+    let err = broken
+        .try_get_transient::<FallibleCustomCtor>()
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("broken adder"));
+}
+
+#[test]
+#[cfg(not(feature = "async"))]
+fn env_field() {
+    std::env::set_var("FERRUNIX_DERIVE_CTOR_PORT", "8080");
+
+    let registry = Registry::empty();
+    EnvConfig::register(&registry);
+    MissingEnvConfig::register(&registry);
+
+    let config = registry.get_transient::<EnvConfig>().unwrap();
+    assert_eq!(config.port, 8080);
+    let err = registry
+        .try_get_transient::<MissingEnvConfig>()
+        .unwrap_err();
+    assert!(err.is_ctor_err());
+}
+
+#[tokio::test]
+#[cfg(feature = "async")]
+async fn env_field() {
+    std::env::set_var("FERRUNIX_DERIVE_CTOR_PORT", "8080");
+
+    let registry = Registry::empty();
+    EnvConfig::register(&registry).await;
+    MissingEnvConfig::register(&registry).await;
+
+    let config = registry.get_transient::<EnvConfig>().await.unwrap();
+    assert_eq!(config.port, 8080);
+    let err = registry
+        .try_get_transient::<MissingEnvConfig>()
+        .await
+        .unwrap_err();
+    assert!(err.is_ctor_err());
+}
@@ -6,7 +6,7 @@ use ferrunix::{Inject, Registry};
 pub struct NotRegistered {}
 
 #[test]
-#[cfg(not(feature = "tokio"))]
+#[cfg(not(feature = "async"))]
 fn no_not_registered_type() {
     let global = Registry::autoregistered();
     let not_registered = global.get_singleton::<NotRegistered>();
@@ -14,9 +14,35 @@ fn no_not_registered_type() {
 }
 
 #[tokio::test]
-#[cfg(feature = "tokio")]
+#[cfg(feature = "async")]
 async fn no_not_registered_type() {
     let global = Registry::autoregistered().await;
     let not_registered = global.get_singleton::<NotRegistered>().await;
     assert!(not_registered.is_none());
 }
+
+#[derive(Inject)]
+#[provides(transient, group = "derive_plugins")]
+pub struct Plugin {}
+
+#[test]
+#[cfg(not(feature = "async"))]
+fn grouped_type() {
+    let global = Registry::autoregistered();
+    assert!(global.get_transient::<Plugin>().is_none());
+
+    let registry = Registry::empty();
+    registry.populate_group("derive_plugins");
+    assert!(registry.get_transient::<Plugin>().is_some());
+}
+
+#[tokio::test]
+#[cfg(feature = "async")]
+async fn grouped_type() {
+    let global = Registry::autoregistered().await;
+    assert!(global.get_transient::<Plugin>().await.is_none());
+
+    let registry = Registry::empty();
+    registry.populate_group("derive_plugins").await;
+    assert!(registry.get_transient::<Plugin>().await.is_some());
+}
@@ -73,3 +73,588 @@ fn inject_stringtemplate() {
     assert_eq!(maker.template.raw, "The Magic Number is ");
     assert_eq!(maker.number, 5);
 }
+
+trait Strategy: Send + Sync {
+    fn describe(&self) -> String;
+}
+
+#[derive(Inject)]
+#[provides(transient = "dyn Strategy", no_registration)]
+enum Strategies {
+    Fast,
+    #[inject(default_variant)]
+    Careful {
+        #[inject(transient)]
+        template: StringTemplate,
+        #[inject(ctor = "3")]
+        retries: u32,
+    },
+}
+
+impl Strategy for Strategies {
+    fn describe(&self) -> String {
+        match self {
+            Self::Fast => "fast".to_owned(),
+            Self::Careful { template, retries } => {
+                format!("{}{retries}", template.raw)
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Inject)]
+#[provides(singleton, no_registration)]
+enum Mode {
+    #[inject(default_variant)]
+    Debug,
+    Release,
+}
+
+#[test]
+fn inject_enum() {
+    let registry = Registry::empty();
+    StringTemplate::register(&registry);
+    Strategies::register(&registry);
+    Mode::register(&registry);
+    registry.validate_all().unwrap();
+
+    let strategy = registry.get_transient::<Box<dyn Strategy>>().unwrap();
+    assert_eq!(strategy.describe(), "The Magic Number is 3");
+    assert_eq!(Strategies::Fast.describe(), "fast");
+
+    let mode = registry.get_singleton::<Mode>().unwrap();
+    assert_eq!(*mode, Mode::Debug);
+    assert_ne!(*mode, Mode::Release);
+}
+
+trait MetricsSink: Send + Sync {
+    fn record(&self) -> u32;
+}
+
+#[derive(Inject)]
+#[provides(transient = "dyn MetricsSink", no_registration)]
+struct CountingSink {
+    #[inject(ctor = "1")]
+    count: u32,
+}
+
+impl MetricsSink for CountingSink {
+    fn record(&self) -> u32 {
+        self.count
+    }
+}
+
+#[derive(Inject)]
+#[provides(transient, no_registration)]
+struct Service {
+    #[inject(transient, optional)]
+    metrics: Option<Box<dyn MetricsSink>>,
+}
+
+#[test]
+fn inject_optional() {
+    let registry = Registry::empty();
+    Service::register(&registry);
+    registry.validate_all().unwrap();
+
+    let service = registry.get_transient::<Service>().unwrap();
+    assert!(service.metrics.is_none());
+
+    CountingSink::register(&registry);
+    registry.validate_all().unwrap();
+
+    let service = registry.get_transient::<Service>().unwrap();
+    assert_eq!(service.metrics.map(|metrics| metrics.record()), Some(1));
+}
+
+trait Lookup: Send + Sync {
+    fn lookup(&self, key: &str) -> Option<u32>;
+}
+
+#[derive(Inject)]
+#[provides(singleton = "dyn Lookup", no_registration)]
+struct StaticLookup {
+    #[inject(ctor = "42")]
+    value: u32,
+}
+
+impl Lookup for StaticLookup {
+    fn lookup(&self, _key: &str) -> Option<u32> {
+        Some(self.value)
+    }
+}
+
+#[derive(Inject)]
+#[provides(transient, no_registration)]
+struct Resolver {
+    #[inject(singleton, optional)]
+    lookup: Option<ferrunix::Ref<dyn Lookup>>,
+}
+
+#[test]
+fn inject_optional_singleton() {
+    let registry = Registry::empty();
+    Resolver::register(&registry);
+    registry.validate_all().unwrap();
+
+    let resolver = registry.get_transient::<Resolver>().unwrap();
+    assert!(resolver.lookup.is_none());
+
+    StaticLookup::register(&registry);
+    registry.validate_all().unwrap();
+
+    let resolver = registry.get_transient::<Resolver>().unwrap();
+    let lookup = resolver.lookup.unwrap();
+    assert_eq!(lookup.lookup("answer"), Some(42));
+
+    let shared = registry
+        .get_singleton::<ferrunix::Ref<dyn Lookup>>()
+        .unwrap();
+    assert!(ferrunix::Ref::ptr_eq(&lookup, &*shared));
+}
+
+#[derive(Inject)]
+#[provides(transient = "Cache<String>")]
+struct Cache<T> {
+    #[inject(default)]
+    entries: Vec<T>,
+}
+
+#[derive(Inject)]
+#[provides(transient, no_registration)]
+struct Wrapper<T>
+where
+    T: Default + Send + Sync + 'static,
+{
+    #[inject(default)]
+    inner: T,
+}
+
+#[test]
+fn inject_generics() {
+    let registry = Registry::autoregistered();
+    let cache = registry.get_transient::<Cache<String>>().unwrap();
+    assert!(cache.entries.is_empty());
+    assert!(registry.get_transient::<Cache<u8>>().is_none());
+
+    let registry = Registry::empty();
+    Wrapper::<u8>::register(&registry);
+    Wrapper::<String>::register(&registry);
+    assert_eq!(registry.get_transient::<Wrapper<u8>>().unwrap().inner, 0);
+    assert_eq!(
+        registry.get_transient::<Wrapper<String>>().unwrap().inner,
+        ""
+    );
+}
+
+#[derive(Inject)]
+#[provides(transient, no_registration, post_construct = "warm")]
+struct WarmCache {
+    #[inject(ctor = "vec![1, 2]")]
+    entries: Vec<u32>,
+    #[inject(default)]
+    warmed: bool,
+}
+
+impl WarmCache {
+    fn warm(&mut self) {
+        self.entries.push(3);
+        self.warmed = true;
+    }
+}
+
+#[derive(Inject)]
+#[provides(singleton, no_registration, post_construct = "connect")]
+struct Connection {
+    #[inject(ctor = r#""localhost".to_owned()"#)]
+    host: String,
+}
+
+impl Connection {
+    fn connect(self) -> Self {
+        Self {
+            host: format!("{}:8080", self.host),
+        }
+    }
+}
+
+#[test]
+fn inject_post_construct() {
+    let registry = Registry::empty();
+    WarmCache::register(&registry);
+    Connection::register(&registry);
+
+    let cache = registry.get_transient::<WarmCache>().unwrap();
+    assert!(cache.warmed);
+    assert_eq!(cache.entries, vec![1, 2, 3]);
+
+    let connection = registry.get_singleton::<Connection>().unwrap();
+    assert_eq!(connection.host, "localhost:8080");
+}
+
+trait Reader: Send + Sync {
+    fn read(&self) -> u32;
+}
+
+trait Writer: Send + Sync {
+    fn write(&self, value: u32) -> u32;
+}
+
+#[derive(Inject)]
+#[provides(transient = "dyn Reader", no_registration)]
+#[provides(transient = "dyn Writer")]
+struct Storage {
+    #[inject(ctor = "7")]
+    value: u32,
+}
+
+impl Reader for Storage {
+    fn read(&self) -> u32 {
+        self.value
+    }
+}
+
+impl Writer for Storage {
+    fn write(&self, value: u32) -> u32 {
+        self.value + value
+    }
+}
+
+#[test]
+fn inject_multiple_provides() {
+    let registry = Registry::empty();
+    Storage::register(&registry);
+    registry.validate_all().unwrap();
+
+    let reader = registry.get_transient::<Box<dyn Reader>>().unwrap();
+    assert_eq!(reader.read(), 7);
+    let writer = registry.get_transient::<Box<dyn Writer>>().unwrap();
+    assert_eq!(writer.write(1), 8);
+}
+
+#[derive(Inject)]
+#[provides(transient, no_registration)]
+struct Replicated {
+    #[inject(transient, name = "primary")]
+    primary: u16,
+    #[inject(transient, name = "replica")]
+    replica: u16,
+}
+
+#[test]
+fn inject_named() {
+    let registry = Registry::empty();
+    registry.transient_named("primary", || 5432_u16);
+    registry.transient_named("replica", || 5433_u16);
+    Replicated::register(&registry);
+    registry.validate_all().unwrap();
+
+    let replicated = registry.get_transient::<Replicated>().unwrap();
+    assert_eq!(replicated.primary, 5432);
+    assert_eq!(replicated.replica, 5433);
+}
+
+#[derive(Inject)]
+#[provides(singleton, no_registration)]
+struct Settings {
+    #[inject(ctor = "8")]
+    workers: u8,
+}
+
+trait Clock: Send + Sync {
+    fn now(&self) -> u64;
+}
+
+#[derive(Inject)]
+#[provides(singleton = "dyn Clock", no_registration)]
+struct FixedClock {}
+
+impl Clock for FixedClock {
+    fn now(&self) -> u64 {
+        42
+    }
+}
+
+#[derive(Inject)]
+#[provides(transient, no_registration)]
+struct Scheduler {
+    #[inject(singleton)]
+    settings: ferrunix::Ref<Settings>,
+    #[inject(singleton)]
+    clock: ferrunix::Ref<dyn Clock>,
+}
+
+#[test]
+fn inject_singleton_fields() {
+    let registry = Registry::empty();
+    Settings::register(&registry);
+    FixedClock::register(&registry);
+    Scheduler::register(&registry);
+    registry.validate_all().unwrap();
+
+    let scheduler = registry.get_transient::<Scheduler>().unwrap();
+    assert_eq!(scheduler.settings.workers, 8);
+    assert_eq!(scheduler.clock.now(), 42);
+}
+
+#[derive(Inject)]
+#[provides(transient, no_registration)]
+struct Unit;
+
+#[test]
+fn inject_unit_struct() {
+    let registry = Registry::empty();
+    Unit::register(&registry);
+    registry.validate_all().unwrap();
+    assert!(registry.get_transient::<Unit>().is_some());
+}
+
+#[derive(Inject)]
+#[provides(transient, no_registration)]
+struct Deferred {
+    #[inject(lazy)]
+    template: ferrunix::dependencies::Lazy<StringTemplate>,
+}
+
+#[test]
+fn inject_lazy() {
+    let registry = Registry::empty();
+    Deferred::register(&registry);
+    let deferred = registry.get_transient::<Deferred>().unwrap();
+    assert!(deferred.template.get(&registry).is_err());
+
+    StringTemplate::register(&registry);
+    let template = deferred.template.get(&registry).unwrap();
+    assert_eq!(template.raw, "The Magic Number is ");
+}
+
+trait Plugin: Send + Sync {
+    fn id(&self) -> u8;
+}
+
+struct NumberedPlugin(u8);
+
+impl Plugin for NumberedPlugin {
+    fn id(&self) -> u8 {
+        self.0
+    }
+}
+
+#[derive(Inject)]
+#[provides(transient, no_registration)]
+struct PluginHost {
+    #[inject(all)]
+    plugins: Vec<Box<dyn Plugin>>,
+}
+
+#[test]
+fn inject_all() {
+    let registry = Registry::empty();
+    PluginHost::register(&registry);
+    registry.validate_all().unwrap();
+    let host = registry.get_transient::<PluginHost>().unwrap();
+    assert!(host.plugins.is_empty());
+
+    registry.register_all::<Box<dyn Plugin>>(|| Box::new(NumberedPlugin(1)));
+    registry
+        .with_deps::<Box<dyn Plugin>, (ferrunix::Transient<u8>,)>()
+        .register_all(|(id,)| Box::new(NumberedPlugin(id.get())));
+    registry.transient(|| 2_u8);
+    registry.validate_all().unwrap();
+
+    let host = registry.get_transient::<PluginHost>().unwrap();
+    let ids = host
+        .plugins
+        .iter()
+        .map(|plugin| plugin.id())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [1, 2]);
+}
+
+#[derive(Clone, Inject)]
+#[provides(singleton, no_registration)]
+struct Limits {
+    #[inject(ctor = "16")]
+    max_connections: u32,
+}
+
+#[derive(Inject)]
+#[provides(transient, no_registration)]
+struct Pool {
+    #[inject(clone_singleton)]
+    limits: Limits,
+}
+
+#[test]
+fn inject_clone_singleton() {
+    let registry = Registry::empty();
+    Limits::register(&registry);
+    Pool::register(&registry);
+    registry.validate_all().unwrap();
+
+    let mut pool = registry.get_transient::<Pool>().unwrap();
+    assert_eq!(pool.limits.max_connections, 16);
+
+    // The field is owned, the singleton isn't affected.
+    pool.limits.max_connections = 32;
+    let limits = registry.get_singleton::<Limits>().unwrap();
+    assert_eq!(limits.max_connections, 16);
+}
+
+#[derive(Inject)]
+#[provides(transient, no_registration)]
+struct Counters {
+    #[inject(transient)]
+    base: u8,
+    #[inject(default)]
+    count: u64,
+    #[inject(default)]
+    samples: Vec<(u8, f32)>,
+    #[inject(default)]
+    label: Option<&'static str>,
+}
+
+#[test]
+fn inject_default_mixed_with_deps() {
+    let registry = Registry::empty();
+    registry.transient(|| 7_u8);
+    Counters::register(&registry);
+    registry.validate_all().unwrap();
+
+    let counters = registry.get_transient::<Counters>().unwrap();
+    assert_eq!(counters.base, 7);
+    assert_eq!(counters.count, 0);
+    assert!(counters.samples.is_empty());
+    assert!(counters.label.is_none());
+}
+
+trait Store: Send + Sync {
+    fn seed(&self) -> u32;
+}
+
+#[derive(Inject)]
+#[provides(singleton = "dyn Store + Send + Sync", no_registration)]
+struct MemoryStore {
+    #[inject(transient)]
+    seed: u32,
+}
+
+impl Store for MemoryStore {
+    fn seed(&self) -> u32 {
+        self.seed
+    }
+}
+
+#[derive(Inject)]
+#[provides(singleton = "Box<dyn Store + Send + Sync>", no_registration)]
+struct BoxedStore {
+    #[inject(singleton)]
+    inner: ferrunix::Ref<dyn Store + Send + Sync>,
+}
+
+impl Store for BoxedStore {
+    fn seed(&self) -> u32 {
+        self.inner.seed() + 1
+    }
+}
+
+#[derive(Inject)]
+#[provides(singleton = "StoreUser", no_registration)]
+struct StoreUser {
+    #[inject(singleton)]
+    store: ferrunix::Ref<dyn Store + Send + Sync>,
+    #[inject(singleton)]
+    boxed: ferrunix::Ref<Box<dyn Store + Send + Sync>>,
+}
+
+#[derive(Inject)]
+#[provides(transient, no_registration)]
+struct StoreUserUser {
+    #[inject(singleton)]
+    user: ferrunix::Ref<StoreUser>,
+}
+
+#[test]
+fn inject_trait_object_singletons_with_deps() {
+    let registry = Registry::empty();
+    registry.transient(|| 1_u32);
+    MemoryStore::register(&registry);
+    BoxedStore::register(&registry);
+    StoreUser::register(&registry);
+    StoreUserUser::register(&registry);
+    registry.validate_all().unwrap();
+
+    let user = registry.get_transient::<StoreUserUser>().unwrap().user;
+    assert_eq!(user.store.seed(), 1);
+    assert_eq!(user.boxed.seed(), 2);
+}
+
+/// A facade re-exporting `ferrunix`.
+mod di {
+    pub use ferrunix::*;
+}
+
+#[derive(Inject)]
+#[provides(singleton, no_registration, crate = "crate::derive_simple::di")]
+struct FacadeConfig {
+    #[inject(ctor = "8080")]
+    port: u16,
+}
+
+#[derive(Inject)]
+#[provides(transient, no_registration, crate = "crate::derive_simple::di")]
+struct FacadeServer {
+    #[inject(singleton)]
+    config: di::Ref<FacadeConfig>,
+}
+
+#[test]
+fn inject_with_crate_path() {
+    let registry = di::Registry::empty();
+    FacadeConfig::register(&registry);
+    FacadeServer::register(&registry);
+    registry.validate_all().unwrap();
+
+    let server = registry.get_transient::<FacadeServer>().unwrap();
+    assert_eq!(server.config.port, 8080);
+}
+
+/// Distinct from a bare `u16`, constructed from the `u16` transient.
+#[derive(Inject)]
+#[provides(transient, newtype, no_registration)]
+struct Port(u16);
+
+/// Distinct from a bare `u16`, and from `Port`.
+#[derive(Inject)]
+#[provides(singleton, newtype, no_registration)]
+struct Timeout {
+    #[inject(ctor = "30")]
+    secs: u16,
+}
+
+#[derive(Inject)]
+#[provides(transient, no_registration)]
+struct Listener {
+    #[inject(transient)]
+    port: Port,
+    #[inject(singleton)]
+    timeout: ferrunix::Ref<Timeout>,
+}
+
+#[test]
+fn inject_newtype() {
+    let registry = Registry::empty();
+    registry.transient(|| 8080_u16);
+    Port::register(&registry);
+    Timeout::register(&registry);
+    Listener::register(&registry);
+    registry.validate_all().unwrap();
+
+    let listener = registry.get_transient::<Listener>().unwrap();
+    assert_eq!(*listener.port, 8080);
+    assert_eq!(**listener.timeout, 30);
+    assert_eq!(registry.get_transient::<u16>(), Some(8080));
+
+    let port = Port::from(443);
+    assert_eq!(*port, 443);
+}
@@ -0,0 +1,60 @@
+#![allow(clippy::unwrap_used)]
+
+use ferrunix::local::LocalRegistry;
+use ferrunix::Ref;
+
+#[test]
+fn local_registry() {
+    let mut registry = LocalRegistry::empty();
+    assert!(registry.is_empty());
+
+    registry.transient(|| 1_u8);
+    registry.transient_with(|registry| {
+        let i = registry.get_transient::<u8>()?;
+        Some(u16::from(i) + 1_u16)
+    });
+    registry.singleton(|| String::from("Hello, World"));
+    registry.singleton_with(|registry| {
+        let s = registry.get_singleton::<String>()?;
+        let i = registry.get_transient::<u16>()?;
+        Some(format!("{s} {i}"))
+    });
+    assert_eq!(registry.len(), 4);
+    assert!(registry.contains::<u16>());
+
+    assert_eq!(registry.get_transient::<u8>(), Some(1));
+    assert_eq!(registry.get_transient::<u16>(), Some(2));
+
+    let s1 = registry.get_singleton::<String>().unwrap();
+    let s2 = registry.get_singleton::<String>().unwrap();
+    assert!(Ref::ptr_eq(&s1, &s2));
+    assert_eq!(*s1, "Hello, World");
+
+    // Resolving with the wrong lifetime, or a missing type, fails.
+    assert!(registry.get_singleton::<u8>().is_none());
+    assert!(registry.get_transient::<String>().is_none());
+    assert!(registry.get_transient::<u32>().is_none());
+}
+
+#[test]
+fn local_registry_missing_dependency() {
+    let mut registry = LocalRegistry::empty();
+    registry.singleton_with(|registry| {
+        let i = registry.get_transient::<u8>()?;
+        Some(u16::from(i))
+    });
+
+    assert!(registry.get_singleton::<u16>().is_none());
+
+    // The singleton is constructed once the dependency is registered.
+    registry.transient(|| 1_u8);
+    assert_eq!(*registry.get_singleton::<u16>().unwrap(), 1);
+}
+
+#[test]
+#[should_panic(expected = "is already registered")]
+fn local_registry_register_twice() {
+    let mut registry = LocalRegistry::empty();
+    registry.transient(|| 1_u8);
+    registry.transient(|| 2_u8);
+}
@@ -5,23 +5,33 @@ mod cycle_test;
 mod stress;
 mod validate_traits;
 
-#[cfg(all(feature = "derive", feature = "tokio"))]
+#[cfg(all(feature = "derive", feature = "async"))]
 mod derive_async;
+#[cfg(all(feature = "derive", not(miri)))]
+mod derive_compile_fail;
+#[cfg(feature = "derive")]
+mod derive_ctor;
 #[cfg(feature = "derive")]
 mod derive_registration;
 #[cfg(feature = "derive")]
 mod derive_regression;
-#[cfg(feature = "derive")]
-mod derive_ctor;
-#[cfg(all(feature = "derive", not(feature = "tokio")))]
+#[cfg(all(feature = "derive", not(feature = "async")))]
 mod derive_simple;
 
-#[cfg(not(feature = "tokio"))]
+#[cfg(not(feature = "async"))]
+mod child_override;
+#[cfg(all(not(feature = "multithread"), not(feature = "async")))]
+mod local;
+#[cfg(not(feature = "async"))]
 mod manual;
-#[cfg(not(feature = "tokio"))]
+#[cfg(not(feature = "async"))]
+mod manual_named;
+#[cfg(not(feature = "async"))]
 mod manual_non_object_safe;
-#[cfg(not(feature = "tokio"))]
+#[cfg(not(feature = "async"))]
 mod manual_traits;
 
-#[cfg(feature = "tokio")]
+#[cfg(feature = "async")]
+mod child_override_async;
+#[cfg(feature = "async")]
 mod manual_async;
@@ -1,6 +1,7 @@
 #![allow(clippy::unwrap_used, dead_code)]
 
-use ferrunix::{Registry, Singleton, Transient};
+use ferrunix::error::RegistryError;
+use ferrunix::{Ref, Registry, Singleton, Transient};
 
 #[test]
 fn simple_registry_concrete_types() {
@@ -143,6 +144,133 @@ fn panic_when_registered_twice_singleton() {
     registry.singleton(|| 1_u8);
 }
 
+#[test]
+fn checked_registration_returns_error() {
+    let registry = Registry::empty();
+    registry.checked_transient(|| 1_u8).unwrap();
+    registry.checked_singleton(|| 1_i8).unwrap();
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .checked_transient(|(i,)| u16::from(i.get()))
+        .unwrap();
+    registry
+        .with_deps::<_, (Singleton<i8>,)>()
+        .checked_singleton(|(i,)| i16::from(*i.get()))
+        .unwrap();
+
+    let err = registry.checked_transient(|| 2_u8).unwrap_err();
+    assert!(matches!(
+        err,
+        RegistryError::AlreadyRegistered { type_name: "u8" }
+    ));
+    assert!(registry.checked_singleton(|| 2_u8).is_err());
+    assert!(registry.checked_singleton(|| 2_i8).is_err());
+    assert!(registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .checked_transient(|(i,)| u16::from(i.get()) + 1)
+        .is_err());
+    assert!(registry
+        .with_deps::<_, (Singleton<i8>,)>()
+        .checked_singleton(|(i,)| i16::from(*i.get()) + 1)
+        .is_err());
+
+    registry.validate_all().unwrap();
+    assert_eq!(registry.get_transient::<u8>(), Some(1_u8));
+    assert_eq!(registry.get_transient::<u16>(), Some(1_u16));
+    assert_eq!(*registry.get_singleton::<i16>().unwrap(), 1_i16);
+}
+
+#[test]
+fn contains_registered_types() {
+    let registry = Registry::empty();
+    registry.transient(|| 1_u8);
+    registry.singleton(|| 1_i8);
+
+    assert!(registry.contains::<u8>());
+    assert!(registry.contains::<i8>());
+    assert!(registry.contains_local::<u8>());
+    assert!(registry.contains_id(std::any::TypeId::of::<i8>()));
+    assert!(!registry.contains::<u16>());
+    assert!(!registry.contains_id(std::any::TypeId::of::<u16>()));
+}
+
+#[test]
+fn registered_types() {
+    let registry = Registry::empty();
+    assert!(registry.is_empty());
+    assert_eq!(registry.len(), 0);
+
+    registry.transient(|| 1_u8);
+    registry.singleton(|| String::from("Hello, World"));
+
+    assert!(!registry.is_empty());
+    assert_eq!(registry.len(), 2);
+
+    let mut types = registry.registered_types();
+    types.sort_by_key(|(_, name)| *name);
+    assert_eq!(
+        types,
+        vec![
+            (
+                std::any::TypeId::of::<String>(),
+                std::any::type_name::<String>()
+            ),
+            (std::any::TypeId::of::<u8>(), "u8"),
+        ]
+    );
+}
+
+#[test]
+fn override_registered_types() {
+    let registry = Registry::empty();
+    registry.transient(|| 1_u8);
+    registry.singleton(|| 1_i8);
+    registry
+        .with_deps::<_, (Transient<u8>, Singleton<i8>)>()
+        .transient(|(i, j)| i16::from(i.get()) + i16::from(*j.get()));
+
+    registry.validate_all().unwrap();
+    assert_eq!(registry.get_transient::<i16>(), Some(2_i16));
+    assert_eq!(*registry.get_singleton::<i8>().unwrap(), 1_i8);
+
+    registry.override_transient(|| 2_u8);
+    registry.override_singleton(|| 2_i8);
+    registry.override_transient(|| 3_u16);
+
+    registry.validate_all().unwrap();
+    assert_eq!(registry.get_transient::<u8>(), Some(2_u8));
+    assert_eq!(*registry.get_singleton::<i8>().unwrap(), 2_i8);
+    assert_eq!(registry.get_transient::<i16>(), Some(4_i16));
+    assert_eq!(registry.get_transient::<u16>(), Some(3_u16));
+}
+
+#[test]
+fn remove_registered_types() {
+    let registry = Registry::empty();
+    registry.transient(|| 1_u8);
+    registry.singleton(|| 8_i8);
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(i,)| u16::from(i.get()));
+
+    registry.validate_all().unwrap();
+
+    assert!(registry.remove::<u8>());
+    assert!(!registry.remove::<u8>());
+    assert_eq!(registry.get_transient::<u8>(), None);
+    assert!(
+        registry.validate_all().is_err(),
+        "should fail due to the removed u8 dependency"
+    );
+
+    assert!(registry.remove::<i8>());
+    assert_eq!(registry.get_singleton::<i8>(), None);
+
+    registry.transient(|| 2_u8);
+    registry.validate_all().unwrap();
+    assert_eq!(registry.get_transient::<u16>(), Some(2_u16));
+}
+
 #[derive(Debug)]
 struct NotClone {
     inner: String,
@@ -163,3 +291,1584 @@ fn register_static_lifetime() {
     let registry = Registry::empty();
     registry.transient(|| TupleWithStatic("TEST"));
 }
+
+trait Handler {
+    fn handle(&self) -> u32;
+}
+
+struct Logging;
+
+impl Handler for Logging {
+    fn handle(&self) -> u32 {
+        1
+    }
+}
+
+struct Metrics(u32);
+
+impl Handler for Metrics {
+    fn handle(&self) -> u32 {
+        self.0
+    }
+}
+
+#[test]
+fn transient_all_dependency() {
+    use ferrunix::dependencies::TransientAll;
+
+    struct Dispatcher(Vec<Box<dyn Handler>>);
+
+    let registry = Registry::empty();
+    registry
+        .with_deps::<_, (TransientAll<Box<dyn Handler>>,)>()
+        .transient(|(handlers,)| Dispatcher(handlers.get()));
+    registry.register_all::<Box<dyn Handler>>(|| Box::new(Logging));
+    registry.validate_all().unwrap();
+
+    let graph = registry.dependency_graph();
+    assert_eq!(graph.edges().len(), 1);
+    let dispatcher = registry.get_transient::<Dispatcher>().unwrap();
+    assert_eq!(dispatcher.0.len(), 1);
+
+    // A constructor of the collection depending on the dependent is a cycle.
+    registry
+        .with_deps::<Box<dyn Handler>, (Transient<Dispatcher>,)>()
+        .register_all(|(_dispatcher,)| Box::new(Metrics(2)));
+    assert!(registry.validate_all().is_err());
+}
+
+#[test]
+fn register_all_transients() {
+    let registry = Registry::empty();
+    assert!(registry
+        .transient_all::<Box<dyn Handler>>()
+        .unwrap()
+        .is_empty());
+
+    registry.register_all::<Box<dyn Handler>>(|| Box::new(Logging));
+    registry
+        .with_deps::<Box<dyn Handler>, (Transient<u32>,)>()
+        .register_all(|(num,)| Box::new(Metrics(num.get())));
+    assert!(
+        registry.validate_all().is_err(),
+        "should fail due to the missing u32 dependency"
+    );
+    assert!(registry.transient_all::<Box<dyn Handler>>().is_err());
+
+    registry.transient(|| 2_u32);
+    registry.validate_all().unwrap();
+
+    let handlers = registry.transient_all::<Box<dyn Handler>>().unwrap();
+    let results = handlers
+        .iter()
+        .map(|handler| handler.handle())
+        .collect::<Vec<_>>();
+    assert_eq!(results, vec![1, 2]);
+
+    // Single registrations are independent of the collection.
+    assert!(registry.get_transient::<Box<dyn Handler>>().is_none());
+}
+
+#[test]
+fn scoped_registrations() {
+    struct RequestId(u64);
+
+    let registry = Ref::new(Registry::empty());
+    registry.transient(|| 7_u64);
+    registry
+        .with_deps::<_, (Transient<u64>,)>()
+        .register_scoped(|(id,)| RequestId(id.get()));
+    registry.validate_all().unwrap();
+
+    let scope = registry.enter_scope();
+    assert!(scope.get::<u64>().unwrap_err().is_missing());
+
+    let first = scope.get::<RequestId>().unwrap();
+    let second = scope.get::<RequestId>().unwrap();
+    assert_eq!(first.0, 7);
+    assert!(Ref::ptr_eq(&first, &second));
+
+    {
+        let nested = registry.enter_scope();
+        let inner = nested.get::<RequestId>().unwrap();
+        assert!(!Ref::ptr_eq(&first, &inner));
+
+        // The outer scope is unaffected by the nested one.
+        let third = scope.get::<RequestId>().unwrap();
+        assert!(Ref::ptr_eq(&first, &third));
+    }
+
+    drop(scope);
+    assert_eq!(Ref::strong_count(&first), 2);
+
+    // Scoped types registered on a parent are resolved from a child.
+    let child = registry.child();
+    let child_scope = child.enter_scope();
+    let from_child = child_scope.get::<RequestId>().unwrap();
+    assert!(!Ref::ptr_eq(&first, &from_child));
+    assert_eq!(from_child.0, 7);
+}
+
+#[test]
+fn register_instance() {
+    struct Config {
+        port: u16,
+    }
+
+    let registry = Registry::empty();
+    registry.register_instance(Config { port: 8080 });
+    registry
+        .with_deps::<_, (Singleton<Config>,)>()
+        .transient(|(config,)| u32::from(config.port));
+    registry.validate_all().unwrap();
+
+    let config = registry.get_singleton::<Config>().unwrap();
+    assert_eq!(config.port, 8080);
+    assert!(Ref::ptr_eq(
+        &config,
+        &registry.get_singleton::<Config>().unwrap()
+    ));
+    assert_eq!(registry.get_transient::<u32>(), Some(8080));
+}
+
+#[test]
+fn register_singleton_eager() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    static CONSTRUCTED: AtomicU32 = AtomicU32::new(0);
+
+    let registry = Registry::empty();
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .register_singleton_eager(|(num,)| u32::from(num.get()))
+        .unwrap_err();
+    assert!(!registry.contains::<u32>());
+    registry.validate_all().unwrap();
+
+    registry.transient(|| 8_u8);
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .register_singleton_eager(|(num,)| {
+            CONSTRUCTED.fetch_add(1, Ordering::SeqCst);
+            u32::from(num.get())
+        })
+        .unwrap();
+    assert_eq!(CONSTRUCTED.load(Ordering::SeqCst), 1);
+    registry.validate_all().unwrap();
+
+    assert_eq!(registry.peek_singleton::<u32>().as_deref(), Some(&8));
+    let first = registry.get_singleton::<u32>().unwrap();
+    let second = registry.get_singleton::<u32>().unwrap();
+    assert!(Ref::ptr_eq(&first, &second));
+    assert_eq!(CONSTRUCTED.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn get_or_register_singleton() {
+    let registry = Registry::empty();
+
+    let first = registry.get_or_register_singleton(|| String::from("first"));
+    let second = registry.get_or_register_singleton(|| String::from("second"));
+    assert_eq!(*first, "first");
+    assert!(Ref::ptr_eq(&first, &second));
+
+    registry.singleton(|| 1_u8);
+    let value = registry.get_or_register_singleton(|| 2_u8);
+    assert_eq!(*value, 1);
+
+    registry.validate_all().unwrap();
+}
+
+#[test]
+fn merge_registries() {
+    use ferrunix::error::MergeError;
+
+    let db = Registry::empty();
+    db.transient(|| 1_u8);
+    db.singleton(|| String::from("postgres://localhost"));
+
+    let http = Registry::empty();
+    http.with_deps::<_, (Transient<u8>, Singleton<String>)>()
+        .transient(|(num, url)| format!("{}:{}", *url, *num).len());
+    assert!(http.validate_all().is_err());
+
+    let root = Registry::empty();
+    root.merge(db).unwrap();
+    root.merge(http).unwrap();
+    root.validate_all().unwrap();
+
+    assert_eq!(root.len(), 3);
+    assert_eq!(root.get_transient::<usize>(), Some(22));
+
+    let conflicting = Registry::empty();
+    conflicting.transient(|| 2_u8);
+    conflicting.transient(|| 2_u16);
+    assert!(matches!(
+        root.merge(conflicting),
+        Err(MergeError::Conflict { .. })
+    ));
+    assert_eq!(root.get_transient::<u8>(), Some(1));
+    assert!(!root.contains::<u16>());
+
+    let overriding = Registry::empty();
+    overriding.transient(|| 2_u8);
+    root.merge_override(overriding).unwrap();
+    assert_eq!(root.get_transient::<u8>(), Some(2));
+    root.validate_all().unwrap();
+}
+
+#[test]
+fn merge_named_any() {
+    use ferrunix::error::MergeError;
+
+    let plugins = Registry::empty();
+    plugins.register_named_any("port", || Box::new(8080_u16));
+
+    let root = Registry::empty();
+    root.register_named_any("host", || Box::new("localhost"));
+    root.merge(plugins).unwrap();
+    let port = root.resolve_named_any("port").unwrap();
+    assert_eq!(port.downcast_ref::<u16>(), Some(&8080));
+    assert!(root.resolve_named_any("host").is_some());
+
+    let conflicting = Registry::empty();
+    conflicting.register_named_any("port", || Box::new(9090_u16));
+    conflicting.transient(|| 1_u8);
+    assert!(matches!(
+        root.merge(conflicting),
+        Err(MergeError::Conflict { .. })
+    ));
+    // Nothing is merged on conflicts.
+    assert!(!root.contains::<u8>());
+
+    let overriding = Registry::empty();
+    overriding.register_named_any("port", || Box::new(9090_u16));
+    root.merge_override(overriding).unwrap();
+    let port = root.resolve_named_any("port").unwrap();
+    assert_eq!(port.downcast_ref::<u16>(), Some(&9090));
+}
+
+#[test]
+fn merge_default_provider() {
+    use std::any::TypeId;
+
+    let fallbacks = Registry::empty();
+    fallbacks.set_default_provider(|id, _registry| {
+        if id == TypeId::of::<u16>() {
+            return Some(Box::new(8080_u16));
+        }
+        None
+    });
+
+    let root = Registry::empty();
+    root.merge(fallbacks).unwrap();
+    assert_eq!(root.get_transient::<u16>(), Some(8080));
+
+    // The default provider of the registry is kept.
+    let other = Registry::empty();
+    other.set_default_provider(|_id, _registry| None);
+    root.merge(other).unwrap();
+    assert_eq!(root.get_transient::<u16>(), Some(8080));
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn merge_metrics_sink() {
+    use std::sync::{Arc, Mutex};
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let instrumented = Registry::empty();
+    instrumented.set_metrics_sink({
+        let events = Arc::clone(&events);
+        move |event| events.lock().unwrap().push(event)
+    });
+
+    let root = Registry::empty();
+    root.transient(|| 1_u8);
+    root.merge(instrumented).unwrap();
+    assert_eq!(root.get_transient::<u8>(), Some(1));
+    assert_eq!(events.lock().unwrap().len(), 1);
+
+    // The metrics sink of the registry is kept.
+    let other = Registry::empty();
+    other.set_metrics_sink(|_event| {});
+    root.merge(other).unwrap();
+    assert_eq!(root.get_transient::<u8>(), Some(1));
+    assert_eq!(events.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn dependency_graph() {
+    use std::any::TypeId;
+
+    let registry = Registry::empty();
+    registry.transient(|| 1_u8);
+    registry.singleton(|| String::from("Hello, World"));
+    registry
+        .with_deps::<_, (Transient<u8>, Singleton<String>)>()
+        .transient(|(num, text)| u16::from(*num) + text.len() as u16);
+    registry
+        .with_deps::<_, (Transient<u16>,)>()
+        .transient(|(num,)| u32::from(*num));
+
+    let graph = registry.dependency_graph();
+    assert_eq!(graph.nodes().len(), 4);
+    assert_eq!(graph.edges().len(), 3);
+
+    let mut dependencies = graph
+        .dependencies_of(TypeId::of::<u16>())
+        .iter()
+        .map(|node| node.type_id())
+        .collect::<Vec<_>>();
+    dependencies.sort();
+    let mut expected = vec![TypeId::of::<u8>(), TypeId::of::<String>()];
+    expected.sort();
+    assert_eq!(dependencies, expected);
+
+    let dependents = graph
+        .dependents_of(TypeId::of::<u16>())
+        .iter()
+        .map(|node| node.type_name())
+        .collect::<Vec<_>>();
+    assert_eq!(dependents, vec!["u32"]);
+
+    assert!(graph.dependents_of(TypeId::of::<u32>()).is_empty());
+    assert!(graph.dependencies_of(TypeId::of::<u8>()).is_empty());
+}
+
+#[test]
+fn dependency_graph_lifetimes() {
+    use ferrunix::cycle_detection::Lifetime;
+
+    let registry = Registry::empty();
+    registry.transient(|| 1_u8);
+    registry.singleton(|| 2_u16);
+    registry.register_scoped(|| 3_u32);
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .register_scoped(|(num,)| u64::from(*num));
+
+    let graph = registry.dependency_graph();
+    let lifetime_of = |type_name| {
+        graph
+            .nodes()
+            .iter()
+            .find(|node| node.type_name() == type_name)
+            .map(|node| node.lifetime())
+    };
+    assert_eq!(lifetime_of("u8"), Some(Lifetime::Transient));
+    assert_eq!(lifetime_of("u16"), Some(Lifetime::Singleton));
+    assert_eq!(lifetime_of("u32"), Some(Lifetime::Scoped));
+    assert_eq!(lifetime_of("u64"), Some(Lifetime::Scoped));
+}
+
+#[test]
+fn unused_types() {
+    use std::any::TypeId;
+
+    let registry = Registry::empty();
+    registry.transient(|| 1_u8);
+    registry.transient(|| 1_i8);
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| u16::from(*num));
+    registry
+        .with_deps::<_, (Transient<u16>,)>()
+        .transient(|(num,)| u32::from(*num));
+    registry.validate_all().unwrap();
+
+    let mut unused = registry.unused_types();
+    unused.sort();
+    let mut expected =
+        vec![(TypeId::of::<i8>(), "i8"), (TypeId::of::<u32>(), "u32")];
+    expected.sort();
+    assert_eq!(unused, expected);
+
+    let mut unreachable = registry.unreachable_types(&[TypeId::of::<u16>()]);
+    unreachable.sort();
+    assert_eq!(unreachable, expected);
+
+    let roots = [TypeId::of::<u32>(), TypeId::of::<i8>()];
+    assert!(registry.unreachable_types(&roots).is_empty());
+}
+
+#[test]
+fn mermaid_and_json_graph() {
+    let registry = Registry::empty();
+    registry.transient(|| 1_u8);
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| u16::from(*num));
+
+    let mermaid = registry.mermaid_graph().unwrap();
+    assert!(mermaid.starts_with("graph TD\n"), "{mermaid}");
+    let (dependent, dependency) = if mermaid.contains(r#"n0["u16"]"#) {
+        ("n0", "n1")
+    } else {
+        ("n1", "n0")
+    };
+    assert!(mermaid.contains(&format!("{dependent} --> {dependency}")));
+
+    let json = registry.graph_json().unwrap();
+    let (from, to) =
+        if json.contains(r#"{"id":0,"type_name":"u16","name":null}"#) {
+            (0_usize, 1_usize)
+        } else {
+            (1_usize, 0_usize)
+        };
+    assert!(json.starts_with(r#"{"nodes":[{"id":0,"#), "{json}");
+    assert!(
+        json.ends_with(&format!(r#""edges":[{{"from":{from},"to":{to}}}]}}"#))
+    );
+
+    registry
+        .with_deps::<_, (Transient<u32>,)>()
+        .transient(|(num,)| u64::from(*num));
+    registry.mermaid_graph().unwrap_err();
+    registry.graph_json().unwrap_err();
+}
+
+#[test]
+fn dotgraph_styled() {
+    let registry = Registry::empty();
+    registry.transient(|| 1_u8);
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .singleton(|(num,)| u16::from(*num));
+
+    let dot = registry.dotgraph_styled().unwrap();
+    assert!(dot.starts_with("digraph {\n"), "{dot}");
+    assert!(
+        dot.contains(r#"[ label = "u8", shape = ellipse ]"#),
+        "{dot}"
+    );
+    assert!(dot.contains(r#"[ label = "u16", shape = box ]"#), "{dot}");
+    assert!(dot.contains(r#"[ label = "transient" ]"#), "{dot}");
+    assert!(dot.contains("subgraph cluster_legend {"), "{dot}");
+
+    registry
+        .with_deps::<_, (Transient<u32>,)>()
+        .transient(|(num,)| u64::from(*num));
+    registry.dotgraph_styled().unwrap_err();
+}
+
+thread_local! {
+    static RESOLVED: std::cell::RefCell<Vec<&'static str>> =
+        const { std::cell::RefCell::new(Vec::new()) };
+}
+
+fn record_resolved<T>(value: T) -> T {
+    RESOLVED.with(|resolved| {
+        resolved.borrow_mut().push(std::any::type_name::<T>());
+    });
+    value
+}
+
+#[test]
+fn twelve_dependencies() {
+    let registry = Registry::empty();
+    registry.transient(|| record_resolved(1_u8));
+    registry.transient(|| record_resolved(2_u16));
+    registry.transient(|| record_resolved(3_u32));
+    registry.transient(|| record_resolved(4_u64));
+    registry.transient(|| record_resolved(5_u128));
+    registry.transient(|| record_resolved(6_i8));
+    registry.transient(|| record_resolved(7_i16));
+    registry.transient(|| record_resolved(8_i32));
+    registry.transient(|| record_resolved(9_i64));
+    registry.transient(|| record_resolved(10_i128));
+    registry.transient(|| record_resolved(11_usize));
+    registry.transient(|| record_resolved(12_isize));
+    registry
+        .with_deps::<_, (
+            Transient<u8>,
+            Transient<u16>,
+            Transient<u32>,
+            Transient<u64>,
+            Transient<u128>,
+            Transient<i8>,
+            Transient<i16>,
+            Transient<i32>,
+            Transient<i64>,
+            Transient<i128>,
+            Transient<usize>,
+            Transient<isize>,
+        )>()
+        .transient(
+            |(
+                dep1,
+                dep2,
+                dep3,
+                dep4,
+                dep5,
+                dep6,
+                dep7,
+                dep8,
+                dep9,
+                dep10,
+                dep11,
+                dep12,
+            )| {
+                vec![
+                    dep1.get().to_string(),
+                    dep2.get().to_string(),
+                    dep3.get().to_string(),
+                    dep4.get().to_string(),
+                    dep5.get().to_string(),
+                    dep6.get().to_string(),
+                    dep7.get().to_string(),
+                    dep8.get().to_string(),
+                    dep9.get().to_string(),
+                    dep10.get().to_string(),
+                    dep11.get().to_string(),
+                    dep12.get().to_string(),
+                ]
+            },
+        );
+
+    registry.validate_all().unwrap();
+
+    RESOLVED.with(|resolved| resolved.borrow_mut().clear());
+    let values = registry.get_transient::<Vec<String>>().unwrap();
+    assert_eq!(
+        values,
+        (1_i32..=12_i32)
+            .map(|num| num.to_string())
+            .collect::<Vec<_>>()
+    );
+    RESOLVED.with(|resolved| {
+        assert_eq!(
+            *resolved.borrow(),
+            vec![
+                "u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64",
+                "i128", "usize", "isize",
+            ]
+        );
+    });
+}
+
+#[test]
+fn seven_and_eight_dependencies() {
+    let registry = Registry::empty();
+    registry.transient(|| 1_u8);
+    registry.transient(|| 2_u16);
+    registry.transient(|| 3_u32);
+    registry.transient(|| 4_u64);
+    registry.transient(|| 5_i8);
+    registry.transient(|| 6_i16);
+    registry.transient(|| 7_i32);
+    registry.transient(|| 8_i64);
+    registry
+        .with_deps::<_, (
+            Transient<u8>,
+            Transient<u16>,
+            Transient<u32>,
+            Transient<u64>,
+            Transient<i8>,
+            Transient<i16>,
+            Transient<i32>,
+        )>()
+        .transient(|(dep1, dep2, dep3, dep4, dep5, dep6, dep7)| {
+            [
+                i64::from(dep1.get()),
+                i64::from(dep2.get()),
+                i64::from(dep3.get()),
+                i64::try_from(dep4.get()).unwrap(),
+                i64::from(dep5.get()),
+                i64::from(dep6.get()),
+                i64::from(dep7.get()),
+            ]
+        });
+    registry
+        .with_deps::<_, (
+            Transient<u8>,
+            Transient<u16>,
+            Transient<u32>,
+            Transient<u64>,
+            Transient<i8>,
+            Transient<i16>,
+            Transient<i32>,
+            Transient<i64>,
+        )>()
+        .transient(|(dep1, dep2, dep3, dep4, dep5, dep6, dep7, dep8)| {
+            [
+                i64::from(dep1.get()),
+                i64::from(dep2.get()),
+                i64::from(dep3.get()),
+                i64::try_from(dep4.get()).unwrap(),
+                i64::from(dep5.get()),
+                i64::from(dep6.get()),
+                i64::from(dep7.get()),
+                dep8.get(),
+            ]
+        });
+
+    registry.validate_all().unwrap();
+
+    assert_eq!(
+        registry.get_transient::<[i64; 7]>(),
+        Some([1, 2, 3, 4, 5, 6, 7])
+    );
+    assert_eq!(
+        registry.get_transient::<[i64; 8]>(),
+        Some([1, 2, 3, 4, 5, 6, 7, 8])
+    );
+}
+
+#[test]
+fn optional_dependencies() {
+    use ferrunix::dependencies::{OptionalSingleton, OptionalTransient};
+
+    let registry = Registry::empty();
+    registry
+        .with_deps::<_, (OptionalTransient<u8>, OptionalSingleton<u16>)>()
+        .transient(|(num, other)| {
+            u32::from(num.get().unwrap_or(10))
+                + u32::from(other.get().map_or(20, |other| *other))
+        });
+    registry.validate_all().unwrap();
+    assert_eq!(registry.get_transient::<u32>(), Some(30));
+
+    registry.transient(|| 1_u8);
+    registry.singleton(|| 2_u16);
+    registry.validate_all().unwrap();
+    assert_eq!(registry.get_transient::<u32>(), Some(3));
+}
+
+#[test]
+fn post_construct_hooks() {
+    fn by_ref(num: &mut u32) {
+        *num += 1;
+    }
+
+    fn by_value(num: u32) -> u32 {
+        num * 2
+    }
+
+    assert_eq!(ferrunix::post_construct(1_u32, by_ref), 2);
+    assert_eq!(ferrunix::post_construct(2_u32, by_value), 4);
+}
+
+#[test]
+fn resolve_error_dependency_chain() {
+    let registry = Registry::empty();
+    registry
+        .with_deps::<_, (Transient<u16>,)>()
+        .transient(|(num,)| u32::from(num.get()));
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| u16::from(num.get()));
+
+    let err = registry.try_get_transient::<u32>().unwrap_err();
+    assert_eq!(err.chain(), &["u32", "u16", "u8"]);
+
+    let missing = registry.try_get_singleton::<i8>().unwrap_err();
+    assert_eq!(missing.chain(), &["i8"]);
+
+    registry.transient(|| 1_u8);
+    assert_eq!(registry.try_get_transient::<u32>().unwrap(), 1);
+}
+
+#[test]
+fn mismatched_lifetime_doesnt_panic() {
+    let registry = Registry::empty();
+    registry.transient(|| 1_u8);
+    registry
+        .with_deps::<_, (Singleton<u8>,)>()
+        .transient(|(num,)| u16::from(*num.get()));
+
+    // `u8` is registered, but as transient, not as singleton.
+    registry.validate_all().unwrap();
+    let err = registry.try_get_transient::<u16>().unwrap_err();
+    assert_eq!(err.chain(), &["u16", "u8"]);
+    assert_eq!(registry.get_transient::<u16>(), None);
+}
+
+#[test]
+fn peek_singleton() {
+    let registry = Registry::empty();
+    registry.singleton(|| 1_u8);
+    registry.register_instance(2_u16);
+
+    assert!(registry.peek_singleton::<u8>().is_none());
+    assert!(registry.peek_singleton::<u32>().is_none());
+    assert_eq!(registry.peek_singleton::<u16>().as_deref(), Some(&2));
+
+    let first = registry.get_singleton::<u8>().unwrap();
+    let peeked = registry.peek_singleton::<u8>().unwrap();
+    assert!(Ref::ptr_eq(&first, &peeked));
+}
+
+#[test]
+fn invalidate_singleton() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    static CONSTRUCTED: AtomicU32 = AtomicU32::new(0);
+
+    let registry = Registry::empty();
+    registry.singleton(|| CONSTRUCTED.fetch_add(1, Ordering::SeqCst));
+    registry
+        .with_deps::<_, (Singleton<u32>,)>()
+        .singleton(|(num,)| u64::from(*num.get()));
+    registry.register_instance(1_u16);
+
+    assert!(!registry.invalidate_singleton::<u32>());
+    let first = registry.get_singleton::<u32>().unwrap();
+    assert_eq!(*first, 0);
+    assert_eq!(*registry.get_singleton::<u64>().unwrap(), 0);
+
+    assert!(registry.invalidate_singleton::<u32>());
+    assert!(!registry.invalidate_singleton::<u32>());
+    assert!(registry.peek_singleton::<u32>().is_none());
+    assert_eq!(*registry.get_singleton::<u32>().unwrap(), 1);
+    assert_eq!(*first, 0);
+
+    // `u64` still holds on to the previous instance until it's invalidated.
+    assert_eq!(*registry.get_singleton::<u64>().unwrap(), 0);
+    assert!(registry.invalidate_singleton::<u64>());
+    assert_eq!(*registry.get_singleton::<u64>().unwrap(), 1);
+
+    assert!(!registry.invalidate_singleton::<u16>());
+    assert!(!registry.invalidate_singleton::<u8>());
+}
+
+struct GroupedPlugin(u8);
+
+fn register_grouped_plugin(registry: &Registry) {
+    registry.transient(|| GroupedPlugin(1));
+}
+
+ferrunix::autoregister!(ferrunix::RegistrationFunc::with_group(
+    register_grouped_plugin,
+    "manual_plugins"
+));
+
+#[test]
+fn populate_group() {
+    let global = Registry::autoregistered();
+    assert!(!global.contains::<GroupedPlugin>());
+
+    let registry = Registry::empty();
+    registry.populate_group("other_plugins");
+    assert!(!registry.contains::<GroupedPlugin>());
+
+    registry.populate_group("manual_plugins");
+    assert_eq!(registry.get_transient::<GroupedPlugin>().unwrap().0, 1);
+}
+
+#[test]
+fn fresh_autoregistered() {
+    let first = Registry::fresh_autoregistered();
+    let second = Registry::fresh_autoregistered();
+    assert!(!Ref::ptr_eq(&first, &second));
+    assert!(!first.contains::<GroupedPlugin>());
+
+    first.populate_group("manual_plugins");
+    assert!(first.contains::<GroupedPlugin>());
+    assert!(!second.contains::<GroupedPlugin>());
+}
+
+#[test]
+fn autoregistered_lenient() {
+    let (registry, errors) = Registry::autoregistered_lenient();
+    assert!(errors.is_empty());
+    assert!(!registry.contains::<GroupedPlugin>());
+}
+
+#[test]
+fn weak_singletons() {
+    use ferrunix::dependencies::WeakSingleton;
+
+    struct Subject {
+        observer: WeakSingleton<Observer>,
+    }
+
+    struct Observer {
+        subject: Ref<Subject>,
+    }
+
+    let registry = Registry::empty();
+    registry
+        .with_deps::<_, (WeakSingleton<Observer>,)>()
+        .singleton(|(observer,)| Subject { observer });
+    registry
+        .with_deps::<_, (Singleton<Subject>,)>()
+        .singleton(|(subject,)| Observer {
+            subject: subject.get(),
+        });
+    registry
+        .with_deps::<_, (WeakSingleton<u16>,)>()
+        .transient(|(num,)| {
+            num.get().upgrade().map_or(0, |value| u32::from(*value))
+        });
+    registry.register_instance(2_u16);
+    registry.validate_all().unwrap();
+
+    let subject = registry.get_singleton::<Subject>().unwrap();
+    assert!(subject.observer.get().upgrade().is_none());
+
+    let observer = registry.get_singleton::<Observer>().unwrap();
+    assert!(Ref::ptr_eq(&observer.subject, &subject));
+    let upgraded = subject.observer.get().upgrade().unwrap();
+    assert!(Ref::ptr_eq(&upgraded, &observer));
+    assert_eq!(registry.get_transient::<u32>(), Some(2));
+
+    let weak_observer = Ref::downgrade(&observer);
+    drop((registry, subject, observer, upgraded));
+    assert!(weak_observer.upgrade().is_none());
+}
+
+#[test]
+fn weak_singletons_missing() {
+    use ferrunix::dependencies::WeakSingleton;
+
+    let registry = Registry::empty();
+    registry
+        .with_deps::<_, (WeakSingleton<u8>,)>()
+        .singleton(|(num,)| u16::from(num.get().upgrade().is_some()));
+    registry.validate_all().unwrap_err();
+    assert!(registry.get_singleton::<u16>().is_none());
+}
+
+#[test]
+fn with_capacity() {
+    let registry = Registry::with_capacity(4);
+    registry.transient(|| 1_u8);
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| u16::from(num.get()) + 1);
+    registry.singleton(|| 3_u32);
+    registry.singleton(|| 4_u64);
+    registry.singleton(|| 5_i8);
+    registry.validate_all_full().unwrap();
+
+    assert_eq!(registry.get_transient::<u16>(), Some(2));
+    assert_eq!(*registry.get_singleton::<i8>().unwrap(), 5);
+}
+
+#[test]
+fn validate_after_registering_missing() {
+    let registry = Registry::empty();
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| u16::from(num.get()));
+
+    registry.validate_all().unwrap_err();
+    registry.validate_all().unwrap_err();
+    registry.validate_all_full().unwrap_err();
+
+    registry.transient(|| 1_u8);
+    registry.validate_all().unwrap();
+    registry.validate_all_full().unwrap();
+    assert_eq!(registry.get_transient::<u16>(), Some(1));
+}
+
+#[test]
+fn register_factory() {
+    #[derive(Debug, PartialEq)]
+    struct Connection {
+        url: String,
+        retries: u8,
+    }
+
+    let registry = Registry::empty();
+    registry.transient(|| 3_u8);
+    registry.register_factory(|registry: &Registry, url: String| Connection {
+        url,
+        retries: registry.get_transient::<u8>().unwrap(),
+    });
+
+    let conn = registry
+        .make::<Connection, _>("db://localhost".to_owned())
+        .unwrap();
+    assert_eq!(conn.url, "db://localhost");
+    assert_eq!(conn.retries, 3);
+
+    let other = registry.make::<Connection, _>("db://remote".to_owned());
+    assert_eq!(other.unwrap().url, "db://remote");
+
+    registry.make::<Connection, _>(1_u32).unwrap_err();
+    registry.make::<u16, _>(()).unwrap_err();
+}
+
+#[test]
+fn shutdown_order() {
+    let registry = Registry::empty();
+    registry.singleton(|| 1_u8);
+    registry
+        .with_deps::<_, (Singleton<u8>,)>()
+        .singleton(|(num,)| u16::from(*num.get()) + 1);
+    registry
+        .with_deps::<_, (Singleton<u16>,)>()
+        .singleton(|(num,)| u32::from(*num.get()) + 1);
+    registry.singleton(|| 4_u64);
+
+    assert!(registry.shutdown_order().unwrap().is_empty());
+
+    assert_eq!(*registry.get_singleton::<u32>().unwrap(), 3);
+    let mut constructed = 0_usize;
+    registry.for_each_singleton(|_| constructed += 1);
+    assert_eq!(constructed, 3);
+
+    let mut order = registry.shutdown_order().unwrap().into_iter();
+    assert_eq!(order.next().unwrap().downcast_ref::<u32>(), Some(&3));
+    assert_eq!(order.next().unwrap().downcast_ref::<u16>(), Some(&2));
+    assert_eq!(order.next().unwrap().downcast_ref::<u8>(), Some(&1));
+    assert!(order.next().is_none());
+}
+
+#[test]
+fn initialize_all_singletons() {
+    let registry = Registry::empty();
+    registry.singleton(|| 1_u8);
+    registry
+        .with_deps::<_, (Singleton<u8>,)>()
+        .singleton(|(num,)| u16::from(*num.get()) + 1);
+    registry
+        .with_deps::<_, (Singleton<u16>,)>()
+        .singleton(|(num,)| u32::from(*num.get()) + 1);
+    registry.transient(|| 4_u64);
+
+    registry.initialize_all_singletons().unwrap();
+    assert_eq!(registry.peek_singleton::<u8>().as_deref(), Some(&1));
+    assert_eq!(registry.peek_singleton::<u16>().as_deref(), Some(&2));
+    assert_eq!(registry.peek_singleton::<u32>().as_deref(), Some(&3));
+
+    let mut order = registry.shutdown_order().unwrap().into_iter();
+    assert_eq!(order.next().unwrap().downcast_ref::<u32>(), Some(&3));
+    assert_eq!(order.next().unwrap().downcast_ref::<u16>(), Some(&2));
+    assert_eq!(order.next().unwrap().downcast_ref::<u8>(), Some(&1));
+    assert!(order.next().is_none());
+
+    registry
+        .with_deps::<_, (Singleton<i8>,)>()
+        .singleton(|(num,)| i16::from(*num.get()));
+    let err = registry.initialize_all_singletons().unwrap_err();
+    assert!(err.is_missing());
+}
+
+#[test]
+fn initialize_all_singletons_cycle() {
+    use ferrunix::error::ResolveError;
+
+    let registry = Registry::empty();
+    registry
+        .with_deps::<_, (Singleton<u16>,)>()
+        .singleton(|(num,)| u8::try_from(*num.get()).unwrap_or_default());
+    registry
+        .with_deps::<_, (Singleton<u8>,)>()
+        .singleton(|(num,)| u16::from(*num.get()));
+
+    let err = registry.initialize_all_singletons().unwrap_err();
+    assert!(matches!(err, ResolveError::Cycle));
+    assert!(registry.peek_singleton::<u8>().is_none());
+}
+
+#[test]
+fn conditional_registration() {
+    let registry = Registry::empty();
+    assert!(!registry.register_transient_if(false, || 1_u8));
+    assert!(registry.register_transient_if(true, || 2_u8));
+    assert!(!registry.register_singleton_if(false, || 3_u16));
+    assert!(registry.register_singleton_if(true, || 4_u16));
+
+    assert_eq!(registry.get_transient::<u8>(), Some(2));
+    assert_eq!(*registry.get_singleton::<u16>().unwrap(), 4);
+
+    assert!(registry.register_first_available(&[
+        (false, || 5_u32),
+        (true, || 6_u32),
+        (true, || 7_u32),
+    ]));
+    assert_eq!(registry.get_transient::<u32>(), Some(6));
+
+    assert!(!registry.register_first_available(&[(false, || 8_u64)]));
+    assert!(registry.get_transient::<u64>().is_none());
+}
+
+#[test]
+fn lazy_dependency() {
+    use ferrunix::dependencies::Lazy;
+
+    let registry = Registry::empty();
+    registry
+        .with_deps::<_, (Lazy<u8>,)>()
+        .singleton(|(num,)| num);
+    registry.validate_all().unwrap_err();
+
+    registry.transient(|| 1_u8);
+    registry.validate_all().unwrap();
+
+    let lazy = registry.get_singleton::<Lazy<u8>>().unwrap();
+    assert!(lazy.get_if_init().is_none());
+    assert_eq!(lazy.get(&registry).unwrap(), &1);
+    assert_eq!(lazy.get_if_init(), Some(&1));
+}
+
+#[test]
+fn resolve_dep() {
+    use ferrunix::dependencies::OptionalTransient;
+
+    let registry = Registry::empty();
+    registry.transient(|| 1_u8);
+    registry.singleton(|| 2_u16);
+
+    let num = registry.resolve_dep::<Transient<u8>>().unwrap();
+    assert_eq!(num.get(), 1);
+    let single = registry.resolve_dep::<Singleton<u16>>().unwrap();
+    assert_eq!(*single.get(), 2);
+    let missing = registry.resolve_dep::<OptionalTransient<u32>>().unwrap();
+    assert!(missing.get().is_none());
+    registry.resolve_dep::<Transient<u32>>().unwrap_err();
+}
+
+#[test]
+fn duplicate_registry() {
+    let registry = Registry::empty();
+    registry.transient(|| 1_u8);
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .singleton(|(num,)| u16::from(*num) + 1);
+    registry.register_instance(String::from("config"));
+    registry.register_all(|| 3_u32);
+    registry.register_factory(|_: &Registry, num: u8| u64::from(num));
+
+    let first = registry.get_singleton::<u16>().unwrap();
+    assert_eq!(*first, 2);
+
+    let duplicate = registry.duplicate();
+    duplicate.validate_all().unwrap();
+    assert_eq!(duplicate.len(), registry.len());
+    assert!(duplicate.peek_singleton::<u16>().is_none());
+
+    let second = duplicate.get_singleton::<u16>().unwrap();
+    assert_eq!(*second, 2);
+    assert!(!Ref::ptr_eq(&first, &second));
+    assert!(Ref::ptr_eq(
+        &registry.get_singleton::<String>().unwrap(),
+        &duplicate.get_singleton::<String>().unwrap()
+    ));
+    assert_eq!(duplicate.transient_all::<u32>().unwrap(), vec![3]);
+    assert_eq!(duplicate.make::<u64, _>(4_u8).unwrap(), 4);
+
+    duplicate.transient(|| 5_i8);
+    assert!(duplicate.contains::<i8>());
+    assert!(!registry.contains::<i8>());
+}
+
+#[test]
+fn registration_handle() {
+    let registry = Registry::empty();
+    let transient = registry.transient_handle(|| 1_u8);
+    let singleton = registry.singleton_handle(|| 1_u16);
+    assert_eq!(transient.type_id(), std::any::TypeId::of::<u8>());
+
+    transient.override_transient(|| 2);
+    assert_eq!(registry.get_transient::<u8>(), Some(2));
+    singleton.override_singleton(|| 3);
+    assert_eq!(*registry.get_singleton::<u16>().unwrap(), 3);
+
+    assert!(transient.remove());
+    assert!(!registry.contains::<u8>());
+    assert!(singleton.remove());
+    assert!(registry.is_empty());
+}
+
+#[test]
+fn registry_ref_dependency() {
+    use ferrunix::dependencies::RegistryRef;
+    use ferrunix::error::ResolveError;
+
+    let registry = Registry::root();
+    registry.transient(|| 1_u8);
+    registry
+        .with_deps::<_, (RegistryRef, Transient<u8>)>()
+        .transient(|(registry, num)| {
+            u16::from(registry.get_transient::<u8>().unwrap() + *num)
+        });
+    registry.validate_all().unwrap();
+    assert_eq!(registry.get_transient::<u16>(), Some(2));
+
+    let child = registry.child();
+    child
+        .with_deps::<_, (RegistryRef,)>()
+        .transient(|(registry,)| registry.len());
+    assert_eq!(child.get_transient::<usize>(), Some(1));
+
+    let unshared = Registry::empty();
+    unshared
+        .with_deps::<_, (RegistryRef,)>()
+        .transient(|(registry,)| registry.len());
+    unshared.validate_all().unwrap();
+    let err = unshared.try_get_transient::<usize>().unwrap_err();
+    assert!(matches!(
+        err,
+        ResolveError::DependencyChain { ref source, .. }
+            if matches!(**source, ResolveError::RegistryNotRef)
+    ));
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn metrics_sink() {
+    use ferrunix::metrics::MetricEvent;
+    use std::sync::{Arc, Mutex};
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let registry = Registry::empty();
+    registry.set_metrics_sink({
+        let events = Arc::clone(&events);
+        move |event| events.lock().unwrap().push(event)
+    });
+    registry.transient(|| 1_u8);
+    registry.singleton(|| String::from("Hello"));
+
+    assert_eq!(registry.get_transient::<u8>(), Some(1));
+    assert_eq!(
+        registry.get_singleton::<String>().unwrap().as_str(),
+        "Hello"
+    );
+    assert_eq!(
+        registry.get_singleton::<String>().unwrap().as_str(),
+        "Hello"
+    );
+
+    let events = events.lock().unwrap();
+    let names = events
+        .iter()
+        .map(|event| match *event {
+            MetricEvent::Resolved { type_name, .. } => ("resolved", type_name),
+            MetricEvent::SingletonConstructed { type_name, .. } => {
+                ("constructed", type_name)
+            }
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec![
+            ("resolved", std::any::type_name::<u8>()),
+            ("constructed", std::any::type_name::<String>()),
+            ("resolved", std::any::type_name::<String>()),
+            ("resolved", std::any::type_name::<String>()),
+        ]
+    );
+}
+
+#[test]
+fn register_default() {
+    let registry = Registry::empty();
+    registry.register_transient_default::<u8>();
+    registry.register_singleton_default::<String>();
+    registry.validate_all().unwrap();
+
+    assert_eq!(registry.get_transient::<u8>(), Some(0));
+    let first = registry.get_singleton::<String>().unwrap();
+    assert!(first.is_empty());
+    let second = registry.get_singleton::<String>().unwrap();
+    assert!(Ref::ptr_eq(&first, &second));
+}
+
+#[test]
+fn fallible_constructors() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    static ATTEMPTS: AtomicU32 = AtomicU32::new(0);
+
+    let registry = Registry::empty();
+    registry
+        .try_register_transient(|| "8080".parse::<u16>().map_err(Into::into));
+    registry
+        .with_deps::<_, (Transient<u16>,)>()
+        .try_register_transient(|(port,)| {
+            u8::try_from(port.get()).map_err(Into::into)
+        });
+    registry.try_register_singleton(|| {
+        // Fails on the first attempt only.
+        match ATTEMPTS.fetch_add(1, Ordering::SeqCst) {
+            0 => Err("not yet".into()),
+            attempt => Ok(attempt),
+        }
+    });
+    registry
+        .with_deps::<_, (Singleton<u32>,)>()
+        .try_register_singleton(|(attempt,)| Ok(u64::from(*attempt.get())));
+    registry.validate_all().unwrap();
+
+    assert_eq!(registry.get_transient::<u16>(), Some(8080));
+    let err = registry.try_get_transient::<u8>().unwrap_err();
+    assert_eq!(err.chain(), &["u8"]);
+    assert!(err.to_string().contains("constructor failed"));
+
+    let err = registry.try_get_singleton::<u64>().unwrap_err();
+    assert_eq!(err.chain(), &["u64", "u32"]);
+    assert!(registry.peek_singleton::<u32>().is_none());
+    assert_eq!(*registry.get_singleton::<u64>().unwrap(), 1);
+    assert_eq!(*registry.get_singleton::<u32>().unwrap(), 1);
+}
+
+#[test]
+fn singleton_owned() {
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    struct Config {
+        port: u16,
+    }
+
+    let registry = Registry::empty();
+    registry.singleton(|| Config { port: 8080 });
+
+    let config = registry.singleton_owned::<Config>().unwrap();
+    assert_eq!(config, Config { port: 8080 });
+    assert!(registry.singleton_owned::<u8>().is_err());
+}
+
+#[test]
+fn resolve_error_predicates() {
+    let registry = Registry::empty();
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| u16::from(num.get()));
+    registry.try_register_transient(|| Err::<u32, _>("failed".into()));
+
+    let missing = registry.try_get_transient::<u16>().unwrap_err();
+    assert!(missing.is_missing());
+    assert!(!missing.is_ctor_err());
+    assert!(!missing.is_type_mismatch());
+    assert_eq!(missing.missing_type_name(), Some("u8"));
+
+    let failed = registry.try_get_transient::<u32>().unwrap_err();
+    assert!(failed.is_ctor_err());
+    assert!(!failed.is_missing());
+    assert_eq!(failed.missing_type_name(), None);
+}
+
+#[test]
+fn with_empty_deps() {
+    use std::any::TypeId;
+
+    let registry = Registry::empty();
+    registry.with_deps::<_, ()>().transient(|()| 1_u8);
+    registry.with_deps::<_, ()>().singleton(|()| 2_u16);
+    registry
+        .with_deps::<_, ()>()
+        .try_register_transient(|()| Ok(3_u32));
+    registry.validate_all().unwrap();
+    registry.validate::<u8>().unwrap();
+    registry.validate::<u16>().unwrap();
+
+    assert_eq!(registry.get_transient::<u8>(), Some(1));
+    assert_eq!(*registry.get_singleton::<u16>().unwrap(), 2);
+    assert_eq!(registry.get_transient::<u32>(), Some(3));
+    let graph = registry.dependency_graph();
+    assert!(graph.dependencies_of(TypeId::of::<u8>()).is_empty());
+}
+
+#[test]
+fn registry_builder() {
+    use ferrunix::error::MergeError;
+    use ferrunix::registry::RegistryBuilder;
+
+    let registry = RegistryBuilder::new()
+        .with(|registry| {
+            registry.transient(|| 1_u8);
+            registry
+                .with_deps::<_, (Transient<u8>,)>()
+                .transient(|(num,)| u16::from(num.get()));
+        })
+        .build()
+        .unwrap();
+    assert_eq!(registry.get_transient::<u16>(), Some(1));
+
+    let err = registry.checked_transient(|| 2_u32).unwrap_err();
+    assert!(matches!(err, RegistryError::Frozen { type_name: "u32" }));
+    assert!(registry.merge(Registry::empty()).is_err());
+    assert!(matches!(
+        registry.merge_override(Registry::empty()),
+        Err(MergeError::Frozen)
+    ));
+
+    let builder = RegistryBuilder::default();
+    builder
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| u16::from(num.get()));
+    assert!(builder.build().is_err());
+}
+
+#[test]
+#[should_panic(expected = "registry is frozen")]
+fn registry_builder_frozen() {
+    let registry = ferrunix::registry::RegistryBuilder::new().build().unwrap();
+    registry.transient(|| 1_u8);
+}
+
+#[test]
+fn freeze() {
+    let registry = Registry::empty();
+    registry.transient(|| 1_u8);
+    assert!(!registry.is_frozen());
+
+    registry.freeze();
+    assert!(registry.is_frozen());
+    assert_eq!(registry.get_transient::<u8>(), Some(1));
+    assert!(matches!(
+        registry.checked_transient(|| 2_u16),
+        Err(RegistryError::Frozen { type_name: "u16" })
+    ));
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        registry
+            .with_deps::<_, (Transient<u8>,)>()
+            .transient(|(num,)| u32::from(num.get()));
+    }));
+    assert!(result.is_err());
+    assert!(!registry.contains::<u32>());
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        registry.set_default_provider(|_id, _registry| None);
+    }));
+    assert!(result.is_err());
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        registry.get_or_register_singleton(|| 2_u64);
+    }));
+    assert!(result.is_err());
+    assert!(!registry.contains::<u64>());
+}
+
+#[test]
+fn transient_dyn() {
+    use std::any::TypeId;
+
+    let registry = Registry::empty();
+    registry.transient(|| 1_u8);
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| u16::from(num.get()) + 1);
+    registry.singleton(|| 3_u32);
+
+    let obj = registry.transient_dyn(TypeId::of::<u16>()).unwrap();
+    assert_eq!(obj.downcast_ref::<u16>(), Some(&2));
+
+    let registry = registry.into_ref();
+    let child = registry.child();
+    let obj = child.transient_dyn(TypeId::of::<u8>()).unwrap();
+    assert_eq!(*obj.downcast::<u8>().unwrap(), 1);
+
+    let err = child.transient_dyn(TypeId::of::<u32>()).unwrap_err();
+    assert!(err.is_missing());
+}
+
+#[test]
+fn transient_aliased() {
+    trait Processor {
+        fn name(&self) -> &'static str;
+    }
+
+    struct Paypal;
+
+    impl Processor for Paypal {
+        fn name(&self) -> &'static str {
+            "paypal"
+        }
+    }
+
+    let registry = Registry::empty();
+    registry.register_transient_aliased(
+        || Paypal,
+        |paypal| Box::new(paypal) as Box<dyn Processor>,
+    );
+    registry.validate_all_full().unwrap();
+
+    assert!(registry.get_transient::<Paypal>().is_some());
+    let processor = registry.get_transient::<Box<dyn Processor>>().unwrap();
+    assert_eq!(processor.name(), "paypal");
+}
+
+#[test]
+fn checked_transient_aliased() {
+    let registry = Registry::empty();
+    registry.transient(|| 1_u16);
+
+    let err = registry
+        .checked_register_transient_aliased(|| 2_u8, u16::from)
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        RegistryError::AlreadyRegistered { type_name: "u16" }
+    ));
+    // Nothing is registered, if the alias is registered already.
+    assert!(!registry.contains::<u8>());
+    assert_eq!(registry.get_transient::<u16>(), Some(1));
+
+    registry
+        .checked_register_transient_aliased(|| 2_u8, u32::from)
+        .unwrap();
+    assert_eq!(registry.get_transient::<u32>(), Some(2));
+    registry.validate_all_full().unwrap();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        registry.register_transient_aliased(|| 3_u8, u64::from);
+    }));
+    assert!(result.is_err());
+    assert!(!registry.contains::<u64>());
+}
+
+#[test]
+fn transient_stateful() {
+    let registry = Registry::empty();
+    let mut next_id = 0_u32;
+    registry.register_transient_stateful(move || {
+        next_id += 1;
+        next_id
+    });
+    registry
+        .with_deps::<_, (Transient<u32>,)>()
+        .transient(|(id,)| format!("id-{}", *id));
+
+    assert_eq!(registry.get_transient::<u32>(), Some(1));
+    assert_eq!(registry.get_transient::<u32>(), Some(2));
+    assert_eq!(registry.get_transient::<String>().as_deref(), Some("id-3"));
+    registry.validate_all().unwrap();
+
+    let duplicate = registry.duplicate();
+    assert_eq!(duplicate.get_transient::<u32>(), Some(4));
+    assert_eq!(registry.get_transient::<u32>(), Some(5));
+}
+
+#[test]
+fn dep_group() {
+    use ferrunix::dependencies::DepGroup;
+
+    type Group = DepGroup<(Transient<u8>, Singleton<u16>)>;
+
+    let registry = Registry::empty();
+    registry.with_deps::<_, (Group, Transient<u8>)>().transient(
+        |(group, num)| {
+            let (a, b) = group.get();
+            u32::from(a.get()) + u32::from(*b.get()) + u32::from(num.get())
+        },
+    );
+    registry
+        .with_deps::<_, (DepGroup<(Group,)>,)>()
+        .transient(|(nested,)| {
+            let (group,) = nested.get();
+            let (a, _) = group.get();
+            u64::from(a.get())
+        });
+
+    assert!(registry.validate::<u32>().is_err());
+    assert!(registry.validate::<u64>().is_err());
+
+    registry.transient(|| 1_u8);
+    registry.singleton(|| 2_u16);
+    registry.validate_all_full().unwrap();
+
+    assert_eq!(registry.get_transient::<u32>(), Some(4));
+    assert_eq!(registry.get_transient::<u64>(), Some(1));
+}
+
+#[test]
+fn from_registrations() {
+    use ferrunix::RegistrationFunc;
+
+    fn register_port(registry: &Registry) {
+        registry.transient(|| 8080_u16);
+    }
+
+    let registry = Registry::from_registrations([
+        RegistrationFunc::new(register_port),
+        RegistrationFunc::with_group(register_grouped_plugin, "other"),
+    ]);
+    assert_eq!(registry.get_transient::<u16>(), Some(8080));
+    assert_eq!(registry.get_transient::<GroupedPlugin>().unwrap().0, 1);
+
+    let empty = Registry::from_registrations(Vec::new());
+    assert!(!empty.contains::<u16>());
+}
+
+#[test]
+fn ctor_error_source() {
+    use std::error::Error;
+
+    let registry = Registry::empty();
+    registry
+        .try_register_transient(|| "port".parse::<u16>().map_err(Into::into));
+
+    let err = registry.try_get_transient::<u16>().unwrap_err();
+    assert!(err.is_ctor_err());
+    let ctor = err.source().unwrap();
+    assert!(ctor.to_string().starts_with("constructor failed"));
+    let parse = ctor.source().unwrap();
+    assert!(parse.downcast_ref::<std::num::ParseIntError>().is_some());
+    assert!(parse.source().is_none());
+}
+
+#[test]
+fn named_any() {
+    let registry = Registry::empty();
+    registry.register_named_any("port", || Box::new(8080_u16));
+    registry.register_named_any("host", || Box::new("localhost"));
+
+    let port = registry.resolve_named_any("port").unwrap();
+    assert_eq!(port.downcast_ref::<u16>(), Some(&8080));
+    assert!(registry.resolve_named_any("missing").is_none());
+    assert!(!registry.contains::<u16>());
+
+    let registry = registry.into_ref();
+    let child = registry.child();
+    let host = child.resolve_named_any("host").unwrap();
+    assert_eq!(*host.downcast::<&str>().unwrap(), "localhost");
+
+    let duplicate = child.duplicate();
+    assert!(duplicate.resolve_named_any("port").is_some());
+}
+
+#[test]
+#[should_panic(expected = "key 'port' is already registered")]
+fn named_any_duplicate() {
+    let registry = Registry::empty();
+    registry.register_named_any("port", || Box::new(8080_u16));
+    registry.register_named_any("port", || Box::new(8081_u16));
+}
+
+#[test]
+#[cfg(any(feature = "ref-arc", feature = "multithread"))]
+fn send_singleton_to_thread() {
+    let registry = Registry::empty();
+    registry.singleton(|| String::from("Hello, World"));
+
+    let singleton = registry.get_singleton::<String>().unwrap();
+    let len = std::thread::spawn(move || singleton.len()).join().unwrap();
+    assert_eq!(len, 12);
+}
+
+#[test]
+fn default_provider() {
+    use std::any::TypeId;
+
+    let registry = Registry::empty();
+    registry.transient(|| 1_u8);
+    registry.set_default_provider(|id, _registry| {
+        if id == TypeId::of::<u16>() {
+            return Some(Box::new(8080_u16));
+        }
+        if id == TypeId::of::<u32>() {
+            // Downcasting to the requested type fails.
+            return Some(Box::new(1_u8));
+        }
+        None
+    });
+
+    assert_eq!(registry.get_transient::<u16>(), Some(8080));
+    assert_eq!(registry.get_transient::<u8>(), Some(1));
+    assert!(registry.get_transient::<u64>().is_none());
+    let err = registry.try_get_transient::<u32>().unwrap_err();
+    assert!(err.is_type_mismatch());
+
+    let any = registry.transient_dyn(TypeId::of::<u16>()).unwrap();
+    assert_eq!(any.downcast_ref::<u16>(), Some(&8080));
+
+    // Only unnamed transients are resolved from the fallback.
+    assert!(registry.get_singleton::<u16>().is_none());
+    assert!(registry.get_transient_named::<u16>("port").is_none());
+}
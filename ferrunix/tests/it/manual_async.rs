@@ -1,4 +1,4 @@
-use ferrunix::{Registry, Transient};
+use ferrunix::{Registry, Singleton, Transient};
 
 use crate::common::*;
 
@@ -132,3 +132,1027 @@ async fn test_more_complex() {
 
     result.unwrap();
 }
+
+#[tokio::test]
+async fn test_remove() {
+    let registry = Registry::empty();
+    registry.transient(|| Box::pin(async move { 1_u32 })).await;
+    registry.singleton(|| Box::pin(async move { 1_i64 })).await;
+
+    assert!(registry.remove::<u32>().await);
+    assert!(!registry.remove::<u32>().await);
+    assert!(registry.get_transient::<u32>().await.is_none());
+
+    assert!(registry.remove::<i64>().await);
+    assert!(registry.get_singleton::<i64>().await.is_none());
+}
+
+#[tokio::test]
+async fn test_checked_registration() {
+    let registry = Registry::empty();
+    registry
+        .checked_transient(|| Box::pin(async move { 1_u32 }))
+        .await
+        .unwrap();
+    registry
+        .checked_singleton(|| Box::pin(async move { 1_i64 }))
+        .await
+        .unwrap();
+
+    assert!(registry
+        .checked_transient(|| Box::pin(async move { 2_u32 }))
+        .await
+        .is_err());
+    assert!(registry
+        .checked_singleton(|| Box::pin(async move { 2_i64 }))
+        .await
+        .is_err());
+    assert!(registry
+        .with_deps::<_, (Transient<u32>,)>()
+        .checked_transient(|(x,)| {
+            Box::pin(async move { u64::from(x.get()) })
+        })
+        .await
+        .is_ok());
+
+    let val = registry.get_transient::<u64>().await.unwrap();
+    assert_eq!(val, 1);
+}
+
+#[tokio::test]
+async fn test_contains() {
+    let registry = Registry::empty();
+    registry.transient(|| Box::pin(async move { 1_u32 })).await;
+
+    assert!(registry.contains::<u32>().await);
+    assert!(registry.contains_local::<u32>().await);
+    assert!(registry.contains_id(std::any::TypeId::of::<u32>()).await);
+    assert!(!registry.contains::<u64>().await);
+}
+
+#[tokio::test]
+async fn test_registered_types() {
+    let registry = Registry::empty();
+    assert!(registry.is_empty().await);
+
+    registry.transient(|| Box::pin(async move { 1_u32 })).await;
+
+    assert_eq!(registry.len().await, 1);
+    assert_eq!(
+        registry.registered_types().await,
+        vec![(std::any::TypeId::of::<u32>(), "u32")]
+    );
+}
+
+#[tokio::test]
+async fn test_override() {
+    let registry = Registry::empty();
+    registry.transient(|| Box::pin(async move { 1_u32 })).await;
+    registry.singleton(|| Box::pin(async move { 1_i64 })).await;
+
+    assert_eq!(*registry.get_singleton::<i64>().await.unwrap(), 1);
+
+    registry
+        .override_transient(|| Box::pin(async move { 2_u32 }))
+        .await;
+    registry
+        .override_singleton(|| Box::pin(async move { 2_i64 }))
+        .await;
+
+    assert_eq!(registry.get_transient::<u32>().await.unwrap(), 2);
+    assert_eq!(*registry.get_singleton::<i64>().await.unwrap(), 2);
+}
+
+#[tokio::test]
+async fn test_named() {
+    use ferrunix::dependencies::{DepName, NamedSingleton};
+
+    struct Primary;
+
+    impl DepName for Primary {
+        const NAME: &'static str = "primary";
+    }
+
+    let registry = Registry::empty();
+    registry
+        .transient_named("primary", || Box::pin(async move { 1_u32 }))
+        .await;
+    registry
+        .transient_named("replica", || Box::pin(async move { 2_u32 }))
+        .await;
+    registry
+        .singleton_named("primary", || Box::pin(async move { 3_u16 }))
+        .await;
+    registry
+        .with_deps::<_, (NamedSingleton<u16, Primary>,)>()
+        .transient(|(x,)| Box::pin(async move { u64::from(*x.get()) }))
+        .await;
+
+    registry.validate_all().unwrap();
+
+    let val = registry.get_transient_named::<u32>("primary").await;
+    assert_eq!(val, Some(1));
+    let val = registry.get_transient_named::<u32>("replica").await;
+    assert_eq!(val, Some(2));
+    let val = registry.get_transient::<u32>().await;
+    assert_eq!(val, None);
+    let val = registry.get_transient::<u64>().await;
+    assert_eq!(val, Some(3));
+}
+
+#[tokio::test]
+async fn test_register_all() {
+    let registry = Registry::empty();
+    registry
+        .register_all(|| Box::pin(async move { 1_u32 }))
+        .await;
+    registry
+        .with_deps::<_, (Transient<u16>,)>()
+        .register_all(|(x,)| Box::pin(async move { u32::from(x.get()) }))
+        .await;
+    assert!(registry.validate_all().is_err());
+
+    registry.transient(|| Box::pin(async move { 2_u16 })).await;
+    registry.validate_all().unwrap();
+
+    let all = registry.transient_all::<u32>().await.unwrap();
+    assert_eq!(all, vec![1, 2]);
+    assert!(registry.transient_all::<u64>().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_scoped() {
+    use ferrunix::Ref;
+
+    let registry = Registry::empty();
+    registry
+        .register_scoped(|| Box::pin(async move { 1_u32 }))
+        .await;
+    registry.validate_all().unwrap();
+
+    let scope = registry.enter_scope();
+    assert!(scope.get::<u64>().await.unwrap_err().is_missing());
+
+    let first = scope.get::<u32>().await.unwrap();
+    let second = scope.get::<u32>().await.unwrap();
+    assert_eq!(*first, 1);
+    assert!(Ref::ptr_eq(&first, &second));
+    drop(scope);
+
+    let scope = registry.enter_scope();
+    let third = scope.get::<u32>().await.unwrap();
+    assert!(!Ref::ptr_eq(&first, &third));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_scoped_overlapping() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    use ferrunix::Ref;
+
+    static NEXT: AtomicU32 = AtomicU32::new(0);
+
+    let registry = Arc::new(Registry::empty());
+    registry
+        .register_scoped(|| {
+            Box::pin(async move { NEXT.fetch_add(1, Ordering::SeqCst) })
+        })
+        .await;
+    registry.validate_all().unwrap();
+
+    let barrier = Arc::new(tokio::sync::Barrier::new(2));
+    let handles = (0..2)
+        .map(|_| {
+            let registry = Arc::clone(&registry);
+            let barrier = Arc::clone(&barrier);
+            tokio::spawn(async move {
+                let scope = registry.enter_scope();
+                let first = scope.get::<u32>().await.unwrap();
+                // Both scopes are active at the same time.
+                barrier.wait().await;
+                let second = scope.get::<u32>().await.unwrap();
+                assert!(Ref::ptr_eq(&first, &second));
+                *first
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut ids = Vec::new();
+    for handle in handles {
+        ids.push(handle.await.unwrap());
+    }
+    ids.sort_unstable();
+    assert_eq!(ids, vec![0, 1]);
+}
+
+#[tokio::test]
+async fn test_register_instance() {
+    let registry = Registry::empty();
+    registry
+        .register_instance(String::from("Hello, World"))
+        .await;
+    registry.validate_all().unwrap();
+
+    let value = registry.get_singleton::<String>().await.unwrap();
+    assert_eq!(*value, "Hello, World");
+}
+
+#[tokio::test]
+async fn test_register_singleton_eager() {
+    let registry = Registry::empty();
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .register_singleton_eager(|(num,)| {
+            Box::pin(async move { u32::from(num.get()) })
+        })
+        .await
+        .unwrap_err();
+    assert!(!registry.contains::<u32>().await);
+    registry.validate_all().unwrap();
+
+    registry.transient(|| Box::pin(async move { 8_u8 })).await;
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .register_singleton_eager(|(num,)| {
+            Box::pin(async move { u32::from(num.get()) })
+        })
+        .await
+        .unwrap();
+    registry.validate_all().unwrap();
+
+    let value = registry.get_singleton::<u32>().await.unwrap();
+    assert_eq!(*value, 8);
+}
+
+#[tokio::test]
+async fn test_get_or_register_singleton() {
+    use ferrunix::Ref;
+
+    let registry = Registry::empty();
+    let first = registry
+        .get_or_register_singleton(|| Box::pin(async move { 1_u32 }))
+        .await;
+    let second = registry
+        .get_or_register_singleton(|| Box::pin(async move { 2_u32 }))
+        .await;
+    assert_eq!(*first, 1);
+    assert!(Ref::ptr_eq(&first, &second));
+}
+
+#[tokio::test]
+async fn test_merge() {
+    let db = Registry::empty();
+    db.transient(|| Box::pin(async move { 1_u8 })).await;
+
+    let http = Registry::empty();
+    http.with_deps::<_, (Transient<u8>,)>()
+        .transient(|(x,)| Box::pin(async move { u16::from(x.get()) }))
+        .await;
+
+    let root = Registry::empty();
+    root.merge(db).await.unwrap();
+    root.merge(http).await.unwrap();
+    root.validate_all().unwrap();
+    assert_eq!(root.get_transient::<u16>().await, Some(1));
+
+    let conflicting = Registry::empty();
+    conflicting
+        .transient(|| Box::pin(async move { 2_u8 }))
+        .await;
+    assert!(root.merge(conflicting).await.is_err());
+
+    let overriding = Registry::empty();
+    overriding.transient(|| Box::pin(async move { 3_u8 })).await;
+    root.merge_override(overriding).await.unwrap();
+    assert_eq!(root.get_transient::<u16>().await, Some(3));
+}
+
+#[tokio::test]
+async fn test_resolve_error_dependency_chain() {
+    let registry = Registry::empty();
+    registry
+        .with_deps::<_, (Transient<u16>,)>()
+        .transient(|(num,)| Box::pin(async move { u32::from(num.get()) }))
+        .await;
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| Box::pin(async move { u16::from(num.get()) }))
+        .await;
+
+    let err = registry.try_get_transient::<u32>().await.unwrap_err();
+    assert_eq!(err.chain(), &["u32", "u16", "u8"]);
+
+    registry.transient(|| Box::pin(async move { 1_u8 })).await;
+    assert_eq!(registry.try_get_transient::<u32>().await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_invalidate_singleton() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    static CONSTRUCTED: AtomicU32 = AtomicU32::new(0);
+
+    let registry = Registry::empty();
+    registry
+        .singleton(|| {
+            Box::pin(async move { CONSTRUCTED.fetch_add(1, Ordering::SeqCst) })
+        })
+        .await;
+
+    assert!(!registry.invalidate_singleton::<u32>().await);
+    assert_eq!(*registry.get_singleton::<u32>().await.unwrap(), 0);
+    assert!(registry.invalidate_singleton::<u32>().await);
+    assert_eq!(*registry.get_singleton::<u32>().await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_fresh_autoregistered() {
+    let first = Registry::fresh_autoregistered().await;
+    let second = Registry::fresh_autoregistered().await;
+    assert!(!ferrunix::Ref::ptr_eq(&first, &second));
+
+    first.transient(|| Box::pin(async move { 1_u8 })).await;
+    assert!(first.contains::<u8>().await);
+    assert!(!second.contains::<u8>().await);
+}
+
+#[tokio::test]
+async fn test_autoregistered_lenient() {
+    let (registry, errors) = Registry::autoregistered_lenient().await;
+    assert!(errors.is_empty());
+    assert!(!registry.contains::<u8>().await);
+}
+
+#[tokio::test]
+async fn test_singleton_with_timeout() {
+    use ferrunix::error::ResolveError;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+
+    static ATTEMPTS: AtomicU32 = AtomicU32::new(0);
+
+    let registry = Registry::empty();
+    registry
+        .register_singleton_with_timeout(Duration::from_millis(10), || {
+            Box::pin(async move {
+                if ATTEMPTS.fetch_add(1, Ordering::SeqCst) == 0 {
+                    tokio::time::sleep(Duration::from_secs(60)).await;
+                }
+                1_u32
+            })
+        })
+        .await;
+
+    let err = registry.try_get_singleton::<u32>().await.unwrap_err();
+    assert!(matches!(err, ResolveError::ConstructionTimeout));
+
+    // The timed out construction isn't cached, it's retried.
+    assert_eq!(*registry.get_singleton::<u32>().await.unwrap(), 1);
+    assert_eq!(ATTEMPTS.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_validate_after_registering_missing() {
+    let registry = Registry::empty();
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| Box::pin(async move { u16::from(num.get()) }))
+        .await;
+
+    registry.validate_all().unwrap_err();
+    registry.validate_all_full().unwrap_err();
+
+    registry.transient(|| Box::pin(async move { 1_u8 })).await;
+    registry.validate_all().unwrap();
+    registry.validate_all_full().unwrap();
+    assert_eq!(registry.get_transient::<u16>().await, Some(1));
+}
+
+#[tokio::test]
+async fn test_register_factory() {
+    #[derive(Debug, PartialEq)]
+    struct Connection {
+        url: String,
+        retries: u8,
+    }
+
+    let registry = Registry::empty();
+    registry.transient(|| Box::pin(async move { 3_u8 })).await;
+    registry
+        .register_factory(|registry: &Registry, url: String| {
+            Box::pin(async move {
+                Connection {
+                    url,
+                    retries: registry.get_transient::<u8>().await.unwrap(),
+                }
+            })
+        })
+        .await;
+
+    let conn = registry
+        .make::<Connection, _>("db://localhost".to_owned())
+        .await
+        .unwrap();
+    assert_eq!(conn.url, "db://localhost");
+    assert_eq!(conn.retries, 3);
+
+    let other = registry
+        .make::<Connection, _>("db://remote".to_owned())
+        .await;
+    assert_eq!(other.unwrap().url, "db://remote");
+
+    registry.make::<Connection, _>(1_u32).await.unwrap_err();
+    registry.make::<u16, _>(()).await.unwrap_err();
+}
+
+#[tokio::test]
+async fn test_shutdown_order() {
+    let registry = Registry::empty();
+    registry.singleton(|| Box::pin(async move { 1_u8 })).await;
+    registry
+        .with_deps::<_, (Singleton<u8>,)>()
+        .singleton(|(num,)| Box::pin(async move { u16::from(*num.get()) + 1 }))
+        .await;
+    registry.singleton(|| Box::pin(async move { 3_u32 })).await;
+
+    assert_eq!(*registry.get_singleton::<u16>().await.unwrap(), 2);
+    let mut constructed = 0_usize;
+    registry.for_each_singleton(|_| constructed += 1).await;
+    assert_eq!(constructed, 2);
+
+    let mut order = registry.shutdown_order().await.unwrap().into_iter();
+    assert_eq!(order.next().unwrap().downcast_ref::<u16>(), Some(&2));
+    assert_eq!(order.next().unwrap().downcast_ref::<u8>(), Some(&1));
+    assert!(order.next().is_none());
+}
+
+#[tokio::test]
+async fn test_initialize_all_singletons() {
+    let registry = Registry::empty();
+    registry.singleton(|| Box::pin(async move { 1_u8 })).await;
+    registry
+        .with_deps::<_, (Singleton<u8>,)>()
+        .singleton(|(num,)| Box::pin(async move { u16::from(*num.get()) + 1 }))
+        .await;
+    registry.transient(|| Box::pin(async move { 3_u32 })).await;
+
+    registry.initialize_all_singletons().await.unwrap();
+    let mut order = registry.shutdown_order().await.unwrap().into_iter();
+    assert_eq!(order.next().unwrap().downcast_ref::<u16>(), Some(&2));
+    assert_eq!(order.next().unwrap().downcast_ref::<u8>(), Some(&1));
+    assert!(order.next().is_none());
+}
+
+#[tokio::test]
+async fn test_conditional_registration() {
+    let registry = Registry::empty();
+    assert!(
+        !registry
+            .register_transient_if(false, || Box::pin(async move { 1_u8 }))
+            .await
+    );
+    assert!(
+        registry
+            .register_singleton_if(true, || Box::pin(async move { 2_u16 }))
+            .await
+    );
+    assert!(registry.get_transient::<u8>().await.is_none());
+    assert_eq!(*registry.get_singleton::<u16>().await.unwrap(), 2);
+
+    assert!(
+        registry
+            .register_first_available(&[
+                (false, || Box::pin(async move { 3_u32 })),
+                (true, || Box::pin(async move { 4_u32 })),
+            ])
+            .await
+    );
+    assert_eq!(registry.get_transient::<u32>().await, Some(4));
+}
+
+#[tokio::test]
+async fn test_lazy_dependency() {
+    use ferrunix::dependencies::Lazy;
+
+    let registry = Registry::empty();
+    registry.transient(|| Box::pin(async move { 1_u8 })).await;
+    registry
+        .with_deps::<_, (Lazy<u8>,)>()
+        .transient(|(num,)| Box::pin(async move { num }))
+        .await;
+    registry.validate_all().unwrap();
+
+    let lazy = registry.get_transient::<Lazy<u8>>().await.unwrap();
+    assert!(lazy.get_if_init().is_none());
+    assert_eq!(lazy.get(&registry).await.unwrap(), &1);
+
+    let num = registry.resolve_dep::<Transient<u8>>().await.unwrap();
+    assert_eq!(num.get(), 1);
+}
+
+#[tokio::test]
+async fn test_duplicate() {
+    use ferrunix::Ref;
+
+    let registry = Registry::empty();
+    registry.transient(|| Box::pin(async move { 1_u8 })).await;
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .singleton(|(num,)| Box::pin(async move { u16::from(*num) + 1 }))
+        .await;
+
+    let first = registry.get_singleton::<u16>().await.unwrap();
+    let duplicate = registry.duplicate().await;
+    duplicate.validate_all().unwrap();
+    assert!(duplicate.peek_singleton::<u16>().await.is_none());
+
+    let second = duplicate.get_singleton::<u16>().await.unwrap();
+    assert_eq!(*second, 2);
+    assert!(!Ref::ptr_eq(&first, &second));
+}
+
+#[tokio::test]
+async fn test_registration_handle() {
+    let registry = Registry::empty();
+    let handle = registry
+        .transient_handle(|| Box::pin(async move { 1_u8 }))
+        .await;
+
+    handle
+        .override_transient(|| Box::pin(async move { 2 }))
+        .await;
+    assert_eq!(registry.get_transient::<u8>().await, Some(2));
+    assert!(handle.remove().await);
+    assert!(!registry.contains::<u8>().await);
+}
+
+#[tokio::test]
+async fn test_register_default() {
+    use ferrunix::Ref;
+
+    let registry = Registry::empty();
+    registry.register_transient_default::<u8>().await;
+    registry.register_singleton_default::<String>().await;
+    registry.validate_all().unwrap();
+
+    assert_eq!(registry.get_transient::<u8>().await, Some(0));
+    let first = registry.get_singleton::<String>().await.unwrap();
+    assert!(first.is_empty());
+    let second = registry.get_singleton::<String>().await.unwrap();
+    assert!(Ref::ptr_eq(&first, &second));
+}
+
+#[tokio::test]
+async fn test_fallible_constructors() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    static ATTEMPTS: AtomicU32 = AtomicU32::new(0);
+
+    let registry = Registry::empty();
+    registry
+        .try_register_transient(|| {
+            Box::pin(async move { "8080".parse::<u16>().map_err(Into::into) })
+        })
+        .await;
+    registry
+        .with_deps::<_, (Transient<u16>,)>()
+        .try_register_transient(|(port,)| {
+            Box::pin(async move { u8::try_from(*port).map_err(Into::into) })
+        })
+        .await;
+    registry
+        .try_register_singleton(|| {
+            Box::pin(async move {
+                // Fails on the first attempt only.
+                match ATTEMPTS.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err("not yet".into()),
+                    attempt => Ok(attempt),
+                }
+            })
+        })
+        .await;
+    registry
+        .with_deps::<_, (Singleton<u32>,)>()
+        .try_register_singleton(|(attempt,)| {
+            Box::pin(async move { Ok(u64::from(*attempt)) })
+        })
+        .await;
+    registry.validate_all().unwrap();
+
+    assert_eq!(registry.get_transient::<u16>().await, Some(8080));
+    let err = registry.try_get_transient::<u8>().await.unwrap_err();
+    assert_eq!(err.chain(), &["u8"]);
+    assert!(err.to_string().contains("constructor failed"));
+
+    let err = registry.try_get_singleton::<u64>().await.unwrap_err();
+    assert_eq!(err.chain(), &["u64", "u32"]);
+    assert!(registry.peek_singleton::<u32>().await.is_none());
+    assert_eq!(*registry.get_singleton::<u64>().await.unwrap(), 1);
+    assert_eq!(*registry.get_singleton::<u32>().await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_singleton_owned() {
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    struct Config {
+        port: u16,
+    }
+
+    let registry = Registry::empty();
+    registry
+        .singleton(|| Box::pin(async move { Config { port: 8080 } }))
+        .await;
+
+    let config = registry.singleton_owned::<Config>().await.unwrap();
+    assert_eq!(config, Config { port: 8080 });
+    assert!(registry.singleton_owned::<u8>().await.is_err());
+}
+
+#[tokio::test]
+async fn test_with_empty_deps() {
+    let registry = Registry::empty();
+    registry
+        .with_deps::<_, ()>()
+        .transient(|()| Box::pin(async move { 1_u8 }))
+        .await;
+    registry
+        .with_deps::<_, ()>()
+        .singleton(|()| Box::pin(async move { 2_u16 }))
+        .await;
+    registry.validate_all().unwrap();
+    registry.validate::<u8>().unwrap();
+
+    assert_eq!(registry.get_transient::<u8>().await, Some(1));
+    assert_eq!(*registry.get_singleton::<u16>().await.unwrap(), 2);
+}
+
+#[tokio::test]
+async fn test_registry_builder() {
+    use ferrunix::error::RegistryError;
+    use ferrunix::registry::RegistryBuilder;
+
+    let registry = RegistryBuilder::new()
+        .with_async(|registry| {
+            Box::pin(async move {
+                registry.transient(|| Box::pin(async move { 1_u8 })).await;
+                registry
+                    .with_deps::<_, (Transient<u8>,)>()
+                    .transient(|(num,)| {
+                        Box::pin(async move { u16::from(num.get()) })
+                    })
+                    .await;
+            })
+        })
+        .await
+        .build()
+        .unwrap();
+    assert_eq!(registry.get_transient::<u16>().await, Some(1));
+
+    let err = registry
+        .checked_transient(|| Box::pin(async move { 2_u32 }))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RegistryError::Frozen { type_name: "u32" }));
+
+    let builder = RegistryBuilder::default();
+    builder.transient(|| Box::pin(async move { 1_u8 })).await;
+    let registry = builder.build().unwrap();
+    assert_eq!(registry.get_transient::<u8>().await, Some(1));
+
+    let builder = RegistryBuilder::default();
+    builder
+        .with_deps::<_, (Transient<u8>,)>()
+        .transient(|(num,)| Box::pin(async move { u16::from(num.get()) }))
+        .await;
+    assert!(builder.build().is_err());
+}
+
+#[tokio::test]
+async fn test_freeze() {
+    use ferrunix::error::RegistryError;
+
+    let registry = Registry::empty();
+    registry.transient(|| Box::pin(async move { 1_u8 })).await;
+    registry.freeze();
+    assert!(registry.is_frozen());
+    assert_eq!(registry.get_transient::<u8>().await, Some(1));
+
+    let err = registry
+        .checked_transient(|| Box::pin(async move { 2_u16 }))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RegistryError::Frozen { type_name: "u16" }));
+}
+
+#[tokio::test]
+async fn test_transient_dyn() {
+    use std::any::TypeId;
+
+    let registry = Registry::empty();
+    registry.transient(|| Box::pin(async move { 1_u8 })).await;
+    registry.singleton(|| Box::pin(async move { 2_u32 })).await;
+
+    let obj = registry.transient_dyn(TypeId::of::<u8>()).await.unwrap();
+    assert_eq!(obj.downcast_ref::<u8>(), Some(&1));
+
+    let err = registry
+        .transient_dyn(TypeId::of::<u32>())
+        .await
+        .unwrap_err();
+    assert!(err.is_missing());
+}
+
+#[tokio::test]
+async fn test_transient_aliased() {
+    trait Processor: Send + Sync {
+        fn name(&self) -> &'static str;
+    }
+
+    struct Paypal;
+
+    impl Processor for Paypal {
+        fn name(&self) -> &'static str {
+            "paypal"
+        }
+    }
+
+    let registry = Registry::empty();
+    registry
+        .register_transient_aliased(
+            || Box::pin(async move { Paypal }),
+            |paypal| Box::new(paypal) as Box<dyn Processor>,
+        )
+        .await;
+    registry.validate_all_full().unwrap();
+
+    assert!(registry.get_transient::<Paypal>().await.is_some());
+    let processor = registry
+        .get_transient::<Box<dyn Processor>>()
+        .await
+        .unwrap();
+    assert_eq!(processor.name(), "paypal");
+}
+
+#[tokio::test]
+async fn test_checked_transient_aliased() {
+    use ferrunix::error::RegistryError;
+
+    let registry = Registry::empty();
+    registry.transient(|| Box::pin(async move { 1_u16 })).await;
+
+    let err = registry
+        .checked_register_transient_aliased(
+            || Box::pin(async move { 2_u8 }),
+            u16::from,
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        RegistryError::AlreadyRegistered { type_name: "u16" }
+    ));
+    assert!(!registry.contains::<u8>().await);
+
+    registry
+        .checked_register_transient_aliased(
+            || Box::pin(async move { 2_u8 }),
+            u32::from,
+        )
+        .await
+        .unwrap();
+    assert_eq!(registry.get_transient::<u32>().await, Some(2));
+    registry.validate_all_full().unwrap();
+}
+
+#[tokio::test]
+async fn test_register_upcast() {
+    trait BaseService: Send + Sync {
+        fn name(&self) -> &'static str;
+    }
+
+    trait SpecificService: BaseService {
+        fn into_base(self: Box<Self>) -> Box<dyn BaseService>;
+    }
+
+    struct Service;
+
+    impl BaseService for Service {
+        fn name(&self) -> &'static str {
+            "service"
+        }
+    }
+
+    impl SpecificService for Service {
+        fn into_base(self: Box<Self>) -> Box<dyn BaseService> {
+            self
+        }
+    }
+
+    let registry = Registry::empty();
+    registry
+        .transient::<Box<dyn SpecificService>>(|| {
+            Box::pin(async move {
+                let service: Box<dyn SpecificService> = Box::new(Service);
+                service
+            })
+        })
+        .await;
+    registry
+        .register_upcast::<Box<dyn SpecificService>, _>(|service| {
+            service.into_base()
+        })
+        .await;
+    registry.validate_all_full().unwrap();
+
+    let base = registry
+        .get_transient::<Box<dyn BaseService>>()
+        .await
+        .unwrap();
+    assert_eq!(base.name(), "service");
+}
+
+#[tokio::test]
+async fn test_transient_stateful() {
+    let registry = Registry::empty();
+    let mut next_id = 0_u32;
+    registry
+        .register_transient_stateful(move || {
+            next_id += 1;
+            let id = next_id;
+            Box::pin(async move { id })
+        })
+        .await;
+
+    assert_eq!(registry.get_transient::<u32>().await, Some(1));
+    assert_eq!(registry.get_transient::<u32>().await, Some(2));
+    registry.validate_all().unwrap();
+}
+
+#[tokio::test]
+async fn test_dep_group() {
+    use ferrunix::dependencies::DepGroup;
+
+    let registry = Registry::empty();
+    registry.transient(|| Box::pin(async move { 1_u8 })).await;
+    registry.singleton(|| Box::pin(async move { 2_u16 })).await;
+    registry
+        .with_deps::<_, (DepGroup<(Transient<u8>, Singleton<u16>)>,)>()
+        .transient(|(group,)| {
+            Box::pin(async move {
+                let (a, b) = group.get();
+                u32::from(a.get()) + u32::from(*b.get())
+            })
+        })
+        .await;
+    registry.validate_all_full().unwrap();
+
+    assert_eq!(registry.get_transient::<u32>().await, Some(3));
+}
+
+#[tokio::test]
+async fn test_from_registrations() {
+    use ferrunix::RegistrationFunc;
+
+    fn register_port(
+        registry: &Registry,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send + '_>>
+    {
+        Box::pin(async move {
+            registry
+                .transient(|| Box::pin(async move { 8080_u16 }))
+                .await;
+        })
+    }
+
+    let registry =
+        Registry::from_registrations([RegistrationFunc::new(register_port)])
+            .await;
+    assert_eq!(registry.get_transient::<u16>().await, Some(8080));
+}
+
+#[tokio::test]
+async fn test_named_any() {
+    let registry = Registry::empty();
+    registry.register_named_any("port", || Box::new(8080_u16));
+
+    let port = registry.resolve_named_any("port").unwrap();
+    assert_eq!(port.downcast_ref::<u16>(), Some(&8080));
+    assert!(registry.resolve_named_any("missing").is_none());
+}
+
+#[tokio::test]
+async fn test_construct_many() {
+    use std::any::TypeId;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use futures_util::StreamExt;
+
+    static CONSTRUCTED: AtomicUsize = AtomicUsize::new(0);
+
+    let registry = Registry::empty();
+    registry
+        .singleton(|| {
+            Box::pin(async move {
+                CONSTRUCTED.fetch_add(1, Ordering::SeqCst);
+                1_u8
+            })
+        })
+        .await;
+    registry
+        .with_deps::<_, (Singleton<u8>,)>()
+        .singleton(|(x,)| Box::pin(async move { u16::from(**x) + 1 }))
+        .await;
+    registry
+        .with_deps::<_, (Singleton<u8>,)>()
+        .singleton(|(x,)| Box::pin(async move { u32::from(**x) + 2 }))
+        .await;
+    registry.validate_all_full().unwrap();
+
+    let ids = [
+        TypeId::of::<u16>(),
+        TypeId::of::<u32>(),
+        TypeId::of::<u64>(),
+    ];
+    let constructed = registry
+        .construct_many(&ids)
+        .collect::<std::collections::HashMap<_, _>>()
+        .await;
+    assert_eq!(constructed.len(), 3);
+
+    let u16_resolved = constructed.get(&TypeId::of::<u16>()).unwrap();
+    let u16_resolved = u16_resolved.as_ref().unwrap();
+    assert_eq!(u16_resolved.downcast_ref::<u16>(), Some(&2));
+    let u32_resolved = constructed.get(&TypeId::of::<u32>()).unwrap();
+    let u32_resolved = u32_resolved.as_ref().unwrap();
+    assert_eq!(u32_resolved.downcast_ref::<u32>(), Some(&3));
+    let u64_resolved = constructed.get(&TypeId::of::<u64>()).unwrap();
+    assert!(u64_resolved.as_ref().unwrap_err().is_missing());
+
+    // The shared dependency is only constructed once.
+    assert_eq!(CONSTRUCTED.load(Ordering::SeqCst), 1);
+    assert_eq!(*registry.get_singleton::<u16>().await.unwrap(), 2);
+}
+
+#[tokio::test]
+async fn test_default_provider() {
+    use std::any::TypeId;
+
+    let registry = Registry::empty();
+    registry.set_default_provider(|id, _registry| {
+        if id == TypeId::of::<u16>() {
+            return Some(Box::new(8080_u16));
+        }
+        None
+    });
+
+    assert_eq!(registry.get_transient::<u16>().await, Some(8080));
+    assert!(registry.get_transient::<u64>().await.is_none());
+    assert!(registry.get_singleton::<u16>().await.is_none());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_resolve_during_pending_registration() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+
+    use ferrunix::dependencies::RegistryRef;
+
+    static CONSTRUCTING: AtomicBool = AtomicBool::new(false);
+
+    let registry = Registry::root();
+    registry.singleton(|| Box::pin(async move { 1_u8 })).await;
+    registry
+        .with_deps::<_, (RegistryRef,)>()
+        .singleton(|(registry,)| {
+            Box::pin(async move {
+                CONSTRUCTING.store(true, Ordering::SeqCst);
+                // Give the registration time to wait for the exclusive lock.
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                let dep = registry.get_singleton::<u8>().await.unwrap();
+                u16::from(*dep)
+            })
+        })
+        .await;
+
+    let registering = {
+        let registry = ferrunix::Ref::clone(&registry);
+        tokio::spawn(async move {
+            while !CONSTRUCTING.load(Ordering::SeqCst) {
+                tokio::task::yield_now().await;
+            }
+            registry.transient(|| Box::pin(async move { 2_u32 })).await;
+        })
+    };
+
+    let resolved = tokio::time::timeout(
+        Duration::from_secs(5),
+        registry.get_singleton::<u16>(),
+    )
+    .await
+    .expect("resolving deadlocked behind the pending registration");
+    assert_eq!(resolved.as_deref(), Some(&1));
+
+    registering.await.unwrap();
+    assert_eq!(registry.get_transient::<u32>().await, Some(2));
+}
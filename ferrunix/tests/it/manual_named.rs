@@ -0,0 +1,123 @@
+#![allow(clippy::unwrap_used)]
+
+use ferrunix::dependencies::{DepName, NamedSingleton, NamedTransient};
+use ferrunix::{Registry, Transient};
+
+struct Primary;
+
+impl DepName for Primary {
+    const NAME: &'static str = "primary";
+}
+
+struct Replica;
+
+impl DepName for Replica {
+    const NAME: &'static str = "replica";
+}
+
+trait Database {
+    fn url(&self) -> &'static str;
+}
+
+struct PrimaryDatabase;
+
+impl Database for PrimaryDatabase {
+    fn url(&self) -> &'static str {
+        "postgres://primary"
+    }
+}
+
+struct ReplicaDatabase;
+
+impl Database for ReplicaDatabase {
+    fn url(&self) -> &'static str {
+        "postgres://replica"
+    }
+}
+
+#[test]
+fn named_registrations() {
+    let registry = Registry::empty();
+    registry.transient_named::<Box<dyn Database>>("primary", || {
+        Box::new(PrimaryDatabase)
+    });
+    registry.transient_named::<Box<dyn Database>>("replica", || {
+        Box::new(ReplicaDatabase)
+    });
+    registry.singleton_named("primary", || 1_u8);
+    registry.singleton_named("replica", || 2_u8);
+
+    registry.validate_all().unwrap();
+
+    let primary = registry
+        .get_transient_named::<Box<dyn Database>>("primary")
+        .unwrap();
+    assert_eq!(primary.url(), "postgres://primary");
+    let replica = registry
+        .get_transient_named::<Box<dyn Database>>("replica")
+        .unwrap();
+    assert_eq!(replica.url(), "postgres://replica");
+
+    assert_eq!(*registry.get_singleton_named::<u8>("primary").unwrap(), 1);
+    assert_eq!(*registry.get_singleton_named::<u8>("replica").unwrap(), 2);
+
+    assert!(registry.get_transient::<Box<dyn Database>>().is_none());
+    assert!(registry.get_singleton::<u8>().is_none());
+    assert!(registry
+        .get_transient_named::<Box<dyn Database>>("analytics")
+        .is_none());
+}
+
+#[test]
+fn named_dependencies() {
+    let registry = Registry::empty();
+    registry.transient(|| 10_u8);
+    registry.transient_named("primary", || 1_u8);
+    registry.singleton_named("replica", || 2_u16);
+
+    registry
+        .with_deps::<_, (
+            Transient<u8>,
+            NamedTransient<u8, Primary>,
+            NamedSingleton<u16, Replica>,
+        )>()
+        .transient(|(unnamed, primary, replica)| {
+            u32::from(unnamed.get())
+                + u32::from(primary.get())
+                + u32::from(*replica.get())
+        });
+    registry
+        .with_deps::<_, (NamedTransient<u8, Primary>,)>()
+        .singleton_named("primary", |(primary,)| u64::from(primary.get()));
+
+    registry.validate_all().unwrap();
+
+    assert_eq!(registry.get_transient::<u32>(), Some(13));
+    assert_eq!(*registry.get_singleton_named::<u64>("primary").unwrap(), 1);
+}
+
+#[test]
+fn named_dependencies_missing() {
+    let registry = Registry::empty();
+    registry.transient(|| 1_u8);
+    registry
+        .with_deps::<_, (NamedTransient<u8, Primary>,)>()
+        .transient(|(primary,)| u16::from(primary.get()));
+
+    assert!(registry.validate_all().is_err());
+    assert_eq!(registry.get_transient::<u16>(), None);
+
+    registry.transient_named("primary", || 2_u8);
+
+    registry.validate_all().unwrap();
+    assert_eq!(registry.get_transient::<u16>(), Some(2));
+}
+
+#[test]
+#[should_panic]
+#[allow(clippy::should_panic_without_expect)]
+fn panic_when_registered_twice_named() {
+    let registry = Registry::empty();
+    registry.transient_named("primary", || 1_u8);
+    registry.transient_named("primary", || 1_u8);
+}
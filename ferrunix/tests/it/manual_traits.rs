@@ -93,3 +93,53 @@ fn registry_dyn_traits() {
 
     result.unwrap();
 }
+
+trait BaseService: Send + Sync {
+    fn name(&self) -> &'static str;
+}
+
+trait SpecificService: BaseService {
+    fn id(&self) -> u32;
+    fn into_base(self: Box<Self>) -> Box<dyn BaseService>;
+}
+
+struct Service;
+
+impl BaseService for Service {
+    fn name(&self) -> &'static str {
+        "service"
+    }
+}
+
+impl SpecificService for Service {
+    fn id(&self) -> u32 {
+        7
+    }
+
+    fn into_base(self: Box<Self>) -> Box<dyn BaseService> {
+        self
+    }
+}
+
+#[test]
+fn register_upcast() {
+    let registry = Registry::empty();
+    registry.register_upcast::<Box<dyn SpecificService>, _>(|service| {
+        service.into_base()
+    });
+    assert!(
+        registry.validate_all().is_err(),
+        "should fail due to the missing sub trait registration"
+    );
+    assert!(registry.get_transient::<Box<dyn BaseService>>().is_none());
+
+    registry.transient::<Box<dyn SpecificService>>(|| Box::new(Service));
+    registry.validate_all().unwrap();
+
+    let specific = registry
+        .get_transient::<Box<dyn SpecificService>>()
+        .unwrap();
+    assert_eq!(specific.id(), 7);
+    let base = registry.get_transient::<Box<dyn BaseService>>().unwrap();
+    assert_eq!(base.name(), "service");
+}
@@ -1,7 +1,7 @@
-#![cfg(all(feature = "multithread", not(feature = "tokio")))]
+#![cfg(all(feature = "multithread", not(feature = "async")))]
 use std::sync::Arc;
 
-use ferrunix::Registry;
+use ferrunix::{Registry, Transient};
 
 macro_rules! make_type {
     ($base:ident) => {
@@ -215,3 +215,231 @@ fn stress_registration() {
     registry.validate_all().unwrap();
     // println!("{}", registry.dotgraph().unwrap());
 }
+
+#[test]
+fn stress_get_or_register_singleton() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CONSTRUCTED: AtomicUsize = AtomicUsize::new(0);
+
+    let registry = Arc::new(Registry::empty());
+    let mut handles = Vec::new();
+    for _ in 0..8_u8 {
+        let registry = Arc::clone(&registry);
+        handles.push(std::thread::spawn(move || {
+            registry.get_or_register_singleton(|| {
+                CONSTRUCTED.fetch_add(1, Ordering::SeqCst);
+                String::from("Hello, World")
+            })
+        }));
+    }
+
+    let mut values = Vec::new();
+    for handle in handles {
+        values.push(handle.join().unwrap());
+    }
+
+    let expected = registry.get_singleton::<String>().unwrap();
+    for value in &values {
+        assert!(Arc::ptr_eq(value, &expected));
+    }
+
+    assert_eq!(CONSTRUCTED.load(Ordering::SeqCst), 1);
+    registry.validate_all().unwrap();
+}
+
+#[test]
+fn stress_overlapping_scopes() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Barrier;
+
+    static NEXT: AtomicU32 = AtomicU32::new(0);
+
+    struct RequestId(u32);
+
+    let registry = Arc::new(Registry::empty());
+    registry.register_scoped(|| RequestId(NEXT.fetch_add(1, Ordering::SeqCst)));
+    registry.validate_all().unwrap();
+
+    let barrier = Arc::new(Barrier::new(2));
+    let handles = (0..2)
+        .map(|_| {
+            let registry = Arc::clone(&registry);
+            let barrier = Arc::clone(&barrier);
+            std::thread::spawn(move || {
+                let scope = registry.enter_scope();
+                let first = scope.get::<RequestId>().unwrap();
+                // Both scopes are active at the same time.
+                barrier.wait();
+                let second = scope.get::<RequestId>().unwrap();
+                assert!(Arc::ptr_eq(&first, &second));
+                first.0
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut ids = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect::<Vec<_>>();
+    ids.sort_unstable();
+    assert_eq!(ids, vec![0, 1]);
+}
+
+#[test]
+fn stress_constructed_singleton() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CONSTRUCTED: AtomicUsize = AtomicUsize::new(0);
+
+    let registry = Arc::new(Registry::empty());
+    registry.transient(|| 1_u8);
+    registry
+        .with_deps::<_, (Transient<u8>,)>()
+        .singleton(|(num,)| {
+            CONSTRUCTED.fetch_add(1, Ordering::SeqCst);
+            u16::from(num.get())
+        });
+    let expected = registry.get_singleton::<u16>().unwrap();
+
+    let mut handles = Vec::new();
+    for _ in 0..16_u8 {
+        let registry = Arc::clone(&registry);
+        let expected = Arc::clone(&expected);
+        handles.push(std::thread::spawn(move || {
+            for _ in 0..10_000_u32 {
+                let value = registry.get_singleton::<u16>().unwrap();
+                assert!(Arc::ptr_eq(&value, &expected));
+            }
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(CONSTRUCTED.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn stress_register_while_resolving() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+
+    static MID_ENTERED: AtomicBool = AtomicBool::new(false);
+
+    #[derive(Debug)]
+    struct Leaf;
+    #[derive(Debug)]
+    struct Mid;
+    #[derive(Debug)]
+    struct Root;
+
+    let registry = Arc::new(Registry::empty());
+    register_all_types!(manytypes0, registry);
+    registry.transient(|| Leaf);
+    registry
+        .with_deps::<_, (Transient<Leaf>,)>()
+        .transient(|_| {
+            // Give the registering thread time to wait for the exclusive
+            // lock, before `Root` resolves its next dependency.
+            MID_ENTERED.store(true, Ordering::SeqCst);
+            std::thread::sleep(Duration::from_millis(100));
+            Mid
+        });
+    registry
+        .with_deps::<_, (Transient<Mid>, Transient<Leaf>)>()
+        .transient(|_| Root);
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut handles = Vec::new();
+    {
+        let registry = Arc::clone(&registry);
+        let sender = sender.clone();
+        handles.push(std::thread::spawn(move || {
+            registry.get_transient::<Root>().unwrap();
+            sender.send(()).unwrap();
+        }));
+    }
+    {
+        let registry = Arc::clone(&registry);
+        let sender = sender.clone();
+        handles.push(std::thread::spawn(move || {
+            while !MID_ENTERED.load(Ordering::SeqCst) {
+                std::thread::yield_now();
+            }
+            register_all_types!(manytypes1, registry);
+            sender.send(()).unwrap();
+        }));
+    }
+    for _ in 0..4_u8 {
+        let registry = Arc::clone(&registry);
+        let sender = sender.clone();
+        handles.push(std::thread::spawn(move || {
+            for _ in 0..1_000_u32 {
+                registry.get_transient::<manytypes0::TypeZero>().unwrap();
+            }
+            sender.send(()).unwrap();
+        }));
+    }
+
+    for _ in 0..handles.len() {
+        receiver
+            .recv_timeout(Duration::from_secs(10))
+            .expect("deadlocked while registering and resolving");
+    }
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    registry.validate_all().unwrap();
+}
+
+#[test]
+fn stress_validate_while_registering() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use ferrunix::cycle_detection::FullValidationError;
+
+    static DONE: AtomicBool = AtomicBool::new(false);
+
+    let parent = Arc::new(Registry::empty());
+    let child = parent.child();
+
+    let registering = {
+        let child = Arc::clone(&child);
+        std::thread::spawn(move || {
+            register_all_types!(manytypes0, child);
+            register_all_types!(manytypes1, child);
+            register_all_types!(manytypes2, child);
+            DONE.store(true, Ordering::SeqCst);
+        })
+    };
+
+    // Every registration invalidates the dependency graph, concurrent
+    // validations race with each other to rebuild it, not only with the
+    // registrations.
+    let mut handles = Vec::new();
+    for _ in 0..4_u8 {
+        let child = Arc::clone(&child);
+        handles.push(std::thread::spawn(move || {
+            while !DONE.load(Ordering::SeqCst) {
+                // Types are missing, until all their dependencies are
+                // registered, but there are never any cycles.
+                let result = child.validate_all_full();
+                assert!(
+                    !matches!(result, Err(FullValidationError::Cycle(_))),
+                    "{result:?}"
+                );
+                assert!(child.shutdown_order().is_ok());
+            }
+        }));
+    }
+
+    registering.join().unwrap();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    child.validate_all_full().unwrap();
+}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+use ferrunix::Inject;
+
+#[derive(Inject)]
+#[provides(transient, no_registration)]
+struct Broken {
+    #[inject(ctor_try = "\"1\".parse::<u8>()")]
+    value: u8,
+}
+
+fn main() {}
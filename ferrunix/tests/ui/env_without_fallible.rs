@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+use ferrunix::Inject;
+
+#[derive(Inject)]
+#[provides(transient, no_registration)]
+struct Broken {
+    #[inject(env = "PORT")]
+    port: u16,
+}
+
+fn main() {}
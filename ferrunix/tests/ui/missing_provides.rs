@@ -0,0 +1,10 @@
+#![allow(dead_code)]
+use ferrunix::Inject;
+
+#[derive(Inject)]
+struct Missing {
+    #[inject(default)]
+    value: u8,
+}
+
+fn main() {}
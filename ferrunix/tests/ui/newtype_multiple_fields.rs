@@ -0,0 +1,8 @@
+#![allow(dead_code)]
+use ferrunix::Inject;
+
+#[derive(Inject)]
+#[provides(transient, newtype, no_registration)]
+struct Broken(u16, u16);
+
+fn main() {}
@@ -0,0 +1,8 @@
+#![allow(dead_code)]
+use ferrunix::Inject;
+
+#[derive(Inject)]
+#[provides(transient, singleton, no_registration)]
+struct Both {}
+
+fn main() {}
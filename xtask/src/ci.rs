@@ -68,10 +68,15 @@ pub(super) fn run(args: &CiArgs) -> Result<()> {
         true
     };
 
-    let combinations_ferrunix =
-        feature_combinations(&["derive", "multithread", "tokio", "tracing"]);
+    let combinations_ferrunix = feature_combinations(&[
+        "derive",
+        "multithread",
+        "tokio",
+        "async-std",
+        "tracing",
+    ]);
     let combinations_ferrunix_core =
-        feature_combinations(&["multithread", "tokio", "tracing"]);
+        feature_combinations(&["multithread", "tokio", "async-std", "tracing"]);
     let combinations_ferrunix_macros =
         feature_combinations(&["multithread", "development"]);
     let test_matrix = {